@@ -1,25 +1,50 @@
 // src/app.rs
 
-use crate::core::models::{AnalysisFinding, ScanReport, Severity};
+use crate::config::Config;
+use crate::history::{self, HistoryEntry};
+use crate::core::knowledge_base::{self, FindingCategory};
+use crate::core::models::{AnalysisFinding, DnsResults, HeadersResults, RedirectResults, ScanReport, ScanResult, Severity, SslResults, WebServer, finding_penalty, findings_are_clean, score_findings};
+use crate::core::scanner;
 use crate::logging;
-use ratatui::widgets::ScrollbarState;
+use chrono::Local;
+use ratatui::widgets::{ListState, ScrollbarState};
+use std::collections::VecDeque;
+use tokio::task::JoinHandle;
 use std::fs;
+use tracing::{info, warn};
+
+/// The file in the data directory whose presence records that the disclaimer was accepted.
+pub const DISCLAIMER_MARKER_FILE: &str = "disclaimer_accepted";
+
+/// The file in the data directory whose presence records that the first-run tutorial was dismissed.
+pub const TUTORIAL_MARKER_FILE: &str = "tutorial_seen";
 
 /// Characters used for the animated loading spinner.
 pub const SPINNER_CHARS: [char; 4] = ['|', '/', '-', '\\'];
 
+/// How many ticks pass between log file reads while the log panel tails a running scan.
+const LOG_REFRESH_TICKS: u64 = 5;
+
+/// How many lines PgUp/PgDn move the log panel by.
+const LOG_SCROLL_LINES: usize = 10;
+
+/// How many of the most recent log file lines the log panel keeps.
+const LOG_PANEL_LINES: usize = 200;
+
 /// Represents the status of a report export operation.
 pub enum ExportStatus {
     /// No export operation is in progress.
     Idle,
     /// The export completed successfully. Contains the filename.
     Success(String),
+    /// Text was copied to the clipboard. Contains what was copied.
+    Copied(String),
     /// An error occurred during the export. Contains the error message.
     Error(String),
 }
 
 /// Defines the main states of the application's lifecycle.
-#[derive(Default, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub enum AppState {
     /// The initial state, showing a disclaimer to the user.
     #[default]
@@ -32,6 +57,90 @@ pub enum AppState {
     Finished,
 }
 
+/// A single sub-check that contributed to a category's pass/fail status.
+#[derive(Debug, Clone)]
+pub struct CheckDetail {
+    /// Whether this sub-check counts towards the category passing.
+    pub passed: bool,
+    /// A short, human-readable explanation (e.g. "DMARC record present (p=reject)").
+    pub description: String,
+}
+
+/// One finding's share of the score, as listed by the score breakdown.
+#[derive(Debug, Clone)]
+pub struct ScoreDeduction {
+    pub code: String,
+    /// The knowledge-base title, with the finding's subject filled in.
+    pub title: String,
+    pub severity: Severity,
+    /// The points taken off, in hundredths of a point (see `finding_penalty`).
+    pub penalty: u32,
+}
+
+/// How much of the target is exposed, beyond the pass/fail checks.
+///
+/// The indicator is the sum of its components, each counting things an attacker can probe.
+/// A component is `None` when the scan producing it did not run or failed, and is then left
+/// out of the total rather than counted as zero. The components are:
+/// - the detected technologies, each software with its own vulnerabilities to track;
+/// - the exposed paths: admin panels and debug pages found by the opt-in path probes;
+/// - the open ports seen: 443 when the TLS handshake succeeded, and 80 when the main page was
+///   served over plain HTTP. No port scan is run, so other ports are never counted.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AttackSurface {
+    /// The technologies identified by the fingerprint scan.
+    pub technologies: Option<usize>,
+    /// The admin panels and debug pages found; `None` unless the path probes ran.
+    pub exposed_paths: Option<usize>,
+    /// The web ports seen open; `None` when the SSL/TLS scan did not run.
+    pub open_ports: Option<usize>,
+}
+
+/// The findings that report a path exposing an admin panel or a debug page.
+const EXPOSED_PATH_CODES: &[&str] = &["HTTP_ADMIN_PANEL_EXPOSED", "HTTP_VERBOSE_ERROR"];
+
+impl AttackSurface {
+    /// Derives the indicator from a (possibly partial) report.
+    pub fn from_report(report: &ScanReport) -> Self {
+        let fingerprint = &report.fingerprint_results;
+        let technologies = fingerprint.technologies.as_ref().ok().filter(|_| !fingerprint.disabled).map(Vec::len);
+        let ssl = &report.ssl_results;
+        let served_over_http = report.headers_results.http_only || fingerprint.http_only;
+        Self {
+            technologies,
+            exposed_paths: (technologies.is_some() && fingerprint.paths_probed).then(|| {
+                fingerprint.analysis.iter().filter(|finding| EXPOSED_PATH_CODES.contains(&finding.code.as_str())).count()
+            }),
+            open_ports: (!ssl.disabled).then(|| usize::from(matches!(ssl.scan, Ok(Some(_)))) + usize::from(served_over_http)),
+        }
+    }
+
+    /// The sum of the known components, or `None` when none is known.
+    pub fn total(&self) -> Option<usize> {
+        [self.technologies, self.exposed_paths, self.open_ports].into_iter().flatten().reduce(|a, b| a + b)
+    }
+
+    /// Describes the known components, e.g. "3 technologies, 1 exposed path, 1 open port".
+    pub fn describe(&self) -> String {
+        let plural = |count: usize, noun: &str| format!("{} {}{}", count, noun, if count == 1 { "" } else { "s" });
+        [
+            self.technologies.map(|count| if count == 1 { "1 technology".to_string() } else { format!("{} technologies", count) }),
+            self.exposed_paths.map(|count| plural(count, "exposed path")),
+            self.open_ports.map(|count| plural(count, "open port")),
+        ].into_iter().flatten().collect::<Vec<_>>().join(", ")
+    }
+}
+
+/// A single target in a multi-target scan queue, with its outcome once scanned.
+pub struct BatchEntry {
+    /// The target exactly as the user typed it.
+    pub target: String,
+    /// The full report, available once this target has been scanned.
+    pub report: Option<ScanReport>,
+    /// The overall score computed for the report.
+    pub score: Option<u8>,
+}
+
 /// Holds a calculated summary of the scan results.
 #[derive(Debug, Default)]
 pub struct ScanSummary {
@@ -41,12 +150,41 @@ pub struct ScanSummary {
     pub critical_issues: usize,
     /// The total number of warning-severity issues found.
     pub warning_issues: usize,
+    /// The number of info-severity findings shown: none while focus mode hides them
+    /// (see `App::hidden_info_findings`).
+    pub info_issues: usize,
     /// Indicates whether the DNS checks (SPF, DMARC, etc.) passed.
     pub dns_check_passed: bool,
     /// Indicates whether the SSL/TLS configuration checks passed.
     pub ssl_check_passed: bool,
     /// Indicates whether the security headers checks passed.
     pub headers_check_passed: bool,
+    /// Whether each category's scanner was disabled in the configuration. A skipped
+    /// category has no sub-checks and is shown as neither passed nor failed.
+    pub dns_check_skipped: bool,
+    pub ssl_check_skipped: bool,
+    pub headers_check_skipped: bool,
+    /// The most severe finding of each category (by its knowledge-base category; the redirect
+    /// findings count as HTTP), or `None` when it has none. It colors the category's verdict.
+    pub dns_worst_severity: Option<Severity>,
+    pub ssl_worst_severity: Option<Severity>,
+    pub headers_worst_severity: Option<Severity>,
+    /// The email security sub-score (SPF, DMARC and DKIM, see `DnsResults::email_security_score`),
+    /// or `None` when the records are unknown.
+    pub email_score: Option<u8>,
+    /// The number of findings hidden by the ignore list. They are excluded from the counts and the score.
+    pub suppressed_issues: usize,
+    /// The WAF that answered the HTTP-based scanners with a challenge page, if any. Their
+    /// results then describe the WAF rather than the site.
+    pub blocked_by_waf: Option<String>,
+    /// The aggregate exposure indicator (see `AttackSurface`).
+    pub attack_surface: AttackSurface,
+    /// The sub-checks behind `dns_check_passed`.
+    pub dns_check_details: Vec<CheckDetail>,
+    /// The sub-checks behind `ssl_check_passed`.
+    pub ssl_check_details: Vec<CheckDetail>,
+    /// The sub-checks behind `headers_check_passed`.
+    pub headers_check_details: Vec<CheckDetail>,
 }
 
 /// The main application struct, holding all state information for the TUI.
@@ -57,20 +195,38 @@ pub struct App {
     pub state: AppState,
     /// The string input from the user (e.g., the target domain).
     pub input: String,
-    /// The full report generated by the scan, available when the scan is finished.
+    /// The report generated by the scan. While scanning, it is filled in section by section.
     pub scan_report: Option<ScanReport>,
+    /// The sections of the running scan that have not arrived yet; empty once the report is complete.
+    pub pending_sections: Vec<scanner::ScanSectionKind>,
+    /// The task running the latest scan, so quitting can stop it (see `run_tui` in `main.rs`).
+    pub scan_task: Option<JoinHandle<()>>,
     /// A summary of the scan results.
     pub summary: ScanSummary,
     /// The current status of any report export operation.
     pub export_status: ExportStatus,
+    /// The full path of the last exported file or bundle directory.
+    pub last_export_path: Option<std::path::PathBuf>,
     /// The current frame index for the loading spinner animation.
     pub spinner_frame: usize,
     /// A consolidated list of all findings from all analysis categories.
     pub all_findings: Vec<AnalysisFinding>,
+    /// Hides Info findings from the list (on by default); they are still exported and logged.
+    pub focus_mode: bool,
+    /// How many Info findings focus mode keeps out of `all_findings`.
+    pub hidden_info_findings: usize,
     /// The state for the scrollable list of analysis findings.
     pub analysis_list_state: ratatui::widgets::ListState,
     /// The score value currently being displayed, used for animation.
     pub displayed_score: u8,
+    /// Skips certificate validation in the HTTP scanners for this session ("inspect anyway").
+    /// It survives `reset`, so it stays on until toggled off.
+    pub accept_invalid_certs: bool,
+    /// Scans the host the target redirects to instead (see `follow_canonical_redirect` in the
+    /// config, which it starts from). Like `accept_invalid_certs`, it survives `reset`.
+    pub follow_canonical_redirect: bool,
+    /// Stacks the panes in a single column; also applied automatically on narrow terminals.
+    pub compact_mode: bool,
     /// A flag to control the visibility of the log panel.
     pub show_logs: bool,
     /// The content of the log file to be displayed in the log panel.
@@ -79,26 +235,199 @@ pub struct App {
     pub log_horizontal_scroll_state: ScrollbarState,
     /// The current horizontal scroll position for the log content.
     pub log_horizontal_scroll: usize,
+    /// The state for the vertical scrollbar in the log panel.
+    pub log_vertical_scroll_state: ScrollbarState,
+    /// The current vertical scroll position (first visible line) for the log content.
+    pub log_vertical_scroll: usize,
+    /// Whether the log panel sticks to the newest lines. Scrolling up turns it off;
+    /// scrolling back to the bottom turns it on again.
+    pub log_follow: bool,
+    /// Counts calls to `on_tick`; used to pace periodic work such as tailing the log file.
+    tick_count: u64,
+    /// The user configuration loaded at startup.
+    pub config: Config,
+    /// A flag to control the visibility of the "why did the checks pass?" popup.
+    pub show_check_details: bool,
+    /// A flag to control the visibility of the score breakdown popup.
+    pub show_score_breakdown: bool,
+    /// A flag to control the visibility of the raw HTTP response popup.
+    pub show_raw_response: bool,
+    /// Whether the raw response popup also shows the (truncated) body.
+    pub raw_response_show_body: bool,
+    /// The vertical scroll offset of the raw response popup, in lines.
+    pub raw_response_scroll: u16,
+    /// Whether any scan of the current run reported a Critical finding; decides the completion bell.
+    pub run_found_critical: bool,
+    /// Targets still waiting to be scanned in a multi-target run.
+    pub scan_queue: VecDeque<String>,
+    /// All targets of a multi-target run, in input order. Empty for single-target scans.
+    pub batch: Vec<BatchEntry>,
+    /// The state for the selectable list of batch targets.
+    pub batch_list_state: ListState,
+    /// The state to return to once the disclaimer is acknowledged.
+    state_after_disclaimer: AppState,
+    /// Whether the first-run tutorial is shown, over the input screen. It only follows the first
+    /// acknowledgment of the disclaimer, and is never shown again once dismissed.
+    pub show_tutorial: bool,
+    /// Previously scanned targets, least recent first, for recalling them in the input box.
+    pub input_history: Vec<String>,
+    /// The note attached to the current report; written into exported reports.
+    pub notes: Option<String>,
+    /// The note being edited while the notes overlay is open; `None` when it is closed.
+    pub notes_input: Option<String>,
+    /// The `input_history` entry currently shown in the input box, if the user is browsing it.
+    history_index: Option<usize>,
 }
 
 impl App {
-    /// Creates a new instance of the `App` with default values.
-    pub fn new() -> Self {
+    /// Creates a new instance of the `App` with default values and the given configuration.
+    pub fn new(config: Config) -> Self {
         Self {  
             should_quit: false,
-            state: AppState::default(),
+            // The disclaimer is only forced on users who have never acknowledged it.
+            state: if disclaimer_marker_path().exists() { AppState::Idle } else { AppState::Disclaimer },
             input: String::new(),
             scan_report: None,
+            pending_sections: Vec::new(),
+            scan_task: None,
             summary: ScanSummary::default(),
             export_status: ExportStatus::Idle,
+            last_export_path: None,
             spinner_frame: 0,
             all_findings: Vec::new(),
+            focus_mode: true,
+            hidden_info_findings: 0,
             analysis_list_state: ratatui::widgets::ListState::default(),
             displayed_score: 0,
+            accept_invalid_certs: false,
+            follow_canonical_redirect: config.follow_canonical_redirect,
+            compact_mode: false,
             show_logs: false,
             log_content: Vec::new(),
             log_horizontal_scroll_state: ScrollbarState::default(),
             log_horizontal_scroll: 0,
+            log_vertical_scroll_state: ScrollbarState::default(),
+            log_vertical_scroll: 0,
+            log_follow: true,
+            tick_count: 0,
+            config,
+            show_check_details: false,
+            show_score_breakdown: false,
+            show_raw_response: false,
+            run_found_critical: false,
+            raw_response_show_body: false,
+            raw_response_scroll: 0,
+            scan_queue: VecDeque::new(),
+            batch: Vec::new(),
+            batch_list_state: ListState::default(),
+            state_after_disclaimer: AppState::Idle,
+            show_tutorial: false,
+            input_history: history::recent_targets(&history::load()),
+            history_index: None,
+            notes: None,
+            notes_input: None,
+        }
+    }
+
+    /// The options for the next scan: the configured ones plus this session's toggles.
+    pub fn scan_options(&self) -> scanner::ScanOptions {
+        scanner::ScanOptions {
+            accept_invalid_certs: self.accept_invalid_certs,
+            follow_canonical_redirect: self.follow_canonical_redirect,
+            ..self.config.scan_options()
+        }
+    }
+
+    /// Opens the notes overlay, starting from the current note.
+    pub fn open_notes_editor(&mut self) {
+        self.notes_input = Some(self.notes.clone().unwrap_or_default());
+    }
+
+    /// Closes the notes overlay, keeping the edited note. An empty note removes it.
+    pub fn save_notes(&mut self) {
+        if let Some(draft) = self.notes_input.take() {
+            let draft = draft.trim();
+            self.notes = (!draft.is_empty()).then(|| draft.to_string());
+        }
+    }
+
+    /// Replaces the input with the previous (older) target from the history.
+    pub fn recall_previous_target(&mut self) {
+        if self.input_history.is_empty() { return; }
+        let index = match self.history_index {
+            Some(0) => 0,
+            Some(i) => i - 1,
+            None => self.input_history.len() - 1,
+        };
+        self.history_index = Some(index);
+        self.input = self.input_history[index].clone();
+    }
+
+    /// Replaces the input with the next (more recent) target from the history.
+    /// Moving past the most recent entry clears the input.
+    pub fn recall_next_target(&mut self) {
+        let Some(index) = self.history_index else { return; };
+        if index + 1 < self.input_history.len() {
+            self.history_index = Some(index + 1);
+            self.input = self.input_history[index + 1].clone();
+        } else {
+            self.history_index = None;
+            self.input.clear();
+        }
+    }
+
+    /// Records a completed scan in the history file and makes its target recallable.
+    pub fn record_history(&mut self, target: &str) {
+        let entry = HistoryEntry {
+            target: target.to_string(),
+            timestamp: Local::now(),
+            score: self.summary.score,
+            critical: self.summary.critical_issues,
+            warning: self.summary.warning_issues,
+        };
+        if let Err(e) = history::append(&entry) {
+            warn!(error = %e, "Could not append to the scan history.");
+        }
+        #[cfg(feature = "findings-db")]
+        if let Err(e) = crate::findings_db::append(target, entry.score, &self.collect_findings(), entry.timestamp) {
+            warn!(error = %e, "Could not record the findings in the findings database.");
+        }
+        self.input_history.retain(|known| known != target);
+        self.input_history.push(target.to_string());
+    }
+
+    /// Shows the disclaimer again, for reference. Acknowledging it returns to the current state.
+    pub fn show_disclaimer(&mut self) {
+        if self.state != AppState::Disclaimer {
+            self.state_after_disclaimer = std::mem::replace(&mut self.state, AppState::Disclaimer);
+        }
+    }
+
+    /// Acknowledges the disclaimer and records it, so later launches start directly in `Idle`.
+    /// The first acknowledgment also opens the tutorial, unless it was dismissed before.
+    pub fn accept_disclaimer(&mut self) {
+        let marker = disclaimer_marker_path();
+        if !marker.exists() {
+            self.show_tutorial = !tutorial_marker_path().exists();
+            let written = marker.parent().map_or(Ok(()), fs::create_dir_all)
+                .and_then(|_| fs::write(&marker, Local::now().to_rfc3339()));
+            match written {
+                Ok(()) => info!(path = %marker.display(), "Disclaimer acknowledged."),
+                Err(e) => warn!(path = %marker.display(), error = %e, "Could not record the disclaimer acknowledgment."),
+            }
+        }
+        self.state = std::mem::replace(&mut self.state_after_disclaimer, AppState::Idle);
+    }
+
+    /// Closes the first-run tutorial and records it, so it is not shown again.
+    pub fn dismiss_tutorial(&mut self) {
+        self.show_tutorial = false;
+        let marker = tutorial_marker_path();
+        let written = marker.parent().map_or(Ok(()), fs::create_dir_all)
+            .and_then(|_| fs::write(&marker, Local::now().to_rfc3339()));
+        match written {
+            Ok(()) => info!(path = %marker.display(), "Tutorial dismissed."),
+            Err(e) => warn!(path = %marker.display(), error = %e, "Could not record the tutorial dismissal."),
         }
     }
     
@@ -122,15 +451,176 @@ impl App {
         self.analysis_list_state.select(Some(i));
     }
     
+    /// Returns the index of the first finding of each category, in list order.
+    fn category_starts(&self) -> Vec<usize> {
+        (0..self.all_findings.len())
+            .filter(|&i| i == 0 || finding_category(&self.all_findings[i]) != finding_category(&self.all_findings[i - 1]))
+            .collect()
+    }
+
+    /// Jumps to the first finding of the next category, wrapping around to the first category.
+    pub fn select_next_category(&mut self) {
+        let starts = self.category_starts();
+        let Some(&first) = starts.first() else { return; };
+        let current = self.analysis_list_state.selected().unwrap_or(0);
+        let next = starts.iter().copied().find(|&start| start > current).unwrap_or(first);
+        self.analysis_list_state.select(Some(next));
+    }
+
+    /// Jumps to the first finding of the previous category, wrapping around to the last category.
+    pub fn select_previous_category(&mut self) {
+        let starts = self.category_starts();
+        let Some(&last) = starts.last() else { return; };
+        let current = self.analysis_list_state.selected().unwrap_or(0);
+        // The category the selection is in starts at the last boundary at or before it.
+        let current_start = starts.iter().rposition(|&start| start <= current).unwrap_or(0);
+        let previous = if current_start == 0 { last } else { starts[current_start - 1] };
+        self.analysis_list_state.select(Some(previous));
+    }
+
+    /// Whether the displayed findings contain nothing requiring action. See `ScanReport::is_clean`;
+    /// this variant honors the configured severity overrides and ignore list.
+    pub fn is_clean(&self) -> bool {
+        findings_are_clean(&self.all_findings)
+    }
+
+    /// Returns the category of the currently selected finding, if any.
+    pub fn selected_category(&self) -> Option<FindingCategory> {
+        self.analysis_list_state.selected()
+            .and_then(|i| self.all_findings.get(i))
+            .map(finding_category)
+    }
+
+    /// The web server remediation snippets are written for: the configured one, otherwise
+    /// the one detected by the fingerprint scan.
+    pub fn web_server(&self) -> Option<WebServer> {
+        self.config.web_server.or_else(|| {
+            let technologies = self.scan_report.as_ref()?.fingerprint_results.technologies.as_ref().ok()?;
+            WebServer::detect(technologies)
+        })
+    }
+
+    /// Returns the config snippet that fixes the selected finding, with the server it is written for.
+    pub fn selected_config_snippet(&self) -> Option<(WebServer, String)> {
+        let finding = self.analysis_list_state.selected().and_then(|i| self.all_findings.get(i))?;
+        let server = self.web_server()?;
+        knowledge_base::config_snippet(&finding.code, server).map(|snippet| (server, snippet))
+    }
+
+    /// What the "apply hint" key copies for the selected finding: a description of it and the text.
+    /// Falls back to the generic remediation when no snippet exists for the finding or the server.
+    pub fn selected_remediation_hint(&self) -> Option<(String, String)> {
+        if let Some((server, snippet)) = self.selected_config_snippet() {
+            return Some((format!("the {} snippet", server), snippet));
+        }
+        let finding = self.analysis_list_state.selected().and_then(|i| self.all_findings.get(i))?;
+        let detail = knowledge_base::get_finding_detail(&finding.code)?;
+        Some(("the remediation".to_string(), finding.fill_template(detail.remediation)))
+    }
+
+    /// Returns `true` if the ignore list hides this finding for the target being displayed.
+    fn is_suppressed(&self, finding: &AnalysisFinding) -> bool {
+        self.config.is_ignored(&scanner::target_host(self.report_target()), &finding.code)
+    }
+
+    /// Counts the findings of the current report that the ignore list hides.
+    fn count_suppressed(&self) -> usize {
+        self.scan_report.as_ref()
+            .map_or(0, |report| report.findings().filter(|finding| self.is_suppressed(finding)).count())
+    }
+
+    /// Adds the selected finding to the ignore list of the current target and saves the configuration.
+    /// The findings list and the summary are refreshed, so the finding disappears immediately.
+    pub fn suppress_selected_finding(&mut self) -> std::io::Result<()> {
+        let Some(finding) = self.analysis_list_state.selected().and_then(|i| self.all_findings.get(i)) else {
+            return Ok(());
+        };
+        let code = finding.code.clone();
+        let target = scanner::target_host(self.report_target());
+        // Update the file as it is on disk, so command-line overrides are not persisted along the way.
+        // A file that does not parse is left alone: saving over it would lose every setting in it.
+        let mut stored = Config::try_load()?;
+        stored.ignore_for_target(&target, &code);
+        stored.save()?;
+        self.config.ignore_for_target(&target, &code);
+        info!(code = %code, target = %target, "Finding added to the ignore list.");
+
+        let selected = self.analysis_list_state.selected();
+        self.update_summary();
+        self.update_findings();
+        // Keep the cursor near where it was instead of jumping back to the top.
+        if let Some(i) = selected && !self.all_findings.is_empty() {
+            self.analysis_list_state.select(Some(i.min(self.all_findings.len() - 1)));
+        }
+        if let Some(index) = self.batch_list_state.selected()
+            && let Some(entry) = self.batch.get_mut(index)
+        {
+            entry.score = Some(self.summary.score);
+        }
+        Ok(())
+    }
+
+    /// Collects all findings from the scan report, with the configured severity overrides applied
+    /// and the ignored findings left out.
+    /// It flattens the findings from DNS, SSL, Headers, and Fingerprint results into a single list.
+    pub fn collect_findings(&self) -> Vec<AnalysisFinding> {
+        let Some(report) = &self.scan_report else { return Vec::new(); };
+        report.findings()
+            .filter(|finding| !self.is_suppressed(finding))
+            .cloned()
+            .map(|mut finding| {
+                self.config.apply_severity_override(&mut finding);
+                finding
+            })
+            .collect()
+    }
+
+    /// Switches focus mode on or off and rebuilds the findings list.
+    pub fn toggle_focus_mode(&mut self) {
+        self.focus_mode = !self.focus_mode;
+        self.update_findings();
+        self.summary.info_issues = self.shown_info_issues(&self.collect_findings());
+    }
+
+    /// Counts the Info findings among `findings` that the list shows, i.e. none in focus mode.
+    fn shown_info_issues(&self, findings: &[AnalysisFinding]) -> usize {
+        if self.focus_mode { 0 } else { findings.iter().filter(|finding| finding.severity == Severity::Info).count() }
+    }
+
+    /// Merges one scanner's results into the report being built, so they can be shown while
+    /// the rest of the scan is still running. The first section of a scan starts a new report.
+    pub fn apply_scan_section(&mut self, section: scanner::ScanSection) {
+        if self.pending_sections.is_empty() {
+            self.scan_report = Some(ScanReport::default());
+            self.pending_sections = scanner::ScanSectionKind::ALL.to_vec();
+        }
+        self.pending_sections.retain(|kind| *kind != section.kind());
+        if let Some(report) = self.scan_report.as_mut() {
+            section.apply_to(report);
+        }
+        self.update_summary();
+        self.update_findings();
+    }
+
+    /// Returns `true` if the section's results have arrived (always the case outside of a scan).
+    pub fn has_section(&self, kind: scanner::ScanSectionKind) -> bool {
+        !self.pending_sections.contains(&kind)
+    }
+
     /// Populates the `all_findings` vector by collecting all findings from the scan report.
-    /// It chains the findings from DNS, SSL, and Headers results into a single list.
     pub fn update_findings(&mut self) {
-        if let Some(report) = &self.scan_report {
-            self.all_findings = report.dns_results.analysis.iter()
-                .chain(report.ssl_results.analysis.iter())
-                .chain(report.headers_results.analysis.iter())
-                .cloned()
-                .collect();
+        if self.scan_report.is_some() {
+            self.all_findings = self.collect_findings();
+            // Focus mode keeps Info findings out of the list, but counts them so they are not silently dropped.
+            if self.focus_mode {
+                let total = self.all_findings.len();
+                self.all_findings.retain(|finding| finding.severity != Severity::Info);
+                self.hidden_info_findings = total - self.all_findings.len();
+            } else {
+                self.hidden_info_findings = 0;
+            }
+            // Group the list by category; the sort is stable, so scanner order is kept within a group.
+            self.all_findings.sort_by_key(finding_category);
 
             // Select the first finding by default if the list is not empty.
             if !self.all_findings.is_empty() {
@@ -145,17 +635,21 @@ impl App {
     /// Used for animations like the spinner and the score counter.
     pub fn on_tick(&mut self) {
         // Animate the spinner while scanning.
+        self.tick_count = self.tick_count.wrapping_add(1);
         if matches!(self.state, AppState::Scanning) {
             self.spinner_frame = (self.spinner_frame + 1) % SPINNER_CHARS.len();
-        }
 
-        // Animate the score gauge when the scan is finished.
-        if matches!(self.state, AppState::Finished) {
-            if self.displayed_score < self.summary.score {
-                // Increment the score gradually for a smooth animation.
-                self.displayed_score = (self.displayed_score + 2).min(self.summary.score);
+            // Tail the log file while the panel is open, without reading it on every tick.
+            if self.show_logs && self.tick_count.is_multiple_of(LOG_REFRESH_TICKS) {
+                self.refresh_logs();
             }
         }
+
+        // Animate the score gauge once results are in, including the partial ones of a running scan.
+        if self.scan_report.is_some() && self.displayed_score < self.summary.score {
+            // Increment the score gradually for a smooth animation.
+            self.displayed_score = (self.displayed_score + 2).min(self.summary.score);
+        }
     }
 
     /// Sets the `should_quit` flag to true to signal the application to exit.
@@ -165,44 +659,171 @@ impl App {
     pub fn reset(&mut self) {
         self.state = AppState::Idle;
         self.input = String::new();
+        self.history_index = None;
+        self.notes = None;
+        self.notes_input = None;
         self.scan_report = None;
+        self.pending_sections.clear();
         self.summary = ScanSummary::default();
         self.export_status = ExportStatus::Idle;
+        self.last_export_path = None;
         self.spinner_frame = 0;
         self.all_findings = Vec::new();
+        self.hidden_info_findings = 0;
         self.analysis_list_state.select(None);
         self.log_horizontal_scroll = 0;
         self.log_horizontal_scroll_state = ScrollbarState::default();
+        self.log_vertical_scroll = 0;
+        self.log_vertical_scroll_state = ScrollbarState::default();
+        self.log_follow = true;
+        self.show_check_details = false;
+        self.show_score_breakdown = false;
+        self.show_raw_response = false;
+        self.raw_response_show_body = false;
+        self.raw_response_scroll = 0;
+        self.scan_queue.clear();
+        self.batch.clear();
+        self.batch_list_state.select(None);
+    }
+
+    /// Queues several targets to be scanned one after another.
+    pub fn start_batch(&mut self, targets: Vec<String>) {
+        self.batch = targets.iter()
+            .map(|target| BatchEntry { target: target.clone(), report: None, score: None })
+            .collect();
+        self.scan_queue = targets.into();
+        self.batch_list_state.select(None);
+    }
+
+    /// Returns `true` if the current run scans more than one target.
+    pub fn is_batch(&self) -> bool {
+        !self.batch.is_empty()
+    }
+
+    /// Stores the report currently shown into the first pending batch entry and selects it.
+    /// `update_summary` must have been called first so the score is current.
+    pub fn record_batch_result(&mut self) {
+        if let Some(index) = self.batch.iter().position(|entry| entry.report.is_none()) {
+            self.batch[index].report = self.scan_report.clone();
+            self.batch[index].score = Some(self.summary.score);
+            self.batch_list_state.select(Some(index));
+        }
+    }
+
+    /// Loads the report of a completed batch entry into the analysis view.
+    fn select_batch_entry(&mut self, index: usize) {
+        let Some(report) = self.batch.get(index).and_then(|entry| entry.report.clone()) else { return; };
+        self.scan_report = Some(report);
+        self.update_summary();
+        self.update_findings();
+        self.batch_list_state.select(Some(index));
+    }
+
+    /// Selects the next completed batch target, wrapping around at the end.
+    pub fn select_next_batch_entry(&mut self) {
+        if self.batch.is_empty() { return; }
+        let next = self.batch_list_state.selected().map_or(0, |i| (i + 1) % self.batch.len());
+        self.select_batch_entry(next);
+    }
+
+    /// Selects the previous completed batch target, wrapping around at the start.
+    pub fn select_previous_batch_entry(&mut self) {
+        if self.batch.is_empty() { return; }
+        let previous = match self.batch_list_state.selected() {
+            Some(0) | None => self.batch.len() - 1,
+            Some(i) => i - 1,
+        };
+        self.select_batch_entry(previous);
+    }
+
+    /// The displayed report as it is exported: the severity overrides, the ignore list and the
+    /// scoring profile applied, as in headless mode, then the export severity filter and the notes.
+    pub fn report_for_export(&self) -> Option<ScanReport> {
+        let mut report = self.scan_report.clone()?;
+        self.config.apply_to_report(&scanner::target_host(self.report_target()), &mut report);
+        let mut report = match &self.config.export_min_severity {
+            Some(min_severity) => report.filtered_by_severity(min_severity),
+            None => report,
+        };
+        report.notes = self.notes.clone();
+        Some(report)
+    }
+
+    /// The target the currently displayed report belongs to, as typed by the user.
+    pub fn report_target(&self) -> &str {
+        self.batch_list_state.selected()
+            .and_then(|i| self.batch.get(i))
+            .map_or(self.input.as_str(), |entry| entry.target.as_str())
     }
     
+    /// Lists every finding that costs points under the report's scoring profile, most
+    /// expensive first. The penalties add up to what `update_summary` takes off the score;
+    /// Info findings cost nothing and are left out.
+    pub fn score_breakdown(&self) -> Vec<ScoreDeduction> {
+        let profile = self.scan_report.as_ref().map(|report| report.scoring_profile).unwrap_or_default();
+        let mut deductions: Vec<ScoreDeduction> = self.collect_findings().into_iter()
+            .map(|finding| ScoreDeduction {
+                penalty: finding_penalty(&finding, profile),
+                title: knowledge_base::get_finding_detail(&finding.code)
+                    .map_or_else(|| finding.code.clone(), |detail| finding.fill_template(detail.title)),
+                code: finding.code,
+                severity: finding.severity,
+            })
+            .filter(|deduction| deduction.penalty > 0)
+            .collect();
+        deductions.sort_by(|a, b| b.penalty.cmp(&a.penalty).then_with(|| a.code.cmp(&b.code)));
+        deductions
+    }
+
     /// Calculates and populates the `ScanSummary` struct from the full scan report.
     /// It counts issues, calculates a score, and determines the pass/fail status of major check categories.
     pub fn update_summary(&mut self) {
+        let all_analyses = self.collect_findings();
         if let Some(report) = &self.scan_report {
-            let all_analyses: Vec<_> = report.dns_results.analysis.iter()
-                .chain(report.ssl_results.analysis.iter())
-                .chain(report.headers_results.analysis.iter())
-                .collect();
-            
+
             // Count issues by severity.
             let criticals = all_analyses.iter().filter(|a| matches!(a.severity, Severity::Critical)).count();
             let warnings = all_analyses.iter().filter(|a| matches!(a.severity, Severity::Warning)).count();
+            let infos = self.shown_info_issues(&all_analyses);
             
-            // Calculate score based on findings (15 points off for critical, 5 for warning).
-            let score = 100_i16.saturating_sub((criticals * 15) as i16).saturating_sub((warnings * 5) as i16);
+            // Calculate score based on findings (15 points off for critical, 5 for warning),
+            // weighted by the report's scoring profile.
+            let score = score_findings(&all_analyses, report.scoring_profile);
             
             // Determine if major scan categories passed successfully.
-            let dns_check_passed = report.dns_results.spf.is_ok() && report.dns_results.dmarc.is_ok() && report.dns_results.dkim.is_ok() && report.dns_results.caa.is_ok();
-            let ssl_check_passed = report.ssl_results.scan.is_ok();
-            let headers_check_passed = report.headers_results.error.is_none() && report.headers_results.hsts.is_ok() && report.headers_results.csp.is_ok() && report.headers_results.x_frame_options.is_ok() && report.headers_results.x_content_type_options.is_ok();
+            // A category passes when every one of its sub-checks passed; the sub-checks are kept
+            // so the UI can explain the verdict.
+            let dns_check_details = explain_dns_checks(&report.dns_results);
+            let ssl_check_details = explain_ssl_checks(&report.ssl_results);
+            let mut headers_check_details = explain_headers_checks(&report.headers_results);
+            if !report.redirect_results.disabled {
+                headers_check_details.push(explain_redirect_chain(&report.redirect_results));
+            }
+            let dns_check_passed = dns_check_details.iter().all(|d| d.passed);
+            let ssl_check_passed = ssl_check_details.iter().all(|d| d.passed);
+            let headers_check_passed = headers_check_details.iter().all(|d| d.passed);
 
             self.summary = ScanSummary {
-                score: if score < 0 { 0 } else { score as u8 },
+                score,
                 critical_issues: criticals,
                 warning_issues: warnings,
+                info_issues: infos,
+                suppressed_issues: self.count_suppressed(),
                 dns_check_passed,
                 ssl_check_passed,
                 headers_check_passed,
+                dns_check_skipped: report.dns_results.disabled,
+                ssl_check_skipped: report.ssl_results.disabled,
+                headers_check_skipped: report.headers_results.disabled,
+                dns_worst_severity: worst_severity(&all_analyses, FindingCategory::Dns),
+                ssl_worst_severity: worst_severity(&all_analyses, FindingCategory::Ssl),
+                headers_worst_severity: worst_severity(&all_analyses, FindingCategory::Http),
+                email_score: report.dns_results.email_security_score(),
+                blocked_by_waf: report.blocking_waf().map(String::from),
+                attack_surface: AttackSurface::from_report(report),
+                dns_check_details,
+                ssl_check_details,
+                headers_check_details,
             };
             
             // Reset the displayed score to 0 to trigger the animation.
@@ -210,17 +831,199 @@ impl App {
         }
     }
 
+    /// Scrolls the log panel towards older lines, leaving follow mode.
+    pub fn scroll_logs_up(&mut self) {
+        self.log_follow = false;
+        self.log_vertical_scroll = self.log_vertical_scroll.saturating_sub(LOG_SCROLL_LINES);
+    }
+
+    /// Scrolls the log panel towards newer lines. Reaching the bottom resumes follow mode
+    /// (the log view clamps the position and checks this when it renders).
+    pub fn scroll_logs_down(&mut self) {
+        self.log_vertical_scroll = self.log_vertical_scroll.saturating_add(LOG_SCROLL_LINES);
+    }
+
+    /// Jumps to the newest log lines and keeps following them.
+    pub fn follow_logs(&mut self) {
+        self.log_follow = true;
+    }
+
     /// Reads the application's log file and updates the `log_content` field.
-    /// It reads the last 200 lines in reverse order for display.
+    /// It keeps the last 200 lines, oldest first, so the newest are at the bottom.
     pub fn refresh_logs(&mut self) {
         let log_path = logging::get_data_dir().join(logging::LOG_FILE.clone());
         match fs::read_to_string(log_path) {
             Ok(content) => {
-                self.log_content = content.lines().rev().take(200).map(String::from).collect();
+                let lines: Vec<&str> = content.lines().collect();
+                let start = lines.len().saturating_sub(LOG_PANEL_LINES);
+                self.log_content = lines[start..].iter().map(|line| line.to_string()).collect();
             }
             Err(_) => {
                 self.log_content = vec!["Could not read log file.".to_string()];
             }
         }
     }
-}
\ No newline at end of file
+}
+
+/// Returns the path of the marker file recording that the disclaimer was accepted.
+fn disclaimer_marker_path() -> std::path::PathBuf {
+    logging::get_data_dir().join(DISCLAIMER_MARKER_FILE)
+}
+
+/// Returns the path of the marker file recording that the tutorial was dismissed.
+fn tutorial_marker_path() -> std::path::PathBuf {
+    logging::get_data_dir().join(TUTORIAL_MARKER_FILE)
+}
+
+/// Looks up a finding's category in the knowledge base.
+/// Unknown codes fall back to the HTTP category, matching how the analysis view displays them.
+fn finding_category(finding: &AnalysisFinding) -> FindingCategory {
+    knowledge_base::get_finding_detail(&finding.code)
+        .map_or(FindingCategory::Http, |detail| detail.category)
+}
+
+/// Writes one structured log event per finding, so the log file keeps an audit trail of
+/// every scan that can be parsed later.
+pub fn log_findings<'a>(target: &str, findings: impl IntoIterator<Item = &'a AnalysisFinding>) {
+    for finding in findings {
+        info!(
+            code = %finding.code,
+            severity = ?finding.severity,
+            category = %finding_category(finding),
+            target = %target,
+            "Finding reported."
+        );
+    }
+}
+
+/// Describes the redirect chain of the main page as "a → b → c". A loop fails the check.
+fn explain_redirect_chain(redirects: &RedirectResults) -> CheckDetail {
+    match &redirects.chain {
+        Ok(Some(chain)) if chain.len() > 1 => {
+            let is_loop = redirects.has_loop();
+            let description = format!("Redirects: {} ({} hops{})", chain.join(" → "), chain.len() - 1, if is_loop { ", loop" } else { "" });
+            CheckDetail { passed: !is_loop, description }
+        }
+        result => explain_result("Redirects", result, |_| "none".to_string()),
+    }
+}
+
+/// Describes the outcome of a single lookup-style sub-check.
+///
+/// A successful lookup passes whether or not data was found; the description tells the two apart.
+fn explain_result<T>(label: &str, result: &ScanResult<T>, describe: impl Fn(&T) -> String) -> CheckDetail {
+    match result {
+        Ok(Some(data)) => CheckDetail { passed: true, description: format!("{}: {}", label, describe(data)) },
+        Ok(None) => CheckDetail { passed: true, description: format!("{}: checked, nothing published", label) },
+        Err(e) => CheckDetail { passed: false, description: format!("{}: check failed ({})", label, e) },
+    }
+}
+
+/// The most severe of the findings in a knowledge-base category, if it has any.
+fn worst_severity(findings: &[AnalysisFinding], category: FindingCategory) -> Option<Severity> {
+    findings.iter()
+        .filter(|finding| knowledge_base::get_finding_detail(&finding.code).is_some_and(|detail| detail.category == category))
+        .map(|finding| finding.severity.clone())
+        .min_by_key(Severity::rank)
+}
+
+/// Builds the sub-check explanations for the DNS category.
+fn explain_dns_checks(dns: &DnsResults) -> Vec<CheckDetail> {
+    if dns.disabled {
+        return Vec::new();
+    }
+    if dns.domain_not_found {
+        return vec![CheckDetail { passed: false, description: "Domain: does not exist (NXDOMAIN)".to_string() }];
+    }
+    vec![
+        explain_result("SPF", &dns.spf, |spf| format!("present ({})", spf.record)),
+        match &dns.dmarc {
+            // Receivers ignore an invalid record, so it does not pass.
+            Ok(Some(dmarc)) if !dmarc.errors.is_empty() => CheckDetail {
+                passed: false,
+                description: format!("DMARC: present but malformed ({})", dmarc.errors.join("; ")),
+            },
+            result => explain_result("DMARC", result, |dmarc| match &dmarc.policy {
+                Some(policy) => format!("present, policy '{}'", policy),
+                None => "present, no policy tag".to_string(),
+            }),
+        },
+        explain_result("DKIM", &dns.dkim, |records| {
+            let selectors: Vec<String> = records.iter().map(|r| match &r.provider {
+                Some(provider) => format!("{} ({})", r.selector, provider),
+                None => r.selector.clone(),
+            }).collect();
+            format!("found for selector(s) {}", selectors.join(", "))
+        }),
+        explain_result("CAA", &dns.caa, |records| format!("{} record(s) present", records.len())),
+    ]
+}
+
+/// Builds the sub-check explanations for the SSL/TLS category.
+fn explain_ssl_checks(ssl: &SslResults) -> Vec<CheckDetail> {
+    if ssl.disabled {
+        return Vec::new();
+    }
+    vec![explain_result("TLS handshake", &ssl.scan, |data| {
+        format!(
+            "certificate issued by {}, {} day(s) until expiry",
+            data.certificate_info.issuer_name, data.certificate_info.days_until_expiry
+        )
+    })]
+}
+
+/// Builds the sub-check explanations for the HTTP headers category.
+fn explain_headers_checks(headers: &HeadersResults) -> Vec<CheckDetail> {
+    if headers.disabled {
+        return Vec::new();
+    }
+    if let Some(e) = &headers.error {
+        return vec![CheckDetail { passed: false, description: format!("HTTP request: {}", e) }];
+    }
+    if let Some(waf) = &headers.blocked_by_waf {
+        return vec![CheckDetail { passed: false, description: format!("HTTP request: blocked by a {} challenge page, headers not checked", waf) }];
+    }
+    let mut details = vec![if headers.http_only {
+        CheckDetail { passed: false, description: "HTTP request: HTTPS unreachable, response received over plain HTTP".to_string() }
+    } else {
+        CheckDetail { passed: true, description: "HTTP request: response received".to_string() }
+    }];
+    details.extend([
+        explain_result("Strict-Transport-Security", &headers.hsts, |h| format!("'{}'", h.value)),
+        explain_result("Content-Security-Policy", &headers.csp, |_| "present".to_string()),
+        explain_result("X-Frame-Options", &headers.x_frame_options, |h| format!("'{}'", h.value)),
+        explain_result("X-Content-Type-Options", &headers.x_content_type_options, |h| format!("'{}'", h.value)),
+        explain_result("Access-Control-Allow-Origin", &headers.access_control_allow_origin, |h| format!("'{}'", h.value)),
+    ]);
+    if let Some(reflected) = headers.cors_origin_reflected {
+        let description = if reflected { "CORS probe: foreign origin allowed" } else { "CORS probe: foreign origin not allowed" };
+        details.push(CheckDetail { passed: !reflected, description: description.to_string() });
+    }
+    details.extend(headers.custom_headers.iter()
+        .map(|custom| explain_result(&custom.name, &custom.header, |h| format!("'{}'", h.value))));
+    details
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::export::{self, ExportFormat};
+
+    #[test]
+    fn exported_reports_carry_the_overridden_severities() {
+        let mut config = Config::default();
+        config.severity_overrides.insert("HEADERS_HSTS_MISSING".to_string(), Severity::Critical);
+        let mut app = App::new(config);
+        app.input = "example.com".to_string();
+        let mut report = ScanReport::default();
+        report.headers_results.analysis.push(AnalysisFinding::new(Severity::Warning, "HEADERS_HSTS_MISSING"));
+        app.scan_report = Some(report);
+
+        let dir = std::env::temp_dir().join(format!("vanguard-app-export-{}", std::process::id()));
+        let exported = app.report_for_export().unwrap();
+        let path = export::write_report(&exported, app.report_target(), &ExportFormat::Json, &dir, false).unwrap();
+        let json: serde_json::Value = serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(json["headers_results"]["analysis"][0]["severity"], "Critical");
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,133 @@
+// src/cli.rs
+
+use crate::core::models::{ScoringProfile, Severity};
+use clap::{Parser, ValueEnum};
+use std::path::PathBuf;
+
+/// Vanguard RS: a privacy-first security analysis toolkit with a TUI dashboard.
+///
+/// Without any argument the interactive TUI starts as usual. Given one or more targets,
+/// it scans them without the TUI: reports go to stdout (JSON by default) and a status line per
+/// target to stderr.
+#[derive(Debug, Parser)]
+#[command(version)]
+pub struct Cli {
+    /// Domains or URLs to scan headlessly, one after another. `-` reads them from stdin, one
+    /// per line, and writes the JSON reports as JSON lines.
+    #[arg(value_name = "TARGET", conflicts_with = "load")]
+    pub targets: Vec<String>,
+
+    /// Open a previously exported JSON report instead of running a live scan.
+    #[arg(long, value_name = "REPORT.json")]
+    pub load: Option<PathBuf>,
+
+    /// Only include findings at or above this severity in exports (critical, warning, info).
+    /// Overrides `export_min_severity` from the config file.
+    #[arg(long, value_name = "LEVEL")]
+    pub min_severity: Option<Severity>,
+
+    /// Score against a baseline profile (general, ecommerce, blog) instead of `scoring_profile`
+    /// from the config file.
+    #[arg(long, value_name = "PROFILE")]
+    pub profile: Option<ScoringProfile>,
+
+    /// Also look for separate RSA and ECDSA certificates (two extra TLS handshakes per target).
+    #[arg(long)]
+    pub dual_cert: bool,
+
+    /// Fingerprint from the response headers only (a HEAD request), without downloading the page.
+    #[arg(long)]
+    pub quick_fingerprint: bool,
+
+    /// Only connect to the targets: refuse any other host and log every host contacted,
+    /// like `offline` in the config file.
+    #[arg(long)]
+    pub offline: bool,
+
+    /// Count every Warning finding as Critical in the score and counts, like `strict` in the config file.
+    #[arg(long)]
+    pub strict: bool,
+
+    /// Skip these scanners (comma-separated or repeated), overriding the `scan_*` config flags.
+    #[arg(long, value_enum, value_delimiter = ',', value_name = "SCANNER")]
+    pub skip: Vec<Scanner>,
+
+    /// How headless reports are written to stdout.
+    #[arg(long, value_enum, default_value_t = OutputFormat::Json)]
+    pub format: OutputFormat,
+
+    /// Write JSON reports on a single line instead of pretty-printed, both with `--format json`
+    /// and in `--export-dir`, for machine ingestion and large batch runs.
+    #[arg(long, requires = "targets")]
+    pub compact: bool,
+
+    /// Print what would be scanned instead of scanning: for each target, the normalized host,
+    /// the scanners that would run and the requests they would send, as JSON. Nothing is sent.
+    #[arg(long, requires = "targets", conflicts_with_all = ["until_clean", "summary", "export_dir"])]
+    pub dry_run: bool,
+
+    /// After scanning every target, write an overview of the whole run to this file:
+    /// HTML if it ends in `.html`, Markdown otherwise.
+    #[arg(long, value_name = "FILE")]
+    pub summary: Option<PathBuf>,
+
+    /// Also save each headless report as a timestamped JSON file in this directory,
+    /// the same file the TUI exports with `e`. The directory is created if needed.
+    #[arg(long, value_name = "DIR")]
+    pub export_dir: Option<PathBuf>,
+
+    /// Rescan the targets every `--interval` seconds until none reports a Critical or Warning
+    /// finding, e.g. to wait for a DNS or certificate fix to propagate. Exits 0 once clean, and
+    /// with the not-clean code when `--max-attempts` or `--timeout` runs out first. No report is
+    /// written, only a status line per attempt, so the report options are refused.
+    #[arg(long, requires = "targets", conflicts_with_all = ["summary", "export_dir", "format", "compact"])]
+    pub until_clean: bool,
+
+    /// Seconds between two attempts of `--until-clean`.
+    #[arg(long, value_name = "SECS", default_value_t = 60, requires = "until_clean")]
+    pub interval: u64,
+
+    /// Give `--until-clean` up after this many attempts. Unlimited by default.
+    #[arg(long, value_name = "N", requires = "until_clean", value_parser = clap::value_parser!(u32).range(1..))]
+    pub max_attempts: Option<u32>,
+
+    /// Give `--until-clean` up once this many seconds have passed. Unlimited by default.
+    #[arg(long, value_name = "SECS", requires = "until_clean")]
+    pub timeout: Option<u64>,
+
+    /// Never use ANSI colors in the table output. Colors are also off when stdout is not a terminal.
+    #[arg(long)]
+    pub no_color: bool,
+
+    /// Print a trend report from the findings database instead of scanning, one tab-separated
+    /// line per match: `open:CODE` lists the targets whose latest scan reports CODE
+    /// (e.g. `open:DNS_DMARC_MISSING`), `fixed-this-month` the findings fixed since the 1st.
+    #[cfg(feature = "findings-db")]
+    #[arg(long, value_name = "QUERY", conflicts_with_all = ["targets", "load"])]
+    pub query: Option<crate::findings_db::TrendQuery>,
+}
+
+/// The scanners that can be skipped with `--skip`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Scanner {
+    Dns,
+    Ssl,
+    /// The HTTP headers scan, including the redirect checks.
+    Headers,
+    Fingerprint,
+}
+
+/// The formats headless mode can write reports in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// The full report as pretty-printed JSON (minified with `--compact`), for machines.
+    Json,
+    /// The full report as JSON on a single line per target (JSON lines), for pipelines.
+    Jsonl,
+    /// A summary and a table of findings, for people.
+    Table,
+    /// Only the detected technologies as CSV (target, name, category, version), for asset inventories.
+    Tech,
+    /// The Critical and Warning findings as a Markdown task list with their remediation, to hand off.
+    Checklist,
+}
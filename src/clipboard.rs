@@ -0,0 +1,56 @@
+// src/clipboard.rs
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+use std::sync::Mutex;
+
+/// The process-wide clipboard handle. On X11 the copied text is served by this process,
+/// so the handle must outlive the call that copied it or the text disappears with it.
+static CLIPBOARD: Mutex<Option<arboard::Clipboard>> = Mutex::new(None);
+
+/// Wayland clipboard programs, tried when the native clipboard is unavailable
+/// (e.g. a Wayland session without XWayland). They read the text from stdin.
+const CLIPBOARD_COMMANDS: &[(&str, &[&str])] = &[("wl-copy", &[])];
+
+/// Copies `text` to the system clipboard.
+///
+/// Returns an error naming why the copy failed when no clipboard is available (e.g. over SSH
+/// or on a headless box); callers should show it along with the text so it can be copied by hand.
+pub fn copy(text: &str) -> Result<(), String> {
+    let native_error = match copy_native(text) {
+        Ok(()) => return Ok(()),
+        Err(e) => e,
+    };
+    for (program, args) in CLIPBOARD_COMMANDS {
+        if pipe_to(program, args, text).is_ok() {
+            return Ok(());
+        }
+    }
+    let programs: Vec<&str> = CLIPBOARD_COMMANDS.iter().map(|(program, _)| *program).collect();
+    Err(format!("{}, and {} did not work either", native_error, programs.join(", ")))
+}
+
+/// Copies `text` with the platform clipboard API, keeping the handle alive for later copies.
+fn copy_native(text: &str) -> Result<(), arboard::Error> {
+    let mut guard = CLIPBOARD.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    let clipboard = match guard.as_mut() {
+        Some(clipboard) => clipboard,
+        None => guard.insert(arboard::Clipboard::new()?),
+    };
+    clipboard.set_text(text)
+}
+
+/// Runs a clipboard program and writes `text` to its stdin.
+fn pipe_to(program: &str, args: &[&str], text: &str) -> std::io::Result<()> {
+    let mut child = Command::new(program)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(text.as_bytes())?;
+    }
+    let status = child.wait()?;
+    if status.success() { Ok(()) } else { Err(std::io::Error::other(format!("{} exited with {}", program, status))) }
+}
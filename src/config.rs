@@ -0,0 +1,288 @@
+// src/config.rs
+
+use crate::core::knowledge_base;
+use crate::core::models::{AnalysisFinding, CustomHeaderCheck, DohProvider, ScanReport, ScoringProfile, Severity, WebServer};
+use crate::core::scanner::{EnabledScanners, ExpiryWindows, ScanOptions};
+use crate::logging;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::net::IpAddr;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tracing::{info, warn};
+
+/// The name of the configuration file inside the application's config directory.
+pub const CONFIG_FILE: &str = "config.json";
+
+/// The database files looked up in the data directory when `geoip_databases` is not set.
+const DEFAULT_GEOIP_DATABASES: &[&str] = &["GeoLite2-ASN.mmdb", "GeoLite2-Country.mmdb"];
+
+/// User-tunable settings, loaded once at startup from `config.json`.
+///
+/// Every field has a sensible default, so a missing file (or a file that only
+/// sets a few keys) behaves exactly like an unconfigured installation.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    /// Maps a finding code (e.g. "HEADERS_CSP_MISSING") to the severity it should be
+    /// reported and scored with, overriding the knowledge-base default.
+    pub severity_overrides: HashMap<String, Severity>,
+    /// When set, exported reports only list findings at or above this severity.
+    /// The score and counts shown in the UI always use the full set.
+    pub export_min_severity: Option<Severity>,
+    /// Finding codes that are accepted risks for every target. They are hidden from the
+    /// findings list and do not count towards the score.
+    pub ignored_findings: Vec<String>,
+    /// Like `ignored_findings`, but scoped to a single target host (e.g. "example.com").
+    pub ignored_findings_by_target: HashMap<String, Vec<String>>,
+    /// Probe for separate RSA and ECDSA certificates. Off by default as it costs two extra handshakes.
+    pub probe_dual_certificates: bool,
+    /// Test TLS session resumption. Off by default as it costs two extra handshakes.
+    pub probe_session_resumption: bool,
+    /// Run the active probes, which send crafted requests to the target: open redirect parameters,
+    /// a `TRACE` request, a foreign `Origin`, a missing page and the default admin panel paths.
+    /// Off by default; only enable it for targets you are authorized to test.
+    pub active_probes: bool,
+    /// Scan the host the main page redirects to when it belongs to the same domain (e.g.
+    /// `www.example.com` for `example.com`), instead of the requested one. `F3` toggles it in the TUI.
+    pub follow_canonical_redirect: bool,
+    /// Fingerprint with a `HEAD` request and only the header and cookie rules, skipping the page
+    /// download and the rules that look inside it. Off by default.
+    pub quick_fingerprint: bool,
+    /// How many KiB of the page the fingerprint scan reads; the rest is not downloaded. 512 when
+    /// unset, which covers the head of the document, where the signatures are.
+    pub fingerprint_body_limit_kb: Option<usize>,
+    /// MaxMind-format (`.mmdb`) databases used to look up the ASN and country of scanned hosts.
+    /// When empty, `GeoLite2-ASN.mmdb` and `GeoLite2-Country.mmdb` in the data directory are used.
+    pub geoip_databases: Vec<PathBuf>,
+    /// A local reverse-IP dataset of `IP,DOMAIN` (or `IP,COUNT`) lines, used to count the domains
+    /// sharing the scanned host's address and report shared hosting. Unset by default.
+    pub reverse_ip_dataset: Option<PathBuf>,
+    /// Headers required by the organization on top of the built-in checks, each reported
+    /// with its own severity when missing (e.g. `{"name": "X-Permitted-Cross-Domain-Policies", "severity": "Info"}`).
+    pub custom_headers: Vec<CustomHeaderCheck>,
+    /// Send every DNS lookup to a DNS-over-HTTPS service ("Cloudflare" or "Google"), for networks
+    /// where plain DNS is filtered or logged. Plain DNS is used when unset.
+    pub dns_over_https: Option<DohProvider>,
+    /// Nameserver IPs to send DNS lookups to instead (e.g. `["10.0.0.53"]`), to test an internal or
+    /// authoritative server directly. Takes precedence over `dns_over_https`.
+    pub dns_nameservers: Vec<String>,
+    /// When to ring the terminal bell as a scan (or a multi-target run) finishes. Off by default.
+    pub completion_bell: CompletionBell,
+    /// The baseline the score is computed against ("General", "Ecommerce" or "Blog").
+    /// `General` weighs every finding by its severity alone.
+    pub scoring_profile: ScoringProfile,
+    /// Turns individual scanners off (`scan_dns`, `scan_ssl`, `scan_headers`, `scan_fingerprint`),
+    /// e.g. to skip the fingerprint scan, which downloads the full page. All are on by default.
+    #[serde(flatten)]
+    pub scanners: EnabledScanners,
+    /// How many days ahead an expiring certificate is reported: as a Warning within
+    /// `cert_expiry_warning_days` (30 by default), and as Critical within `cert_expiry_critical_days` if set.
+    #[serde(flatten)]
+    pub cert_expiry: ExpiryWindows,
+    /// Report a certificate issued less than this many hours ago as `SSL_RECENTLY_ISSUED` (e.g. 48),
+    /// to notice unexpected re-issuance when watching a site. Off when unset, as renewals are routine.
+    pub cert_recently_issued_hours: Option<i64>,
+    /// Report a session cookie set to live longer than this many days as `COOKIE_LONG_LIVED`.
+    /// Defaults to 30 when unset.
+    pub cookie_max_lifetime_days: Option<u64>,
+    /// The minimum time, in milliseconds, between two HTTP requests to the scanned host, shared by
+    /// every HTTP-based check. A request and the redirects followed for it count as one.
+    /// 0 (the default) sends them without waiting.
+    pub request_delay_ms: u64,
+    /// How many scanners of one scan run at the same time; 1 runs them one after another.
+    /// When unset, all of them run at once.
+    pub scan_concurrency: Option<usize>,
+    /// The web server remediation config snippets are written for ("Nginx" or "Apache").
+    /// When unset, it is taken from the fingerprint results.
+    pub web_server: Option<WebServer>,
+    /// Only connect to the target: connections to any other host (redirects to another domain,
+    /// public DNS resolvers, DNS-over-HTTPS) are refused, and every host contacted is logged and
+    /// recorded in the report. For air-gapped or sensitive engagements. Off by default.
+    pub offline: bool,
+    /// Escalates every Warning finding to Critical, for environments where any deviation is a
+    /// hard failure. It changes the score and counts, not the knowledge-base definitions.
+    pub strict: bool,
+}
+
+/// When the TUI rings the terminal bell on completion.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CompletionBell {
+    #[default]
+    Off,
+    /// Ring after every scan.
+    Always,
+    /// Ring only when a Critical finding was reported.
+    OnCritical,
+}
+
+impl Config {
+    /// Returns the full path of the configuration file.
+    pub fn path() -> PathBuf {
+        logging::get_config_dir().join(CONFIG_FILE)
+    }
+
+    /// Loads the configuration from disk.
+    ///
+    /// A missing file yields the default configuration. A file that cannot be parsed is
+    /// logged and ignored rather than aborting startup.
+    pub fn load() -> Self {
+        let config = Self::try_load().unwrap_or_else(|e| {
+            warn!(path = %Self::path().display(), error = %e, "Invalid configuration file, using defaults.");
+            Config::default()
+        });
+        config.validate();
+        config
+    }
+
+    /// Loads the configuration from disk like `load`, but fails on a file that cannot be read
+    /// or parsed instead of falling back to the defaults. Use it before `save`, which would
+    /// otherwise replace such a file and lose every setting in it.
+    pub fn try_load() -> io::Result<Self> {
+        Self::load_from(&Self::path())
+    }
+
+    /// Loads the configuration file at `path`; a missing file yields the defaults.
+    fn load_from(path: &Path) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(content) => {
+                let config = serde_json::from_str(&content)
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, format!("{}: {}", path.display(), e)))?;
+                info!(path = %path.display(), "Configuration loaded.");
+                Ok(config)
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Config::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// The scan options derived from this configuration.
+    pub fn scan_options(&self) -> ScanOptions {
+        ScanOptions {
+            probe_dual_certificates: self.probe_dual_certificates,
+            probe_session_resumption: self.probe_session_resumption,
+            active_probes: self.active_probes,
+            follow_canonical_redirect: self.follow_canonical_redirect,
+            quick_fingerprint: self.quick_fingerprint,
+            fingerprint_body_limit: self.fingerprint_body_limit_kb.map(|kb| kb.saturating_mul(1024)),
+            custom_headers: self.custom_headers.clone(),
+            accept_invalid_certs: false,
+            dns_over_https: self.dns_over_https,
+            dns_nameservers: self.dns_nameserver_ips(),
+            scanners: self.scanners,
+            cert_expiry: self.cert_expiry,
+            cert_recently_issued_hours: self.cert_recently_issued_hours,
+            cookie_max_lifetime_days: self.cookie_max_lifetime_days,
+            request_delay: Duration::from_millis(self.request_delay_ms),
+            scan_concurrency: self.scan_concurrency,
+            offline: self.offline,
+        }
+    }
+
+    /// The configured nameservers as IP addresses. If any entry is not a valid IP the whole
+    /// list is dropped with a warning, so the scan falls back to `dns_over_https` or the default resolver.
+    fn dns_nameserver_ips(&self) -> Vec<IpAddr> {
+        let parsed: Result<Vec<IpAddr>, _> = self.dns_nameservers.iter().map(|ip| ip.trim().parse()).collect();
+        parsed.unwrap_or_else(|e| {
+            warn!(nameservers = ?self.dns_nameservers, error = %e, "Invalid DNS nameserver, ignoring the nameserver list.");
+            Vec::new()
+        })
+    }
+
+    /// The GeoIP databases to load: the configured ones, or the default files in the data directory.
+    pub fn geoip_database_paths(&self) -> Vec<PathBuf> {
+        if !self.geoip_databases.is_empty() {
+            return self.geoip_databases.clone();
+        }
+        let data_dir = logging::get_data_dir();
+        DEFAULT_GEOIP_DATABASES.iter().map(|name| data_dir.join(name)).collect()
+    }
+
+    /// Writes the configuration back to disk, creating the config directory if needed.
+    pub fn save(&self) -> io::Result<()> {
+        let path = Self::path();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&path, serde_json::to_string_pretty(self)?)?;
+        info!(path = %path.display(), "Configuration saved.");
+        Ok(())
+    }
+
+    /// Warns about configuration entries that reference unknown finding codes.
+    /// These entries are kept (they are harmless) but will never match anything.
+    fn validate(&self) {
+        for code in self.severity_overrides.keys() {
+            if knowledge_base::get_finding_detail(code).is_none() {
+                warn!(code = %code, "Severity override refers to an unknown finding code.");
+            }
+        }
+        let ignored_codes = self.ignored_findings.iter()
+            .chain(self.ignored_findings_by_target.values().flatten());
+        for code in ignored_codes {
+            if knowledge_base::get_finding_detail(code).is_none() {
+                warn!(code = %code, "Ignore list refers to an unknown finding code.");
+            }
+        }
+    }
+
+    /// Applies the severity overrides, the ignore list of `target_host` and the scoring profile
+    /// to a report in place, so it scores the same as it would in the TUI.
+    pub fn apply_to_report(&self, target_host: &str, report: &mut ScanReport) {
+        report.scoring_profile = self.scoring_profile;
+        for analysis in report.analyses_mut() {
+            analysis.retain(|finding| !self.is_ignored(target_host, &finding.code));
+            analysis.iter_mut().for_each(|finding| self.apply_severity_override(finding));
+        }
+    }
+
+    /// Returns `true` if the finding code is on the global ignore list or on the list of `target_host`.
+    pub fn is_ignored(&self, target_host: &str, code: &str) -> bool {
+        self.ignored_findings.iter().any(|ignored| ignored == code)
+            || self.ignored_findings_by_target.iter()
+                .filter(|(target, _)| target.eq_ignore_ascii_case(target_host))
+                .any(|(_, codes)| codes.iter().any(|ignored| ignored == code))
+    }
+
+    /// Adds a finding code to the ignore list of a single target host.
+    pub fn ignore_for_target(&mut self, target_host: &str, code: &str) {
+        let codes = self.ignored_findings_by_target.entry(target_host.to_lowercase()).or_default();
+        if !codes.iter().any(|ignored| ignored == code) {
+            codes.push(code.to_string());
+        }
+    }
+
+    /// Applies the configured severity overrides to a finding in place, then the strict-mode
+    /// escalation, so an override to Warning is escalated too.
+    pub fn apply_severity_override(&self, finding: &mut AnalysisFinding) {
+        if let Some(severity) = self.severity_overrides.get(&finding.code) {
+            finding.severity = severity.clone();
+        }
+        if self.strict && finding.severity == Severity::Warning {
+            finding.escalated_from = Some(Severity::Warning);
+            finding.severity = Severity::Critical;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn load_from_refuses_an_invalid_file() {
+        let dir = std::env::temp_dir().join(format!("vanguard-config-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(CONFIG_FILE);
+        assert!(Config::load_from(&path).unwrap().ignored_findings.is_empty());
+
+        fs::write(&path, r#"{ "ignored_findings": ["DNS_CAA_MISSING"] }"#).unwrap();
+        assert_eq!(Config::load_from(&path).unwrap().ignored_findings, ["DNS_CAA_MISSING"]);
+
+        fs::write(&path, r#"{ "ignored_findings": ["DNS_CAA_MISSING"], }"#).unwrap();
+        assert_eq!(Config::load_from(&path).unwrap_err().kind(), io::ErrorKind::InvalidData);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
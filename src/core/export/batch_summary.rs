@@ -0,0 +1,202 @@
+// src/core/export/batch_summary.rs
+
+use crate::core::export::text_table;
+use crate::core::knowledge_base;
+use crate::core::models::{ScanReport, Severity, grade};
+use chrono::{DateTime, Local};
+use std::collections::{BTreeMap, HashSet};
+use std::path::Path;
+
+/// The formats a batch summary can be written in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SummaryFormat {
+    Markdown,
+    Html,
+}
+
+impl SummaryFormat {
+    /// Picks the format from a file extension: `.html`/`.htm` is HTML, anything else Markdown.
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("html") || ext.eq_ignore_ascii_case("htm") => SummaryFormat::Html,
+            _ => SummaryFormat::Markdown,
+        }
+    }
+}
+
+/// One row of the overview table.
+struct TargetRow<'a> {
+    target: &'a str,
+    /// The id of the target's details section, unique within the summary.
+    anchor: String,
+    report: &'a ScanReport,
+    score: u8,
+    critical: usize,
+    warning: usize,
+    info: usize,
+}
+
+/// Renders one overview of a multi-target run: every target's score, grade and finding
+/// counts, worst first, followed by the average score and the most common issue.
+/// Each row links to a details section holding that target's findings table.
+///
+/// # Arguments
+/// * `reports` - Each scanned host with its report, in scan order.
+/// * `min_severity` - When set, the details sections only list findings at or above this severity.
+/// * `generated_at` - The time the summary is stamped with.
+/// * `format` - Markdown or HTML.
+pub fn render(
+    reports: &[(String, ScanReport)],
+    min_severity: Option<&Severity>,
+    generated_at: DateTime<Local>,
+    format: SummaryFormat,
+) -> String {
+    let mut rows: Vec<TargetRow> = reports.iter().map(|(target, report)| {
+        let count = |severity: Severity| report.findings().filter(|finding| finding.severity == severity).count();
+        TargetRow {
+            target,
+            anchor: String::new(),
+            report,
+            score: report.score(),
+            critical: count(Severity::Critical),
+            warning: count(Severity::Warning),
+            info: count(Severity::Info),
+        }
+    }).collect();
+    rows.sort_by(|a, b| a.score.cmp(&b.score).then_with(|| a.target.cmp(b.target)));
+    let mut used = HashSet::new();
+    for row in &mut rows {
+        row.anchor = unique_anchor(row.target, &mut used);
+    }
+
+    let timestamp = generated_at.format("%Y-%m-%d %H:%M:%S").to_string();
+    let average = if rows.is_empty() {
+        0.0
+    } else {
+        rows.iter().map(|row| f64::from(row.score)).sum::<f64>() / rows.len() as f64
+    };
+    let most_common = most_common_issue(reports).map(|(code, targets)| {
+        // Templated titles name a single subject, which differs between targets, so fall back to the code.
+        let title = knowledge_base::get_finding_detail(&code)
+            .filter(|detail| !detail.title.contains("{subject}"))
+            .map_or(code.clone(), |detail| detail.title.to_string());
+        format!("{} ({}), on {} of {} targets", title, code, targets, reports.len())
+    }).unwrap_or_else(|| "none".to_string());
+
+    match format {
+        SummaryFormat::Markdown => render_markdown(&rows, min_severity, &timestamp, average, &most_common),
+        SummaryFormat::Html => render_html(&rows, min_severity, &timestamp, average, &most_common),
+    }
+}
+
+/// Returns the Critical or Warning finding code reported for the most targets, with that number.
+/// Ties go to the alphabetically first code, so the summary is stable across runs.
+fn most_common_issue(reports: &[(String, ScanReport)]) -> Option<(String, usize)> {
+    let mut targets_per_code: BTreeMap<&str, usize> = BTreeMap::new();
+    for (_, report) in reports {
+        let mut codes: Vec<&str> = report.findings()
+            .filter(|finding| finding.severity != Severity::Info)
+            .map(|finding| finding.code.as_str())
+            .collect();
+        codes.sort_unstable();
+        codes.dedup();
+        for code in codes {
+            *targets_per_code.entry(code).or_default() += 1;
+        }
+    }
+    targets_per_code.into_iter()
+        .max_by(|a, b| a.1.cmp(&b.1).then_with(|| b.0.cmp(a.0)))
+        .map(|(code, targets)| (code.to_string(), targets))
+}
+
+/// The anchor of a target's details section, e.g. "example-com". Targets that map to an anchor
+/// already in `used` (the same target scanned twice, or "example.com" and "example-com") get a
+/// counter, e.g. "example-com-2".
+fn unique_anchor(target: &str, used: &mut HashSet<String>) -> String {
+    let base: String = target.chars().map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_lowercase() } else { '-' }).collect();
+    let mut anchor = base.clone();
+    let mut counter = 1;
+    while !used.insert(anchor.clone()) {
+        counter += 1;
+        anchor = format!("{}-{}", base, counter);
+    }
+    anchor
+}
+
+fn render_markdown(rows: &[TargetRow], min_severity: Option<&Severity>, timestamp: &str, average: f64, most_common: &str) -> String {
+    let mut out = String::from("# Vanguard RS Batch Summary\n\n");
+    out.push_str(&format!("- Generated: {}\n", timestamp));
+    out.push_str(&format!("- Targets scanned: {}\n", rows.len()));
+    out.push_str(&format!("- Average score: {:.1}\n", average));
+    out.push_str(&format!("- Most common issue: {}\n\n", most_common));
+
+    out.push_str("| Target | Score | Grade | Critical | Warning | Info |\n");
+    out.push_str("|---|---:|:---:|---:|---:|---:|\n");
+    for row in rows {
+        out.push_str(&format!(
+            "| [{}](#{}) | {} | {} | {} | {} | {} |\n",
+            row.target, row.anchor, row.score, grade(row.score), row.critical, row.warning, row.info,
+        ));
+    }
+
+    out.push_str("\n## Details\n");
+    for row in rows {
+        out.push_str(&format!("\n<a id=\"{}\"></a>\n### {}\n\n```text\n", row.anchor, row.target));
+        out.push_str(&text_table::render(row.target, row.report, min_severity, false));
+        out.push_str("```\n");
+    }
+    out
+}
+
+fn render_html(rows: &[TargetRow], min_severity: Option<&Severity>, timestamp: &str, average: f64, most_common: &str) -> String {
+    let mut out = String::from(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<title>Vanguard RS Batch Summary</title>\n\
+         <style>body{font-family:sans-serif;margin:2em}table{border-collapse:collapse}\
+         th,td{border:1px solid #ccc;padding:4px 10px}td.num{text-align:right}pre{background:#f5f5f5;padding:1em}</style>\n\
+         </head>\n<body>\n<h1>Vanguard RS Batch Summary</h1>\n<ul>\n",
+    );
+    out.push_str(&format!("<li>Generated: {}</li>\n", timestamp));
+    out.push_str(&format!("<li>Targets scanned: {}</li>\n", rows.len()));
+    out.push_str(&format!("<li>Average score: {:.1}</li>\n", average));
+    out.push_str(&format!("<li>Most common issue: {}</li>\n</ul>\n", escape_html(most_common)));
+
+    out.push_str("<table>\n<tr><th>Target</th><th>Score</th><th>Grade</th><th>Critical</th><th>Warning</th><th>Info</th></tr>\n");
+    for row in rows {
+        out.push_str(&format!(
+            "<tr><td><a href=\"#{}\">{}</a></td><td class=\"num\">{}</td><td>{}</td><td class=\"num\">{}</td><td class=\"num\">{}</td><td class=\"num\">{}</td></tr>\n",
+            row.anchor, escape_html(row.target), row.score, grade(row.score), row.critical, row.warning, row.info,
+        ));
+    }
+    out.push_str("</table>\n<h2>Details</h2>\n");
+    for row in rows {
+        out.push_str(&format!("<h3 id=\"{}\">{}</h3>\n<pre>", row.anchor, escape_html(row.target)));
+        out.push_str(&escape_html(&text_table::render(row.target, row.report, min_severity, false)));
+        out.push_str("</pre>\n");
+    }
+    out.push_str("</body>\n</html>\n");
+    out
+}
+
+/// Escapes the characters that would otherwise be read as HTML markup.
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn targets_with_the_same_anchor_link_to_their_own_section() {
+        let reports: Vec<(String, ScanReport)> = ["example.com", "example-com", "example.com"].iter()
+            .map(|target| (target.to_string(), ScanReport::default()))
+            .collect();
+        for format in [SummaryFormat::Markdown, SummaryFormat::Html] {
+            let summary = render(&reports, None, Local::now(), format);
+            for anchor in ["example-com", "example-com-2", "example-com-3"] {
+                assert_eq!(summary.matches(&format!("\"#{}\"", anchor)).count() + summary.matches(&format!("(#{})", anchor)).count(), 1, "{:?}", format);
+                assert_eq!(summary.matches(&format!("id=\"{}\"", anchor)).count(), 1, "{:?}", format);
+            }
+        }
+    }
+}
@@ -0,0 +1,53 @@
+// src/core/export/checklist.rs
+
+use crate::core::knowledge_base::{self, FindingCategory};
+use crate::core::models::{AnalysisFinding, ScanReport, Severity};
+use std::collections::BTreeMap;
+
+/// Renders the actionable findings of a report as a Markdown task list, ready to hand off.
+///
+/// Only Critical and Warning findings are listed, each as a `- [ ]` line with its title and
+/// remediation from the knowledge base. They are grouped by category; the groups holding the
+/// most urgent items come first, and each group lists its Critical items before its Warnings.
+///
+/// # Arguments
+/// * `target` - The scanned host, used in the heading.
+/// * `report` - The report whose findings are listed.
+///
+/// # Returns
+/// The rendered Markdown, ending with a newline.
+pub fn render(target: &str, report: &ScanReport) -> String {
+    let mut groups: BTreeMap<FindingCategory, Vec<&AnalysisFinding>> = BTreeMap::new();
+    for finding in report.findings().filter(|finding| finding.severity != Severity::Info) {
+        let category = knowledge_base::get_finding_detail(&finding.code).map_or(FindingCategory::Http, |detail| detail.category);
+        groups.entry(category).or_default().push(finding);
+    }
+    let mut groups: Vec<(FindingCategory, Vec<&AnalysisFinding>)> = groups.into_iter().collect();
+    for (_, findings) in &mut groups {
+        findings.sort_by_key(|finding| finding.severity.rank());
+    }
+    // Groups are non-empty and sorted, so their first item is their most urgent one.
+    groups.sort_by_key(|(category, findings)| (findings[0].severity.rank(), *category));
+
+    let mut out = format!("# Remediation checklist: {}\n", target);
+    if groups.is_empty() {
+        out.push_str("\nNothing to fix: no Critical or Warning findings.\n");
+        return out;
+    }
+    for (category, findings) in groups {
+        out.push_str(&format!("\n## {}\n\n", category));
+        for finding in findings {
+            let line = match knowledge_base::get_finding_detail(&finding.code) {
+                Some(detail) => format!(
+                    "**{}** ({:?}): {}",
+                    finding.fill_template(detail.title),
+                    finding.severity,
+                    finding.fill_template(detail.remediation),
+                ),
+                None => format!("**{}** ({:?})", finding.code, finding.severity),
+            };
+            out.push_str(&format!("- [ ] {}\n", line));
+        }
+    }
+    out
+}
@@ -0,0 +1,145 @@
+// src/core/export/mod.rs
+
+// Renderers that turn a `ScanReport` into formats meant to be read outside the TUI.
+
+/// A plain-text summary and findings table for terminals, with optional ANSI colors.
+pub mod text_table;
+/// A Markdown or HTML overview of a multi-target run.
+pub mod batch_summary;
+/// A CSV list of the detected technologies, for asset inventories.
+pub mod tech_inventory;
+/// A Markdown task list of the Critical and Warning findings, for handing off fixes.
+pub mod checklist;
+
+use crate::core::models::ScanReport;
+use chrono::{DateTime, Local};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use tracing::warn;
+
+/// The ways a report can be written to disk.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// A single JSON file, pretty-printed unless `write_report` is asked for compact JSON.
+    Json,
+    /// A directory holding the report as `report.json` and a copy of `log_file`, for sharing
+    /// a problematic scan. A missing log is skipped, since the report is still worth sharing.
+    Bundle { log_file: PathBuf },
+    /// Only the detected technologies, as CSV (see `tech_inventory`).
+    TechnologiesCsv,
+    /// The actionable findings as a Markdown task list (see `checklist`).
+    Checklist,
+}
+
+/// Writes a report into `dir` (created if needed) under a timestamped name derived from the
+/// target, e.g. "example.com-20250101_120000.json", "example.com-20250101_120000-bundle" or
+/// "example.com-20250101_120000-tech.csv" (and "-checklist.md").
+///
+/// The report is written as given; severity filtering and notes are up to the caller. With
+/// `compact`, the JSON of `Json` and `Bundle` is minified (see `to_json`).
+/// Returns the path of the written file or bundle directory.
+pub fn write_report(report: &ScanReport, target: &str, format: &ExportFormat, dir: &Path, compact: bool) -> io::Result<PathBuf> {
+    fs::create_dir_all(dir)?;
+    let basename = export_basename(target, Local::now());
+    match format {
+        ExportFormat::Json => {
+            let path = dir.join(format!("{}.json", basename));
+            fs::write(&path, to_json(report, compact)?)?;
+            Ok(path)
+        }
+        ExportFormat::Bundle { log_file } => {
+            let path = dir.join(format!("{}-bundle", basename));
+            fs::create_dir_all(&path)?;
+            fs::write(path.join("report.json"), to_json(report, compact)?)?;
+            let log_name = log_file.file_name().unwrap_or(log_file.as_os_str());
+            match fs::copy(log_file, path.join(log_name)) {
+                Ok(_) => {}
+                Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                    warn!(path = %log_file.display(), "No log file to bundle.");
+                }
+                Err(e) => return Err(e),
+            }
+            Ok(path)
+        }
+        ExportFormat::TechnologiesCsv => {
+            let path = dir.join(format!("{}-tech.csv", basename));
+            // A failed fingerprint scan leaves nothing to list but the header.
+            let technologies = report.fingerprint_results.technologies.as_deref().unwrap_or_default();
+            fs::write(&path, tech_inventory::render_csv(target, technologies, true))?;
+            Ok(path)
+        }
+        ExportFormat::Checklist => {
+            let path = dir.join(format!("{}-checklist.md", basename));
+            fs::write(&path, checklist::render(target, report))?;
+            Ok(path)
+        }
+    }
+}
+
+/// Serializes a report as JSON: pretty-printed for people, or on a single line with `compact`,
+/// which is much smaller for machine ingestion and large batch runs.
+pub fn to_json(report: &ScanReport, compact: bool) -> serde_json::Result<String> {
+    if compact { serde_json::to_string(report) } else { serde_json::to_string_pretty(report) }
+}
+
+/// The name exports of `target` are saved under, without extension, e.g. "example.com-20250101_120000".
+/// A URL's scheme is dropped and its slashes replaced, so the name is a single path component.
+fn export_basename(target: &str, timestamp: DateTime<Local>) -> String {
+    let target_domain = target.split_once("://").unwrap_or(("", target)).1;
+    format!("{}-{}", target_domain.replace('/', "_"), timestamp.format("%Y%m%d_%H%M%S"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A fresh directory for one test, removed by the test once it is done.
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("vanguard-export-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn file_name(path: &Path) -> String {
+        path.file_name().unwrap().to_string_lossy().into_owned()
+    }
+
+    #[test]
+    fn names_each_export_after_the_target_and_its_format() {
+        let dir = temp_dir("formats");
+        let report = ScanReport::default();
+        let write = |format: ExportFormat| write_report(&report, "https://example.com/app", &format, &dir.join("nested"), false).unwrap();
+
+        let json = write(ExportFormat::Json);
+        assert!(file_name(&json).starts_with("example.com_app-") && file_name(&json).ends_with(".json"));
+        assert!(file_name(&write(ExportFormat::TechnologiesCsv)).ends_with("-tech.csv"));
+        assert!(file_name(&write(ExportFormat::Checklist)).ends_with("-checklist.md"));
+        // A missing log file is left out of the bundle.
+        let bundle = write(ExportFormat::Bundle { log_file: dir.join("missing.log") });
+        assert!(file_name(&bundle).ends_with("-bundle") && bundle.join("report.json").is_file());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn compact_json_fits_on_one_line() {
+        let dir = temp_dir("compact");
+        let report = ScanReport::default();
+        let compact = fs::read_to_string(write_report(&report, "example.com", &ExportFormat::Json, &dir.join("compact"), true).unwrap()).unwrap();
+        let pretty = fs::read_to_string(write_report(&report, "example.com", &ExportFormat::Json, &dir.join("pretty"), false).unwrap()).unwrap();
+        assert!(!compact.contains('\n') && pretty.lines().count() > 1);
+        assert_eq!(serde_json::from_str::<serde_json::Value>(&compact).unwrap(), serde_json::from_str::<serde_json::Value>(&pretty).unwrap());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn fails_when_the_directory_cannot_be_created() {
+        let dir = temp_dir("blocked");
+        fs::create_dir_all(&dir).unwrap();
+        // A file stands where the export directory would go.
+        let file = dir.join("taken");
+        fs::write(&file, "").unwrap();
+        assert!(write_report(&ScanReport::default(), "example.com", &ExportFormat::Json, &file.join("reports"), false).is_err());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,43 @@
+// src/core/export/tech_inventory.rs
+
+use crate::core::models::Technology;
+
+/// The header row of the inventory.
+const HEADER: &str = "target,name,category,version";
+
+/// Renders the detected technologies of a target as CSV rows (target, name, category, version),
+/// sorted by category then name. Unknown versions are left empty.
+///
+/// # Arguments
+/// * `target` - The scanned host, repeated on every row so inventories of several targets can be concatenated.
+/// * `technologies` - The technologies found by the fingerprint scan.
+/// * `header` - Whether to start with the header row.
+///
+/// # Returns
+/// The rendered CSV, one line per technology, each ending with a newline.
+pub fn render_csv(target: &str, technologies: &[Technology], header: bool) -> String {
+    let mut sorted: Vec<&Technology> = technologies.iter().collect();
+    sorted.sort_by(|a, b| a.category.cmp(&b.category).then_with(|| a.name.cmp(&b.name)));
+
+    let mut out = String::new();
+    if header {
+        out.push_str(HEADER);
+        out.push('\n');
+    }
+    for tech in sorted {
+        let fields = [target, tech.name.as_str(), tech.category.as_str(), tech.version.as_deref().unwrap_or_default()];
+        let row: Vec<String> = fields.iter().map(|field| escape_csv(field)).collect();
+        out.push_str(&row.join(","));
+        out.push('\n');
+    }
+    out
+}
+
+/// Quotes a field when it contains a separator, a quote or a line break, doubling inner quotes.
+fn escape_csv(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
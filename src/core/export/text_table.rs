@@ -0,0 +1,92 @@
+// src/core/export/text_table.rs
+
+use crate::core::knowledge_base::{self, FindingCategory};
+use crate::core::models::{AnalysisFinding, ScanReport, ScoringProfile, Severity, grade};
+use crossterm::style::{Color, Stylize};
+
+/// The column headers of the findings table.
+const HEADERS: [&str; 3] = ["SEVERITY", "CATEGORY", "TITLE"];
+
+/// Renders a report as a short summary followed by a table of its findings,
+/// most severe first. Titles come from the knowledge base.
+///
+/// # Arguments
+/// * `target` - The scanned host, shown in the summary.
+/// * `report` - The full report; the summary always reflects all of its findings.
+/// * `min_severity` - When set, only findings at or above this severity are listed.
+/// * `color` - Whether to use ANSI colors (disable for pipes and files).
+///
+/// # Returns
+/// The rendered text, ending with a newline.
+pub fn render(target: &str, report: &ScanReport, min_severity: Option<&Severity>, color: bool) -> String {
+    let paint = |text: String, fg: Color| if color { text.with(fg).to_string() } else { text };
+
+    let count = |severity: Severity| report.findings().filter(|finding| finding.severity == severity).count();
+    let score = report.score();
+    let profile = match report.scoring_profile {
+        ScoringProfile::General => String::new(),
+        profile => format!(", {} profile", profile),
+    };
+    let mut out = format!(
+        "{}  score {} ({}{})  {} critical, {} warning, {} info  [{} ms]\n",
+        if color { target.bold().to_string() } else { target.to_string() },
+        score,
+        grade(score),
+        profile,
+        count(Severity::Critical),
+        count(Severity::Warning),
+        count(Severity::Info),
+        report.duration_ms,
+    );
+    // Problems of the scan itself come first: the findings below may be incomplete because of them.
+    for warning in &report.warnings {
+        out.push_str(&paint(format!("scan diagnostic: {}", warning), Color::Yellow));
+        out.push('\n');
+    }
+
+    let mut findings: Vec<&AnalysisFinding> = report.findings()
+        .filter(|finding| min_severity.is_none_or(|min| finding.severity.is_at_least(min)))
+        .collect();
+    if findings.is_empty() {
+        out.push_str(&paint("No findings.".to_string(), Color::Green));
+        out.push('\n');
+        return out;
+    }
+    findings.sort_by_key(|finding| finding.severity.rank());
+
+    let rows: Vec<[String; 3]> = findings.iter().map(|finding| {
+        let detail = knowledge_base::get_finding_detail(&finding.code);
+        [
+            format!("{:?}", finding.severity),
+            detail.map_or(FindingCategory::Http, |detail| detail.category).to_string(),
+            detail.map_or_else(|| finding.code.clone(), |detail| finding.fill_template(detail.title)),
+        ]
+    }).collect();
+
+    // Pad before coloring, so the escape codes do not throw the alignment off.
+    let width = |column: usize| rows.iter()
+        .map(|row| row[column].chars().count())
+        .chain([HEADERS[column].len()])
+        .max()
+        .unwrap_or(0);
+    let (severity_width, category_width) = (width(0), width(1));
+
+    out.push('\n');
+    let header = format!("{:severity_width$}  {:category_width$}  {}", HEADERS[0], HEADERS[1], HEADERS[2]);
+    out.push_str(&if color { header.bold().to_string() } else { header });
+    out.push('\n');
+    for (finding, [severity, category, title]) in findings.iter().zip(rows) {
+        let severity_color = match finding.severity {
+            Severity::Critical => Color::Red,
+            Severity::Warning => Color::Yellow,
+            Severity::Info => Color::Cyan,
+        };
+        out.push_str(&format!(
+            "{}  {:category_width$}  {}\n",
+            paint(format!("{:severity_width$}", severity), severity_color),
+            category,
+            title,
+        ));
+    }
+    out
+}
@@ -0,0 +1,386 @@
+// src/core/geoip.rs
+
+//! Offline IP enrichment (ASN, AS organization and country) backed by MaxMind-format
+//! (`.mmdb`) databases such as GeoLite2-ASN and GeoLite2-Country.
+//!
+//! The databases are read once at startup and never leave the machine, so enrichment
+//! keeps the scan passive and self-contained. Only the subset of the MaxMind DB format
+//! needed for lookups is implemented here.
+
+use std::fs;
+use std::net::IpAddr;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use tracing::{debug, info, warn};
+
+/// The marker that precedes the metadata section at the end of every `.mmdb` file.
+const METADATA_MARKER: &[u8] = b"\xAB\xCD\xEFMaxMind.com";
+
+/// The 16 zero bytes separating the search tree from the data section.
+const DATA_SECTION_SEPARATOR: usize = 16;
+
+/// How deeply maps, arrays and pointers may nest in a record. Real records are a few levels
+/// deep; the limit stops a corrupt database whose pointers loop from recursing forever.
+const MAX_DEPTH: usize = 32;
+
+/// The databases loaded by `init`, shared by every scan.
+static DATABASES: OnceLock<Vec<Database>> = OnceLock::new();
+
+/// What the databases know about an IP address. Fields are `None` when no database has the data.
+#[derive(Debug, Default, PartialEq)]
+pub struct GeoInfo {
+    pub asn: Option<u32>,
+    pub as_org: Option<String>,
+    /// The ISO 3166-1 alpha-2 country code, e.g. "US".
+    pub country: Option<String>,
+}
+
+/// Loads the given databases. Files that are missing or invalid are logged and skipped,
+/// in which case lookups simply return nothing. Only the first call has an effect.
+pub fn init(paths: &[PathBuf]) {
+    let databases = paths.iter()
+        .filter(|path| {
+            // Enrichment is optional, so a database that is simply not installed is not worth a warning.
+            let exists = path.exists();
+            if !exists { debug!(path = %path.display(), "GeoIP database not found."); }
+            exists
+        })
+        .filter_map(|path| match Database::open(path) {
+            Ok(database) => {
+                info!(path = %path.display(), "GeoIP database loaded.");
+                Some(database)
+            }
+            Err(e) => {
+                warn!(path = %path.display(), error = %e, "GeoIP database unavailable.");
+                None
+            }
+        })
+        .collect();
+    let _ = DATABASES.set(databases);
+}
+
+/// Looks an IP address up in every loaded database and merges the answers.
+/// Returns `None` when no database is loaded.
+pub fn lookup(ip: IpAddr) -> Option<GeoInfo> {
+    let databases = DATABASES.get().filter(|databases| !databases.is_empty())?;
+    let mut info = GeoInfo::default();
+    for record in databases.iter().filter_map(|database| database.lookup(ip)) {
+        info.asn = info.asn.or_else(|| record.get("autonomous_system_number").and_then(Value::as_u32));
+        info.as_org = info.as_org.or_else(|| record.get("autonomous_system_organization").and_then(Value::as_string));
+        info.country = info.country.or_else(|| record.get("country").and_then(|c| c.get("iso_code")).and_then(Value::as_string));
+    }
+    Some(info)
+}
+
+/// A decoded value from the data section of a MaxMind database.
+/// Types the enrichment never reads (numbers other than unsigned, arrays, ...) are
+/// decoded only far enough to skip them.
+#[derive(Debug, Clone)]
+enum Value {
+    String(String),
+    Uint(u128),
+    Map(Vec<(String, Value)>),
+    Other,
+}
+
+impl Value {
+    /// Returns the value stored under `key` if this is a map.
+    fn get(&self, key: &str) -> Option<&Value> {
+        match self {
+            Value::Map(entries) => entries.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    fn as_u32(&self) -> Option<u32> {
+        match self {
+            Value::Uint(n) => u32::try_from(*n).ok(),
+            _ => None,
+        }
+    }
+
+    fn as_string(&self) -> Option<String> {
+        match self {
+            Value::String(s) => Some(s.clone()),
+            _ => None,
+        }
+    }
+}
+
+/// An in-memory MaxMind database.
+struct Database {
+    bytes: Vec<u8>,
+    node_count: usize,
+    record_size: usize,
+    ip_version: u16,
+}
+
+impl Database {
+    /// Reads a database file and parses its metadata.
+    fn open(path: &Path) -> Result<Self, String> {
+        Self::from_bytes(fs::read(path).map_err(|e| e.to_string())?)
+    }
+
+    /// Parses the metadata of a database read into memory.
+    fn from_bytes(bytes: Vec<u8>) -> Result<Self, String> {
+        let marker = bytes.windows(METADATA_MARKER.len())
+            .rposition(|window| window == METADATA_MARKER)
+            .ok_or("not a MaxMind database (metadata marker not found)")?;
+        let metadata_start = marker + METADATA_MARKER.len();
+        let (metadata, _) = Decoder { bytes: &bytes, base: metadata_start }.decode(metadata_start)?;
+
+        let field = |name: &str| match metadata.get(name) {
+            Some(Value::Uint(n)) => usize::try_from(*n).map_err(|_| format!("metadata field '{}' out of range", name)),
+            _ => Err(format!("metadata field '{}' missing", name)),
+        };
+        let database = Self {
+            node_count: field("node_count")?,
+            record_size: field("record_size")?,
+            ip_version: u16::try_from(field("ip_version")?).map_err(|_| "metadata field 'ip_version' out of range")?,
+            bytes,
+        };
+        if ![24, 28, 32].contains(&database.record_size) {
+            return Err(format!("unsupported record size {}", database.record_size));
+        }
+        if database.data_section_start().is_none_or(|start| start > database.bytes.len()) {
+            return Err("search tree larger than the database".to_string());
+        }
+        Ok(database)
+    }
+
+    /// Where the data section starts: right after the search tree and its separator.
+    /// `None` when the node count read from the metadata overflows.
+    fn data_section_start(&self) -> Option<usize> {
+        self.node_count.checked_mul(self.record_size / 4)?.checked_add(DATA_SECTION_SEPARATOR)
+    }
+
+    /// Walks the search tree bit by bit and decodes the record the address points to.
+    fn lookup(&self, ip: IpAddr) -> Option<Value> {
+        let bits: Vec<bool> = match (ip, self.ip_version) {
+            (IpAddr::V4(v4), 4) => to_bits(&v4.octets()),
+            // IPv4 addresses live in the ::/96 subtree of IPv6 databases.
+            (IpAddr::V4(v4), _) => to_bits(&v4.to_ipv6_compatible().octets()),
+            (IpAddr::V6(v6), 6) => to_bits(&v6.octets()),
+            (IpAddr::V6(_), _) => return None,
+        };
+
+        let mut node = 0;
+        for bit in bits {
+            if node >= self.node_count {
+                break;
+            }
+            node = self.read_record(node, bit)?;
+        }
+        if node <= self.node_count {
+            // Equal to `node_count` means "no data"; below it, the address was not fully resolved.
+            return None;
+        }
+        let data_start = self.data_section_start()?;
+        // Records pointing into the separator are corrupt.
+        let offset = data_start.checked_add((node - self.node_count).checked_sub(DATA_SECTION_SEPARATOR)?)?;
+        Decoder { bytes: &self.bytes, base: data_start }.decode(offset).ok().map(|(value, _)| value)
+    }
+
+    /// Reads the left (`false`) or right (`true`) record of a search-tree node.
+    fn read_record(&self, node: usize, right: bool) -> Option<usize> {
+        let node_bytes = self.record_size / 4;
+        let start = node.checked_mul(node_bytes)?;
+        let b = self.bytes.get(start..start.checked_add(node_bytes)?)?;
+        let be = |slice: &[u8]| slice.iter().fold(0usize, |acc, &byte| (acc << 8) | byte as usize);
+        Some(match (self.record_size, right) {
+            (24, false) => be(&b[0..3]),
+            (24, true) => be(&b[3..6]),
+            // 28-bit records share the middle byte: its high nibble belongs to the left record.
+            (28, false) => ((b[3] as usize & 0xF0) << 20) | be(&b[0..3]),
+            (28, true) => ((b[3] as usize & 0x0F) << 24) | be(&b[4..7]),
+            (_, false) => be(&b[0..4]),
+            (_, true) => be(&b[4..8]),
+        })
+    }
+}
+
+/// Expands bytes into their bits, most significant first.
+fn to_bits(bytes: &[u8]) -> Vec<bool> {
+    bytes.iter().flat_map(|byte| (0..8).rev().map(move |i| byte >> i & 1 == 1)).collect()
+}
+
+/// Decodes values from a data section; pointers are relative to `base`.
+struct Decoder<'a> {
+    bytes: &'a [u8],
+    base: usize,
+}
+
+impl Decoder<'_> {
+    /// Decodes the value at `offset` and returns it with the offset just past it.
+    fn decode(&self, offset: usize) -> Result<(Value, usize), String> {
+        self.decode_nested(offset, 0)
+    }
+
+    /// Decodes a value found `depth` maps, arrays or pointers down from the record.
+    fn decode_nested(&self, offset: usize, depth: usize) -> Result<(Value, usize), String> {
+        if depth > MAX_DEPTH {
+            return Err("data nested too deeply (pointer loop?)".to_string());
+        }
+        let control = *self.byte(offset)?;
+        let mut offset = offset + 1;
+        let mut type_id = control >> 5;
+
+        if type_id == 1 {
+            // Pointer: the size bits select how many bytes encode the target.
+            let size = (control >> 3) & 0x3;
+            let low = (control & 0x7) as usize;
+            let read = |len: usize| self.slice(offset, len).map(|s| s.iter().fold(0usize, |acc, &b| (acc << 8) | b as usize));
+            let target = match size {
+                0 => (low << 8) | read(1)?,
+                1 => ((low << 16) | read(2)?) + 2048,
+                2 => ((low << 24) | read(3)?) + 526_336,
+                _ => read(4)?,
+            };
+            let target = self.base.checked_add(target).ok_or("pointer out of range")?;
+            // The format forbids pointers to pointers, which also rules out the shortest loops.
+            if *self.byte(target)? >> 5 == 1 {
+                return Err("pointer to a pointer".to_string());
+            }
+            let (value, _) = self.decode_nested(target, depth + 1)?;
+            return Ok((value, offset + size as usize + 1));
+        }
+        if type_id == 0 {
+            type_id = self.byte(offset)?.saturating_add(7);
+            offset += 1;
+        }
+
+        let mut size = (control & 0x1F) as usize;
+        if size >= 29 {
+            let extra = size - 28;
+            let n = self.slice(offset, extra)?.iter().fold(0usize, |acc, &b| (acc << 8) | b as usize);
+            offset += extra;
+            size = match extra {
+                1 => 29 + n,
+                2 => 285 + n,
+                _ => 65_821 + n,
+            };
+        }
+
+        let uint = |offset: usize, size: usize| self.slice(offset, size)
+            .map(|s| s.iter().fold(0u128, |acc, &b| (acc << 8) | b as u128));
+        match type_id {
+            2 => Ok((Value::String(String::from_utf8_lossy(self.slice(offset, size)?).into_owned()), offset + size)),
+            // Double, bytes and int32 are skipped over.
+            3 => Ok((Value::Other, offset + 8)),
+            4 | 8 => Ok((Value::Other, offset + size)),
+            5 | 6 | 9 | 10 => Ok((Value::Uint(uint(offset, size)?), offset + size)),
+            7 => {
+                // The size is read from the file: every entry takes at least two bytes, so no
+                // more can fit in what is left of it.
+                let mut entries = Vec::with_capacity(size.min(self.bytes.len().saturating_sub(offset) / 2));
+                for _ in 0..size {
+                    let (key, next) = self.decode_nested(offset, depth + 1)?;
+                    let (value, next) = self.decode_nested(next, depth + 1)?;
+                    let Value::String(key) = key else { return Err("map key is not a string".into()) };
+                    entries.push((key, value));
+                    offset = next;
+                }
+                Ok((Value::Map(entries), offset))
+            }
+            11 => {
+                for _ in 0..size {
+                    offset = self.decode_nested(offset, depth + 1)?.1;
+                }
+                Ok((Value::Other, offset))
+            }
+            // Booleans store their value in the size bits; floats are four bytes.
+            14 => Ok((Value::Other, offset)),
+            15 => Ok((Value::Other, offset + 4)),
+            other => Err(format!("unsupported data type {}", other)),
+        }
+    }
+
+    fn byte(&self, offset: usize) -> Result<&u8, String> {
+        self.bytes.get(offset).ok_or_else(|| "unexpected end of database".to_string())
+    }
+
+    fn slice(&self, offset: usize, len: usize) -> Result<&[u8], String> {
+        offset.checked_add(len).and_then(|end| self.bytes.get(offset..end)).ok_or_else(|| "unexpected end of database".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::Ipv4Addr;
+
+    fn string(s: &str) -> Vec<u8> {
+        [vec![2 << 5 | s.len() as u8], s.as_bytes().to_vec()].concat()
+    }
+
+    fn uint32(n: u32) -> Vec<u8> {
+        [vec![6 << 5 | 4], n.to_be_bytes().to_vec()].concat()
+    }
+
+    fn map(entries: &[(&str, Vec<u8>)]) -> Vec<u8> {
+        let mut bytes = vec![7 << 5 | entries.len() as u8];
+        for (key, value) in entries {
+            bytes.extend(string(key));
+            bytes.extend(value);
+        }
+        bytes
+    }
+
+    /// A pointer to `target` in the data section, with the one-byte encoding.
+    fn pointer(target: u8) -> Vec<u8> {
+        vec![1 << 5, target]
+    }
+
+    /// An IPv4 database with a single node whose records both point to the start of `data`.
+    fn database(data: &[u8]) -> Vec<u8> {
+        let record = (1 + DATA_SECTION_SEPARATOR as u32).to_be_bytes();
+        let mut bytes = [&record[1..], &record[1..]].concat();
+        bytes.extend([0; DATA_SECTION_SEPARATOR]);
+        bytes.extend(data);
+        bytes.extend(METADATA_MARKER);
+        bytes.extend(map(&[("node_count", uint32(1)), ("record_size", uint32(24)), ("ip_version", uint32(4))]));
+        bytes
+    }
+
+    fn lookup(bytes: Vec<u8>) -> Option<Value> {
+        Database::from_bytes(bytes).unwrap().lookup(IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1)))
+    }
+
+    #[test]
+    fn reads_the_record_of_an_address() {
+        let record = lookup(database(&map(&[
+            ("autonomous_system_number", uint32(64_496)),
+            ("country", map(&[("iso_code", string("NL"))])),
+        ]))).unwrap();
+        assert_eq!(record.get("autonomous_system_number").and_then(Value::as_u32), Some(64_496));
+        assert_eq!(record.get("country").and_then(|c| c.get("iso_code")).and_then(Value::as_string).as_deref(), Some("NL"));
+    }
+
+    #[test]
+    fn rejects_truncated_databases() {
+        let bytes = database(&map(&[("autonomous_system_number", uint32(64_496))]));
+        assert!(Database::from_bytes(bytes[..bytes.len() - 3].to_vec()).is_err());
+        // A string running past the end of the file, and a search tree that claims more nodes than it holds.
+        assert!(lookup(database(&[2 << 5 | 30, 0xFF, 0xFF, b'A'])).is_none());
+        let mut bytes = database(&[]);
+        let count = bytes.windows(10).rposition(|window| window == b"node_count").unwrap() + 11;
+        bytes[count..count + 4].copy_from_slice(&u32::MAX.to_be_bytes());
+        assert!(Database::from_bytes(bytes).is_err());
+    }
+
+    #[test]
+    fn rejects_cyclic_and_oversized_data() {
+        // A map whose value points back at the map, and a pointer to itself.
+        assert!(lookup(database(&[vec![7 << 5 | 1], string("loop"), pointer(0)].concat())).is_none());
+        assert!(lookup(database(&[pointer(0)].concat())).is_none());
+        // A map claiming millions of entries in a handful of bytes.
+        assert!(lookup(database(&[7 << 5 | 31, 0xFF, 0xFF, 0xFF])).is_none());
+    }
+
+    #[test]
+    fn ignores_records_pointing_into_the_separator() {
+        let mut bytes = database(&map(&[]));
+        bytes[..3].copy_from_slice(&[0, 0, 2]);
+        assert!(Database::from_bytes(bytes).unwrap().lookup(IpAddr::V4(Ipv4Addr::new(1, 0, 0, 0))).is_none());
+    }
+}
@@ -3,7 +3,7 @@
 //! complete with detailed, human-readable explanations and remediation steps.
 //! Making this data-driven allows for easy updates and maintenance of the scanner's intelligence.
 
-use crate::core::models::Severity;
+use crate::core::models::{Severity, WebServer};
 use std::fmt;
 
 /// Defines the high-level categories for security findings.
@@ -16,6 +16,8 @@ pub enum FindingCategory {
     Ssl,
     /// Findings related to HTTP security headers.
     Http,
+    /// Findings related to the detected technology stack (e.g. outdated versions).
+    Technology,
 }
 
 /// Implements the `Display` trait to provide a human-friendly name for each category.
@@ -27,6 +29,7 @@ impl fmt::Display for FindingCategory {
             FindingCategory::Dns => write!(f, "DNS Configuration"),
             FindingCategory::Ssl => write!(f, "SSL/TLS Certificate"),
             FindingCategory::Http => write!(f, "HTTP Security Headers"),
+            FindingCategory::Technology => write!(f, "Technology Stack"),
         }
     }
 }
@@ -49,140 +52,590 @@ pub struct FindingDetail {
     pub description: &'static str,
     /// Clear, actionable steps the user can take to fix the issue.
     pub remediation: &'static str,
+    /// Authoritative links (RFCs, OWASP, MDN) where the user can verify and learn more.
+    pub references: &'static [&'static str],
+}
+
+/// Builds a `FindingDetail` entry, naming the category and severity variants directly.
+///
+/// Every field is required and in a fixed order, so an entry missing one does not compile.
+/// `references` must list at least one link, so every finding can be verified.
+macro_rules! finding {
+    (
+        code: $code:expr,
+        title: $title:expr,
+        category: $category:ident,
+        severity: $severity:ident,
+        description: $description:expr,
+        remediation: $remediation:expr,
+        references: [$first_reference:expr $(, $reference:expr)* $(,)?] $(,)?
+    ) => {
+        FindingDetail {
+            code: $code,
+            title: $title,
+            category: FindingCategory::$category,
+            severity: Severity::$severity,
+            description: $description,
+            remediation: $remediation,
+            references: &[$first_reference $(, $reference)*],
+        }
+    };
 }
 
 /// The centralized, static knowledge base of all possible findings.
 ///
 /// This array is the core data that drives the analysis reports. Each entry provides
-/// the complete context for a specific `AnalysisResult` code.
+/// the complete context for a specific `AnalysisResult` code. Codes must be unique, which
+/// is checked at compile time: a duplicate would shadow the entry after it.
 static FINDINGS: &[FindingDetail] = &[
     // --- DNS: Email Security & Domain Integrity ---
-    FindingDetail {
+    finding! {
         code: "DNS_DMARC_MISSING",
         title: "DMARC Record Missing",
-        category: FindingCategory::Dns,
-        severity: Severity::Critical,
+        category: Dns,
+        severity: Critical,
         description: "DMARC is an email authentication policy that protects your domain from being used for email spoofing and phishing. It tells receiving mail servers how to handle emails that fail authentication checks.",
-        remediation: "Add a DMARC record to your domain's DNS settings. Start with a monitoring policy like 'v=DMARC1; p=none;' and gradually move to 'p=quarantine' or 'p=reject' after analyzing reports."
+        remediation: "Add a DMARC record to your domain's DNS settings. Start with a monitoring policy like 'v=DMARC1; p=none;' and gradually move to 'p=quarantine' or 'p=reject' after analyzing reports.",
+        references: ["https://datatracker.ietf.org/doc/html/rfc7489", "https://www.cloudflare.com/learning/dns/dns-records/dns-dmarc-record/"],
+    },
+    finding! {
+        code: "DNS_DMARC_MALFORMED",
+        title: "Malformed DMARC Record",
+        category: Dns,
+        severity: Warning,
+        description: "A DMARC record is published, but it has syntax errors: {subject}. Receivers discard a DMARC record they cannot parse and behave as if none were published, so the domain has no DMARC protection or reporting even though a record exists.",
+        remediation: "Fix the record so it starts with 'v=DMARC1', has a 'p' tag set to 'none', 'quarantine' or 'reject', and gives each tag a valid value, e.g. 'v=DMARC1; p=quarantine; pct=100; rua=mailto:dmarc@example.com'. Separate tags with semicolons and check the result with a DMARC validator.",
+        references: ["https://datatracker.ietf.org/doc/html/rfc7489#section-6.3", "https://datatracker.ietf.org/doc/html/rfc7489#section-6.4"],
     },
-    FindingDetail {
+    finding! {
         code: "DNS_DMARC_POLICY_NONE",
         title: "DMARC Policy is 'none'",
-        category: FindingCategory::Dns,
-        severity: Severity::Warning,
+        category: Dns,
+        severity: Warning,
         description: "Your DMARC policy is in 'monitoring only' mode. It reports fraudulent emails but does not instruct receivers to block or quarantine them, offering no active protection against spoofing.",
-        remediation: "After ensuring your legitimate emails pass SPF/DKIM, update your DMARC policy to 'p=quarantine' (sends to spam) or 'p=reject' (blocks delivery) to actively protect your domain."
+        remediation: "After ensuring your legitimate emails pass SPF/DKIM, update your DMARC policy to 'p=quarantine' (sends to spam) or 'p=reject' (blocks delivery) to actively protect your domain.",
+        references: ["https://datatracker.ietf.org/doc/html/rfc7489#section-6.3"],
     },
-    FindingDetail {
+    finding! {
+        code: "DNS_DMARC_UNENFORCEABLE",
+        title: "DMARC Policy Cannot Pass",
+        category: Dns,
+        severity: Warning,
+        description: "Your DMARC policy quarantines or rejects failing mail, but no SPF record and no DKIM key (under the common selectors) were found. DMARC only passes when SPF or DKIM passes and aligns with the From domain, so legitimate mail from your domain fails it too and may be quarantined or rejected.",
+        remediation: "Publish an SPF record listing your mail servers and enable DKIM signing with your email provider, making sure the domains align with your From address. If your DKIM selector is not a common one, verify it is published.",
+        references: ["https://datatracker.ietf.org/doc/html/rfc7489#section-3.1", "https://datatracker.ietf.org/doc/html/rfc7489#section-4.2"],
+    },
+    finding! {
+        code: "DNS_DOMAIN_NOT_FOUND",
+        title: "Domain Does Not Exist: {subject}",
+        category: Dns,
+        severity: Critical,
+        description: "The DNS answered NXDOMAIN for {subject}: the name does not exist. Nobody can reach a site or send mail under it, and none of the other checks could run. This usually means a typo in the target, a registration that expired, or a delegation that was removed from the parent zone.",
+        remediation: "Check the spelling of the target. If it is correct, check the domain's registration and that the registrar delegates it to your nameservers, e.g. with 'whois' and 'dig NS <domain>'.",
+        references: ["https://datatracker.ietf.org/doc/html/rfc8020"],
+    },
+    finding! {
+        code: "DNS_RESOLUTION_ERROR",
+        title: "DNS Lookups Failed: {subject}",
+        category: Dns,
+        severity: Warning,
+        description: "The lookups of the {subject} records failed instead of returning an answer, so whether these records exist is unknown. Resolvers answer SERVFAIL when the domain's nameservers are unreachable or answer wrongly (a lame delegation), or when DNSSEC validation fails. Mail servers and certificate authorities hit the same failure, and may defer mail or refuse to issue certificates.",
+        remediation: "Query each of the domain's nameservers directly, e.g. 'dig @<nameserver> <domain> TXT', and check the DNSSEC chain with DNSViz. Make sure every nameserver in the NS set answers authoritatively for the zone.",
+        references: ["https://dnsviz.net/", "https://datatracker.ietf.org/doc/html/rfc1035#section-4.1.1"],
+    },
+    finding! {
         code: "DNS_SPF_MISSING",
         title: "SPF Record Missing",
-        category: FindingCategory::Dns,
-        severity: Severity::Warning,
+        category: Dns,
+        severity: Warning,
         description: "Sender Policy Framework (SPF) is a DNS record that lists all the servers authorized to send email on behalf of your domain. Without it, attackers can more easily spoof emails from your domain.",
-        remediation: "Create a TXT record for your domain that defines your authorized mail servers. A simple example for Google Workspace is 'v=spf1 include:_spf.google.com ~all'."
+        remediation: "Create a TXT record for your domain that defines your authorized mail servers. A simple example for Google Workspace is 'v=spf1 include:_spf.google.com ~all'.",
+        references: ["https://datatracker.ietf.org/doc/html/rfc7208", "https://www.cloudflare.com/learning/dns/dns-records/dns-spf-record/"],
     },
-    FindingDetail {
+    finding! {
         code: "DNS_SPF_POLICY_SOFTFAIL",
         title: "SPF Policy is 'Softfail'",
-        category: FindingCategory::Dns,
-        severity: Severity::Info,
+        category: Dns,
+        severity: Info,
         description: "Your SPF record uses '~all' (softfail), which suggests that receiving servers should accept but mark suspicious mail. This is less secure than '-all' (fail), which instructs servers to reject the mail.",
-        remediation: "If you are confident your SPF record lists all legitimate mail sources, consider changing the ending from '~all' to '-all' for stricter enforcement."
+        remediation: "If you are confident your SPF record lists all legitimate mail sources, consider changing the ending from '~all' to '-all' for stricter enforcement.",
+        references: ["https://datatracker.ietf.org/doc/html/rfc7208#section-2.6"],
     },
-    FindingDetail {
+    finding! {
         code: "DNS_SPF_POLICY_NEUTRAL",
         title: "SPF Policy is 'Neutral'",
-        category: FindingCategory::Dns,
-        severity: Severity::Info,
+        category: Dns,
+        severity: Info,
         description: "Your SPF record uses '?all' (neutral), which provides no definitive policy on the mail's legitimacy. It essentially tells receivers 'I don't know if this is valid,' offering no protection.",
-        remediation: "This policy should be avoided. Change '?all' to '~all' (softfail) or, preferably, '-all' (fail) to provide a clear security policy to receiving mail servers."
+        remediation: "This policy should be avoided. Change '?all' to '~all' (softfail) or, preferably, '-all' (fail) to provide a clear security policy to receiving mail servers.",
+        references: ["https://datatracker.ietf.org/doc/html/rfc7208#section-2.6"],
     },
-    FindingDetail {
+    finding! {
+        code: "DNS_SPF_MX_NOT_COVERED",
+        title: "SPF Does Not Authorize the Domain's Mail Servers",
+        category: Dns,
+        severity: Warning,
+        description: "The domain receives mail on its own servers ({subject}), but its SPF record has no 'mx' or 'a' mechanism and none of its 'ip4'/'ip6' ranges covers their addresses. When these servers also send the domain's mail (replies, notifications, forwards), receivers see it fail SPF, which with an enforcing DMARC policy gets it rejected or sent to spam. Includes are not resolved, so a third-party include that lists these addresses is not taken into account.",
+        remediation: "If these servers send mail for the domain, authorize them in the SPF record, e.g. by adding 'mx' before the 'all' mechanism ('v=spf1 mx include:_spf.example.net -all') or their addresses with 'ip4:'/'ip6:'. If they only receive mail, no change is needed.",
+        references: ["https://datatracker.ietf.org/doc/html/rfc7208#section-5.4", "https://datatracker.ietf.org/doc/html/rfc7208#section-5.6"],
+    },
+    finding! {
         code: "DNS_DKIM_MISSING",
         title: "DKIM Record Missing",
-        category: FindingCategory::Dns,
-        severity: Severity::Info,
+        category: Dns,
+        severity: Info,
         description: "DKIM (DomainKeys Identified Mail) adds a tamper-proof digital signature to emails. This signature confirms that the email was sent from your domain and that its content has not been altered in transit.",
-        remediation: "Enable DKIM signing in your email service provider's control panel. This typically involves generating a key and adding the public part as a TXT record to your DNS."
+        remediation: "Enable DKIM signing in your email service provider's control panel. This typically involves generating a key and adding the public part as a TXT record to your DNS.",
+        references: ["https://datatracker.ietf.org/doc/html/rfc6376", "https://www.cloudflare.com/learning/dns/dns-records/dns-dkim-record/"],
+    },
+    finding! {
+        code: "DNS_DKIM_PROVIDER_DETECTED",
+        title: "Email Provider Identified via DKIM",
+        category: Dns,
+        severity: Info,
+        description: "A DKIM record was found under a selector that is specific to a known email provider (e.g. 'google' for Google Workspace, 'selector1' for Microsoft 365). This reveals which service sends mail for your domain. It is informational, not a vulnerability.",
+        remediation: "No action is required. Make sure every provider that sends mail on your behalf signs with DKIM and is covered by your SPF record, and remove selectors for providers you no longer use. The detected provider is listed in the DNS check details (press 'w').",
+        references: ["https://datatracker.ietf.org/doc/html/rfc6376#section-3.1"],
     },
-    FindingDetail {
+    finding! {
         code: "DNS_CAA_MISSING",
         title: "CAA Record Missing",
-        category: FindingCategory::Dns,
-        severity: Severity::Info,
+        category: Dns,
+        severity: Info,
         description: "A Certificate Authority Authorization (CAA) record specifies which Certificate Authorities (CAs) are allowed to issue SSL/TLS certificates for your domain. This acts as a safeguard against certificate mis-issuance.",
-        remediation: "Add a CAA record to your DNS to lock down certificate issuance to your chosen provider(s). For example: '0 issue \"letsencrypt.org\"'."
+        remediation: "Add a CAA record to your DNS to lock down certificate issuance to your chosen provider(s). For example: '0 issue \"letsencrypt.org\"'.",
+        references: ["https://datatracker.ietf.org/doc/html/rfc8659", "https://letsencrypt.org/docs/caa/"],
+    },
+    finding! {
+        code: "DNS_SHARED_HOSTING",
+        title: "Shared Hosting IP",
+        category: Dns,
+        severity: Info,
+        description: "The site's address is shared with many other domains ({subject}), as on shared hosting or a large CDN edge. This is not a vulnerability in itself, but the site shares its IP reputation with its neighbours: one of them sending spam or serving malware can get the address blocklisted, and a compromised or noisy neighbour on the same server can affect it.",
+        remediation: "If the site handles sensitive data or sends email from this address, consider a dedicated IP or a dedicated server, and monitor the address on IP blocklists. Behind a CDN, a shared edge address is expected and no action is needed.",
+        references: ["https://en.wikipedia.org/wiki/Shared_web_hosting_service"],
     },
 
     // --- SSL/TLS: Secure Communication Layer ---
-      FindingDetail {
+    finding! {
         code: "SSL_HANDSHAKE_FAILED",
         title: "TLS Handshake Failed",
-        category: FindingCategory::Ssl,
-        severity: Severity::Critical,
+        category: Ssl,
+        severity: Critical,
         description: "The scanner could not establish a secure TLS connection with the server. This can be caused by an invalid/missing certificate, unsupported cipher suites, or other critical server misconfigurations.",
-        remediation: "Ensure a valid, trusted SSL/TLS certificate is installed on the server for the correct domain. Use an online tool like SSL Labs to diagnose TLS configuration issues."
+        remediation: "Ensure a valid, trusted SSL/TLS certificate is installed on the server for the correct domain. Use an online tool like SSL Labs to diagnose TLS configuration issues.",
+        references: ["https://datatracker.ietf.org/doc/html/rfc8446", "https://www.ssllabs.com/ssltest/"],
+    },
+    finding! {
+        code: "SSL_NO_CERTIFICATE_FOUND",
+        title: "No Certificate Presented",
+        category: Ssl,
+        severity: Warning,
+        description: "The TLS handshake completed, but the server did not present a certificate. Without one, nothing proves the connection reaches your server rather than an impostor, and browsers refuse the connection.",
+        remediation: "Install a certificate issued for this domain by a trusted CA (e.g. via Let's Encrypt / Certbot) and configure the server or load balancer to present it for this host name.",
+        references: ["https://datatracker.ietf.org/doc/html/rfc8446#section-4.4.2", "https://letsencrypt.org/docs/"],
     },
-    FindingDetail {
+    finding! {
         code: "SSL_EXPIRED",
         title: "SSL Certificate Expired",
-        category: FindingCategory::Ssl,
-        severity: Severity::Critical,
+        category: Ssl,
+        severity: Critical,
         description: "The website's SSL certificate is expired. This will cause browsers to show prominent security warnings, block access, and destroy user trust.",
-        remediation: "Renew the SSL certificate immediately. Implement automated renewal processes (e.g., via Let's Encrypt / Certbot) to prevent this from happening in the future."
+        remediation: "Renew the SSL certificate immediately. Implement automated renewal processes (e.g., via Let's Encrypt / Certbot) to prevent this from happening in the future.",
+        references: ["https://datatracker.ietf.org/doc/html/rfc5280#section-4.1.2.5", "https://letsencrypt.org/docs/"],
     },
-    FindingDetail {
+    finding! {
         code: "SSL_EXPIRING_SOON",
         title: "SSL Certificate Expiring Soon",
-        category: FindingCategory::Ssl,
-        severity: Severity::Warning,
-        description: "The SSL certificate will expire in less than 30 days. This is an early warning to prevent service disruption and loss of trust.",
-        remediation: "Renew the SSL certificate before it expires. If you have automated renewals, verify that the system is functioning correctly."
+        category: Ssl,
+        severity: Warning,
+        description: "The SSL certificate will expire within the configured window (30 days unless 'cert_expiry_warning_days' says otherwise). This is an early warning to prevent service disruption and loss of trust.",
+        remediation: "Renew the SSL certificate before it expires. If you have automated renewals, verify that the system is functioning correctly.",
+        references: ["https://datatracker.ietf.org/doc/html/rfc5280#section-4.1.2.5", "https://letsencrypt.org/docs/integration-guide/"],
+    },
+    finding! {
+        code: "SSL_RECENTLY_ISSUED",
+        title: "Certificate Issued Recently: {subject}",
+        category: Ssl,
+        severity: Info,
+        description: "The certificate served became valid {subject}. Most of the time this is a routine renewal, but a certificate that changes unexpectedly can also mean it was re-issued after a key compromise, or that someone else obtained one for the domain. It is reported because 'cert_recently_issued_hours' is set, to surface certificate changes while watching a site.",
+        remediation: "Check that the renewal was expected, e.g. that it matches your ACME client's or provider's schedule. If it was not, look the certificate up in the Certificate Transparency logs (crt.sh), check who requested it, and revoke it if it is not yours.",
+        references: ["https://crt.sh/", "https://certificate.transparency.dev/"],
+    },
+
+    finding! {
+        code: "SSL_TLS_COMPRESSION",
+        title: "TLS Compression Enabled",
+        category: Ssl,
+        severity: Warning,
+        description: "The server negotiated TLS-level compression. Compressing secrets together with attacker-controlled data lets an attacker recover them (e.g. session cookies) by observing the size of encrypted records, which is known as the CRIME attack.",
+        remediation: "Disable TLS compression in your server or TLS library (e.g. 'SSLCompression off' for Apache, or upgrade to an OpenSSL version where it is disabled by default). TLS 1.3 removes compression entirely.",
+        references: ["https://en.wikipedia.org/wiki/CRIME", "https://datatracker.ietf.org/doc/html/rfc7457#section-2.6"],
+    },
+    finding! {
+        code: "SSL_WILDCARD_CERT",
+        title: "Wildcard Certificate in Use",
+        category: Ssl,
+        severity: Info,
+        description: "Most names on the certificate are wildcards (e.g. '*.example.com'). This is convenient, but the same private key then protects every subdomain: if it leaks from any server that holds it, an attacker can impersonate all of them.",
+        remediation: "Limit wildcard certificates to servers that really need them, and prefer per-service certificates (automated issuance makes this cheap). Keep the wildcard's private key on as few machines as possible.",
+        references: ["https://datatracker.ietf.org/doc/html/rfc6125#section-7.2"],
+    },
+    finding! {
+        code: "SSL_WILDCARD_APEX_NOT_COVERED",
+        title: "Wildcard Certificate Does Not Cover the Bare Domain",
+        category: Ssl,
+        severity: Warning,
+        description: "The certificate relies on a wildcard such as '*.example.com', which matches one label only: it covers 'www.example.com' but not 'example.com' itself. Visitors who type the bare domain get a certificate error if the same certificate is served there.",
+        remediation: "Add the bare domain as an additional Subject Alternative Name (e.g. both 'example.com' and '*.example.com') when requesting the certificate.",
+        references: ["https://datatracker.ietf.org/doc/html/rfc6125#section-6.4.3"],
+    },
+    finding! {
+        code: "SSL_MUSTSTAPLE_NOT_STAPLED",
+        title: "Must-Staple Certificate Without OCSP Stapling",
+        category: Ssl,
+        severity: Critical,
+        description: "The certificate carries the OCSP Must-Staple extension, which tells clients to reject it unless the server staples a fresh OCSP response to the handshake. The server did not staple one, so strict clients (such as Firefox) refuse the connection.",
+        remediation: "Enable OCSP stapling on the server (e.g. 'ssl_stapling on;' in nginx or 'SSLUseStapling On' in Apache) and make sure it can reach the CA's OCSP responder. Otherwise, reissue the certificate without Must-Staple.",
+        references: ["https://datatracker.ietf.org/doc/html/rfc7633", "https://datatracker.ietf.org/doc/html/rfc6066#section-8"],
+    },
+    finding! {
+        code: "SSL_DUAL_CERT",
+        title: "Separate RSA and ECDSA Certificates",
+        category: Ssl,
+        severity: Info,
+        description: "The server presents a different certificate depending on whether the client prefers RSA or ECDSA. This is a legitimate setup for large sites, but each certificate has its own expiry date and issuer, and a scan normally only sees one of them.",
+        remediation: "No action is required. Make sure both certificates are monitored and renewed, since clients that only support one key type will never see the other.",
+        references: ["https://datatracker.ietf.org/doc/html/rfc8446#section-4.4.2.2"],
+    },
+    finding! {
+        code: "SSL_DISTRUSTED_ISSUER",
+        title: "Certificate From a Distrusted CA: {subject}",
+        category: Ssl,
+        severity: Warning,
+        description: "The certificate was issued by {subject}, a certificate authority the major browsers have removed trust for after compliance failures. The distrust covers the certificates it issued after the browsers' cutoff date, and this one was issued after it. The scanner's own trust store may still accept it, but visitors' browsers reject the certificate, showing a full-page security warning.",
+        remediation: "Replace the certificate with one from a CA in good standing (e.g. Let's Encrypt, DigiCert, Sectigo or Google Trust Services) and install its full chain. Check other hosts and services that use certificates from the same CA.",
+        references: ["https://wiki.mozilla.org/CA/Additional_Trust_Changes", "https://www.chromium.org/Home/chromium-security/root-ca-policy/"],
+    },
+    finding! {
+        code: "SSL_DEFAULT_CERT",
+        title: "Default Certificate Served: {subject}",
+        category: Ssl,
+        severity: Info,
+        description: "The certificate served for this host does not cover its name and looks like the default certificate of {subject}. The server most likely ignores the name sent via SNI, or has no TLS site configured for this host, and falls back to the platform's catch-all certificate. This explains the handshake failure: the host is not serving a wrong certificate of its own, it is not really serving TLS for this name.",
+        remediation: "Add this host name to the platform's TLS configuration (custom domain, ingress rule or virtual host) and issue a certificate for it. If the host should not serve HTTPS, remove the DNS record pointing it at the shared infrastructure.",
+        references: ["https://datatracker.ietf.org/doc/html/rfc6066#section-3"],
+    },
+    finding! {
+        code: "SSL_NO_SESSION_RESUMPTION",
+        title: "TLS Session Resumption Not Supported",
+        category: Ssl,
+        severity: Info,
+        description: "The server did not resume a TLS session offered from a previous connection, so every new connection performs a full handshake. This is not a security issue, but it adds a round trip and extra CPU work on both sides for returning visitors and for clients that open several connections.",
+        remediation: "Enable session tickets or a session cache on the server or load balancer (e.g. 'ssl_session_cache shared:SSL:10m' and 'ssl_session_tickets on' in nginx). Behind several load-balanced servers, share the ticket keys or the cache between them, and rotate ticket keys regularly.",
+        references: ["https://datatracker.ietf.org/doc/html/rfc8446#section-2.2", "https://datatracker.ietf.org/doc/html/rfc5077"],
+    },
+    finding! {
+        code: "SSL_CAA_VIOLATION",
+        title: "Certificate Issuer Not Allowed by CAA",
+        category: Ssl,
+        severity: Warning,
+        description: "Your domain's CAA records restrict which Certificate Authorities may issue certificates for it, but the certificate being served was issued by a CA that is not on that list. This may mean the CAA records are outdated, or that a certificate was mis-issued.",
+        remediation: "Confirm the certificate is legitimate. If you switched CA on purpose, add an 'issue' property for the new CA to your CAA records (e.g. '0 issue \"letsencrypt.org\"'); otherwise revoke the certificate and investigate how it was issued.",
+        references: ["https://datatracker.ietf.org/doc/html/rfc8659", "https://letsencrypt.org/docs/caa/"],
     },
 
     // --- HTTP Headers: Hardening the Application Layer ---
-    FindingDetail {
+    finding! {
         code: "HEADERS_REQUEST_FAILED",
         title: "HTTP Request Failed",
-        category: FindingCategory::Http,
-        severity: Severity::Critical,
+        category: Http,
+        severity: Critical,
         description: "The scanner could not connect to the target server to check its HTTP headers. The server might be down, unreachable, or blocking automated requests.",
-        remediation: "Verify that the target is online and accessible from the public internet. Check for firewalls or network issues that might be blocking the connection."
+        remediation: "Verify that the target is online and accessible from the public internet. Check for firewalls or network issues that might be blocking the connection.",
+        references: ["https://developer.mozilla.org/en-US/docs/Web/HTTP/Status"],
     },
-    FindingDetail {
+    finding! {
         code: "HEADERS_HSTS_MISSING",
         title: "HSTS Header Missing",
-        category: FindingCategory::Http,
-        severity: Severity::Warning,
+        category: Http,
+        severity: Warning,
         description: "The HTTP Strict-Transport-Security (HSTS) header instructs browsers to only communicate with your site over HTTPS. It protects against protocol downgrade attacks and cookie hijacking.",
-        remediation: "Add the 'Strict-Transport-Security' header to your web server responses. A strong value is 'max-age=31536000; includeSubDomains; preload'."
+        remediation: "Add the 'Strict-Transport-Security' header to your web server responses. A strong value is 'max-age=31536000; includeSubDomains; preload'.",
+        references: ["https://datatracker.ietf.org/doc/html/rfc6797", "https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Strict-Transport-Security", "https://owasp.org/www-project-secure-headers/"],
     },
-    FindingDetail {
+    finding! {
         code: "HEADERS_CSP_MISSING",
         title: "CSP Header Missing",
-        category: FindingCategory::Http,
-        severity: Severity::Warning,
+        category: Http,
+        severity: Warning,
         description: "Content-Security-Policy (CSP) is a powerful security layer that helps prevent attacks like Cross-Site Scripting (XSS) and data injection by defining which resources a browser is allowed to load.",
-        remediation: "Implement a Content-Security-Policy header that defines trusted sources for scripts, styles, and other assets. Start with a restrictive policy and gradually open it up as needed."
+        remediation: "Implement a Content-Security-Policy header that defines trusted sources for scripts, styles, and other assets. Start with a restrictive policy and gradually open it up as needed.",
+        references: ["https://developer.mozilla.org/en-US/docs/Web/HTTP/CSP", "https://cheatsheetseries.owasp.org/cheatsheets/Content_Security_Policy_Cheat_Sheet.html"],
     },
-    FindingDetail {
+    finding! {
         code: "HEADERS_X_FRAME_OPTIONS_MISSING",
         title: "X-Frame-Options Missing",
-        category: FindingCategory::Http,
-        severity: Severity::Warning,
-        description: "This header protects your visitors against 'clickjacking' attacks, where an attacker loads your site in an invisible iframe to trick users into clicking on malicious content.",
-        remediation: "Add the 'X-Frame-Options' header and set it to 'DENY' (no framing allowed) or 'SAMEORIGIN' (only you can frame your site)."
+        category: Http,
+        severity: Warning,
+        description: "This header protects your visitors against 'clickjacking' attacks, where an attacker loads your site in an invisible iframe to trick users into clicking on malicious content. When the Content-Security-Policy already sets 'frame-ancestors', modern browsers use that directive instead and only very old ones still rely on this header, so the finding is reported as Info.",
+        remediation: "Add the 'X-Frame-Options' header and set it to 'DENY' (no framing allowed) or 'SAMEORIGIN' (only you can frame your site). Sending it alongside CSP 'frame-ancestors' is harmless and covers legacy browsers.",
+        references: ["https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/X-Frame-Options", "https://cheatsheetseries.owasp.org/cheatsheets/Clickjacking_Defense_Cheat_Sheet.html"],
+    },
+    finding! {
+        code: "HEADERS_XFO_INVALID",
+        title: "Invalid X-Frame-Options Value: {subject}",
+        category: Http,
+        severity: Warning,
+        description: "The X-Frame-Options header is sent as '{subject}', a value other than 'DENY' or 'SAMEORIGIN'. Browsers ignore any other value, including the deprecated 'ALLOW-FROM uri' (never supported by Chrome or Safari and dropped by Firefox), so the page can be framed as if the header were missing. When the Content-Security-Policy sets 'frame-ancestors', modern browsers use that instead and the finding is reported as Info.",
+        remediation: "Set X-Frame-Options to 'DENY' or 'SAMEORIGIN'. To allow specific sites to frame the page, use the CSP directive instead, e.g. \"Content-Security-Policy: frame-ancestors 'self' https://partner.example\", and keep X-Frame-Options at 'SAMEORIGIN' for legacy browsers (or drop it).",
+        references: ["https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/X-Frame-Options", "https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Content-Security-Policy/frame-ancestors"],
     },
-    FindingDetail {
+    finding! {
         code: "HEADERS_X_CONTENT_TYPE_OPTIONS_MISSING",
         title: "X-Content-Type-Options Missing",
-        category: FindingCategory::Http,
-        severity: Severity::Info,
+        category: Http,
+        severity: Info,
         description: "This header prevents browsers from trying to guess the content type of a file (MIME sniffing). This mitigates attacks where a file disguised as an image could be executed as a script.",
-        remediation: "Add the 'X-Content-Type-Options' header and set its value to 'nosniff'. It's a simple and effective security enhancement."
+        remediation: "Add the 'X-Content-Type-Options' header and set its value to 'nosniff'. It's a simple and effective security enhancement.",
+        references: ["https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/X-Content-Type-Options", "https://owasp.org/www-project-secure-headers/"],
+    },
+    finding! {
+        code: "HEADERS_XSS_PROTECTION_LEGACY",
+        title: "Legacy X-XSS-Protection Enabled",
+        category: Http,
+        severity: Info,
+        description: "X-XSS-Protection controlled the XSS auditor of old browsers. Modern browsers have removed that filter, and values like '1; mode=block' could be abused in legacy browsers to selectively disable scripts or leak information across origins.",
+        remediation: "Remove the 'X-XSS-Protection' header (or set it to '0') and rely on a strong Content-Security-Policy to mitigate Cross-Site Scripting instead.",
+        references: ["https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/X-XSS-Protection", "https://owasp.org/www-project-secure-headers/"],
+    },
+    finding! {
+        code: "HEADERS_EXPECT_CT_OBSOLETE",
+        title: "Obsolete Expect-CT Header",
+        category: Http,
+        severity: Info,
+        description: "Expect-CT let a site opt in to Certificate Transparency enforcement before browsers required it. Chrome has enforced CT for every publicly trusted certificate since 2018, Safari followed in 2021, and Chrome removed Expect-CT support in version 107 (2022); the header was only ever published as an Experimental RFC (9163). It now has no effect, and its 'report-uri' may point at a reporting endpoint that is no longer monitored. Its absence is not an issue.",
+        remediation: "Remove the 'Expect-CT' header from the server or CDN configuration. No replacement is needed: Certificate Transparency is enforced by browsers by default. Some compliance checklists still list it; they can be updated to reference CT enforcement instead.",
+        references: ["https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Expect-CT", "https://datatracker.ietf.org/doc/html/rfc9163"],
+    },
+    finding! {
+        code: "HTTP_OPEN_REDIRECT",
+        title: "Open Redirect",
+        category: Http,
+        severity: Warning,
+        description: "The site redirects visitors to any external address passed in a query parameter (e.g. '?redirect=https://evil.example/'). Attackers use such links in phishing: the URL starts with your trusted domain but lands on their page. It can also be chained with OAuth flows to steal tokens.",
+        remediation: "Only redirect to relative paths or to an allow-list of known destinations, and reject everything else. If arbitrary destinations are needed, show an interstitial page that names the external site.",
+        references: ["https://cheatsheetseries.owasp.org/cheatsheets/Unvalidated_Redirects_and_Forwards_Cheat_Sheet.html", "https://cwe.mitre.org/data/definitions/601.html"],
+    },
+    finding! {
+        code: "HTTP_REDIRECT_LOOP",
+        title: "Redirect Loop",
+        category: Http,
+        severity: Warning,
+        description: "Following the main page's redirects leads back to a URL that was already visited, so browsers give up with a 'too many redirects' error and the page never loads. Loops usually come from conflicting rules, e.g. a CDN that talks HTTP to the origin while the origin redirects everything to HTTPS. The chain is listed in the HTTP section of the check details.",
+        remediation: "Review the redirect rules of the web server, application and CDN together (HTTP to HTTPS, www to apex, trailing slashes) and make sure each request is redirected at most once towards the canonical URL.",
+        references: ["https://developer.mozilla.org/en-US/docs/Web/HTTP/Redirections"],
+    },
+    finding! {
+        code: "HTTP_EXCESSIVE_REDIRECTS",
+        title: "Long Redirect Chain",
+        category: Http,
+        severity: Info,
+        description: "The main page only loads after more than four redirects. Every hop costs a round trip, slowing the first visit, and long chains make it easy for one hop to fall back to plain HTTP or to an unexpected host. Some clients stop following redirects well before browsers do. The chain is listed in the HTTP section of the check details.",
+        remediation: "Redirect straight to the final canonical URL (scheme, host and path) in a single hop, and link to that URL directly wherever possible.",
+        references: ["https://developer.mozilla.org/en-US/docs/Web/HTTP/Redirections"],
+    },
+    finding! {
+        code: "HTTP_SENSITIVE_CACHEABLE",
+        title: "Session Cookie on a Cacheable Response",
+        category: Http,
+        severity: Warning,
+        description: "The page sets a session cookie, but its 'Cache-Control' header does not forbid storing the response. A shared cache (proxy, CDN) or the browser cache may keep the response, including the 'Set-Cookie' header and personalized content, and serve it to another user or leave it on a shared computer.",
+        remediation: "Send 'Cache-Control: no-store' on responses that set or depend on a session (or at least 'private' to keep them out of shared caches). 'Pragma: no-cache' and a past 'Expires' date only help legacy HTTP/1.0 caches.",
+        references: ["https://owasp.org/www-project-web-security-testing-guide/latest/4-Web_Application_Security_Testing/04-Authentication_Testing/06-Testing_for_Browser_Cache_Weaknesses", "https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Cache-Control"],
+    },
+    finding! {
+        code: "HTTP_CORS_WILDCARD",
+        title: "API Response Readable by Any Site (Wildcard CORS)",
+        category: Http,
+        severity: Warning,
+        description: "The main response is JSON, which marks an API, and sends 'Access-Control-Allow-Origin: *'. Any website a visitor opens can then read this API's responses through their browser. Browsers do not send cookies with wildcard CORS requests, but an API reachable from the visitor's network (an intranet, or one that trusts the source IP) still leaks its data. A wildcard on public HTML or static files is normal and not reported.",
+        remediation: "Replace the wildcard with an allow-list: compare the request's 'Origin' against the sites that need access and echo only those back, with 'Vary: Origin'. Keep '*' only for data that is deliberately public.",
+        references: ["https://developer.mozilla.org/en-US/docs/Web/HTTP/CORS", "https://portswigger.net/web-security/cors"],
+    },
+    finding! {
+        code: "HTTP_PROTOCOLS_ADVERTISED",
+        title: "Newer HTTP Protocols Advertised",
+        category: Http,
+        severity: Info,
+        description: "The server advertises protocols beyond the one the page was served over: {subject}. 'Alt-Svc' tells browsers where to reach the site over another protocol, such as HTTP/3 (h3) over QUIC, and 'Upgrade' offers to switch the connection, e.g. to cleartext HTTP/2 (h2c). This is informational: it describes the serving infrastructure, and the alternative endpoints are part of the attack surface too.",
+        remediation: "No action is needed. Make sure every advertised endpoint gets the same TLS and security header configuration as the main one, and drop 'Upgrade: h2c' on servers reachable from the internet unless cleartext HTTP/2 is intended, since h2c upgrades have been used to smuggle requests past reverse proxies.",
+        references: ["https://datatracker.ietf.org/doc/html/rfc7838", "https://datatracker.ietf.org/doc/html/rfc9114", "https://bishopfox.com/blog/h2c-smuggling-request"],
+    },
+    finding! {
+        code: "HTTP_CORS_REFLECTED_ORIGIN",
+        title: "CORS Allows Any Origin (Reflected Origin)",
+        category: Http,
+        severity: Critical,
+        description: "The scanner requested the page with 'Origin: https://cors-probe.example.org' and the server echoed that origin back in 'Access-Control-Allow-Origin'. A server that allows whatever origin asks behaves like a wildcard, but unlike a real '*' it also works with credentials: when it sends 'Access-Control-Allow-Credentials: true', any website can make requests with the visitor's cookies and read the answers, e.g. account data or CSRF tokens.",
+        remediation: "Check the 'Origin' header against an explicit allow-list of trusted sites before echoing it, compare the full origin rather than a prefix or suffix, and never allow the 'null' origin. Send 'Vary: Origin' so caches keep the answers apart.",
+        references: ["https://portswigger.net/web-security/cors", "https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Access-Control-Allow-Origin", "https://cwe.mitre.org/data/definitions/942.html"],
+    },
+    finding! {
+        code: "HTTP_ADMIN_PANEL_EXPOSED",
+        title: "Admin Panel Reachable: {subject}",
+        category: Http,
+        severity: Info,
+        description: "The administration login of the site's stack is served publicly at {subject}. Anyone can reach it to try leaked or guessed passwords, and automated attacks target these default paths specifically. A database console such as phpMyAdmin is reported as a Warning: it gives direct access to the data and has a history of exploitable flaws.",
+        remediation: "Restrict the panel to the people who need it: allow only your office or VPN addresses, or put it behind an extra authentication layer (e.g. HTTP basic auth or an identity-aware proxy). Enforce strong passwords and multi-factor authentication, and remove tools like phpMyAdmin that are not needed in production.",
+        references: ["https://owasp.org/www-project-web-security-testing-guide/latest/4-Web_Application_Security_Testing/02-Configuration_and_Deployment_Management_Testing/05-Enumerate_Infrastructure_and_Application_Admin_Interfaces", "https://developer.wordpress.org/advanced-administration/security/hardening/"],
+    },
+    finding! {
+        code: "HTTP_VERBOSE_ERROR",
+        title: "Debug Error Page Exposed: {subject}",
+        category: Http,
+        severity: Warning,
+        description: "Requesting a page that does not exist returned a {subject} debug or stack-trace page. Such pages reveal the framework and its version, source file paths, code excerpts and sometimes settings, routes or environment variables, which maps the application for an attacker and can leak secrets outright.",
+        remediation: "Turn debug mode off in production (e.g. DEBUG = False in Django, APP_DEBUG=false in Laravel, customErrors mode=\"RemoteOnly\" in ASP.NET, config.consider_all_requests_local = false in Rails) and serve generic error pages that reveal no internals.",
+        references: ["https://owasp.org/www-project-web-security-testing-guide/latest/4-Web_Application_Security_Testing/08-Testing_for_Error_Handling/01-Testing_For_Improper_Error_Handling", "https://cwe.mitre.org/data/definitions/209.html"],
+    },
+    finding! {
+        code: "HTTP_XST",
+        title: "TRACE Method Echoes Requests (Cross-Site Tracing)",
+        category: Http,
+        severity: Warning,
+        description: "The server answered a TRACE request by echoing it back, including a marker header sent by the scanner. Combined with another flaw (such as XSS or a proxy that forwards TRACE), an attacker can use the echo to read headers a script normally cannot access, such as HttpOnly cookies and Authorization headers.",
+        remediation: "Disable the TRACE method on the web server and any proxy in front of it (e.g. 'TraceEnable off' in Apache, or reject the method in nginx/the load balancer). It is not needed in production.",
+        references: ["https://owasp.org/www-community/attacks/Cross_Site_Tracing", "https://owasp.org/www-project-web-security-testing-guide/latest/4-Web_Application_Security_Testing/02-Configuration_and_Deployment_Management_Testing/06-Test_HTTP_Methods"],
+    },
+    finding! {
+        code: "COOKIE_PREFIX_VIOLATION",
+        title: "Cookie Breaks Its Prefix Rules: {subject}",
+        category: Http,
+        severity: Warning,
+        description: "The '{subject}' cookie uses a name prefix that browsers enforce: '__Secure-' cookies must be set with the 'Secure' attribute, and '__Host-' cookies must also have 'Path=/' and no 'Domain' attribute (so they are locked to this exact host). This cookie misses one of those requirements, so browsers refuse to store it, breaking the feature that relies on it as well as the protection the prefix was meant to give.",
+        remediation: "Set the cookie with the attributes its prefix requires, e.g. 'Set-Cookie: __Host-id=...; Secure; Path=/; HttpOnly; SameSite=Lax'. If the cookie must be shared with subdomains, use the '__Secure-' prefix instead of '__Host-'.",
+        references: ["https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Set-Cookie#cookie_prefixes", "https://datatracker.ietf.org/doc/html/draft-ietf-httpbis-rfc6265bis#section-4.1.3"],
+    },
+    finding! {
+        code: "COOKIE_LONG_LIVED",
+        title: "Long-Lived Session Cookie: {subject}",
+        category: Http,
+        severity: Info,
+        description: "The session cookie {subject} is set with a 'Max-Age' or 'Expires' that keeps it in the browser far longer than a session usually lasts. Anyone who obtains it (from a shared computer, a backup, malware or a leaked log) can replay it for that whole time, unless the server also expires sessions on its side. It is reported because its lifetime exceeds 'cookie_max_lifetime_days'.",
+        remediation: "Give session cookies a short lifetime, or none at all so they end with the browser session, and enforce an idle and absolute timeout on the server. Keep long-lived logins in a separate 'remember me' token that can be revoked and is rotated on use.",
+        references: ["https://cheatsheetseries.owasp.org/cheatsheets/Session_Management_Cheat_Sheet.html#session-expiration", "https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Set-Cookie#max-agenumber"],
+    },
+    finding! {
+        code: "HEADERS_DUPLICATE",
+        title: "Header Sent With Conflicting Values: {subject}",
+        category: Http,
+        severity: Warning,
+        description: "The response sends this header several times, with different values: {subject}. This usually happens when both the application and a proxy or CDN add the same header. Browsers do not agree on how to handle it: some use the first value, some the last, and some combine them into one value (e.g. 'DENY, SAMEORIGIN') that is invalid and therefore ignored, leaving the protection off.",
+        remediation: "Set the header in a single place, either the application or the proxy, and remove it from the other. Check the raw response (press 'r') after the change to confirm only one value is sent.",
+        references: ["https://datatracker.ietf.org/doc/html/rfc9110#section-5.3", "https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/X-Frame-Options"],
+    },
+    finding! {
+        code: "HEADERS_REPEATED",
+        title: "Header Sent More Than Once: {subject}",
+        category: Http,
+        severity: Info,
+        description: "The response repeats this header with the same value: {subject}. Whichever copy a browser picks, the same policy applies, but it shows the header is set in two places (typically the application and a proxy or CDN), which drift apart as soon as one of them is changed.",
+        remediation: "Set the header in a single place, either the application or the proxy, and remove it from the other, so a later change cannot leave two conflicting values.",
+        references: ["https://datatracker.ietf.org/doc/html/rfc9110#section-5.3"],
+    },
+    finding! {
+        code: "HEADERS_CUSTOM_MISSING",
+        title: "Required Header Missing: {subject}",
+        category: Http,
+        severity: Warning,
+        description: "The '{subject}' header is required by your configuration ('custom_headers') but the main response does not send it. The header is part of your organization's header policy rather than the scanner's built-in checks.",
+        remediation: "Configure the web server, application or CDN to send the '{subject}' header with the value your policy requires. If the requirement no longer applies, remove it from 'custom_headers'.",
+        references: ["https://owasp.org/www-project-secure-headers/"],
+    },
+    finding! {
+        code: "HTTP_ONLY_SITE",
+        title: "Site Not Available over HTTPS",
+        category: Http,
+        severity: Critical,
+        description: "The main page was served over plain HTTP: no HTTPS connection could be made to the target, or its HTTPS page redirected to plain HTTP, so the HTTP and technology results were collected over an insecure connection. Without TLS, passwords, session cookies and page content travel in clear text and can be read or modified by anyone on the network path.",
+        remediation: "Serve the site over HTTPS with a certificate from a trusted CA (e.g. Let's Encrypt, which is free and automated), redirect all HTTP requests to HTTPS, then enable HSTS.",
+        references: ["https://letsencrypt.org/getting-started/", "https://developer.mozilla.org/en-US/docs/Web/Security/Transport_Layer_Security"],
+    },
+    finding! {
+        code: "HTTP_MISSING_CONTENT_TYPE",
+        title: "Missing Content-Type Header",
+        category: Http,
+        severity: Info,
+        description: "The main response has no 'Content-Type' header, so browsers have to guess the type of the content from its bytes (MIME sniffing). Content that is sniffed as HTML or script can be executed in the site's origin, which turns uploaded or reflected data into a cross-site scripting vector.",
+        remediation: "Send an explicit 'Content-Type' with every response, including the charset for text (e.g. 'text/html; charset=utf-8'), together with 'X-Content-Type-Options: nosniff'.",
+        references: ["https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Content-Type", "https://developer.mozilla.org/en-US/docs/Web/HTTP/Basics_of_HTTP/MIME_types#mime_sniffing"],
+    },
+    finding! {
+        code: "HTTP_GENERIC_CONTENT_TYPE",
+        title: "HTML Served with a Generic Content-Type",
+        category: Http,
+        severity: Info,
+        description: "The main response is an HTML page but is labeled 'application/octet-stream'. The label does not describe the content, so browsers may download it instead of rendering it, or sniff its real type, and intermediaries cannot apply the right handling. A mislabeled response is a common sign of a misconfigured server or proxy.",
+        remediation: "Serve HTML pages as 'text/html; charset=utf-8' and check the server's MIME type mapping. Keep 'X-Content-Type-Options: nosniff' enabled so browsers never override the declared type.",
+        references: ["https://developer.mozilla.org/en-US/docs/Web/HTTP/Basics_of_HTTP/MIME_types", "https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/X-Content-Type-Options"],
+    },
+    finding! {
+        code: "SCAN_BLOCKED_BY_WAF",
+        title: "Scan Blocked by a WAF: {subject}",
+        category: Http,
+        severity: Info,
+        description: "A web application firewall ({subject}) answered the scanner with a challenge or block page (HTTP 403 or 429) instead of the site. The HTTP headers of that page belong to the WAF, so they were not checked, and the technologies may only describe the WAF. This is not a problem with the site: the WAF is doing its job against automated traffic. The HTTP results are incomplete rather than failed.",
+        remediation: "To audit the site itself, scan it from an address the WAF allows (e.g. an allow-listed IP), or temporarily relax the bot protection for the scanner. Increasing 'request_delay_ms' can help when the block is triggered by the request rate (HTTP 429).",
+        references: ["https://owasp.org/www-community/Web_Application_Firewall"],
+    },
+
+    // --- Technology: Detected Software Stack ---
+    finding! {
+        code: "TECH_EOL_VERSION",
+        title: "End-of-Life Software Version: {subject}",
+        category: Technology,
+        severity: Warning,
+        description: "{subject} has reached end-of-life. It no longer receives security patches, so any vulnerability discovered in it stays exploitable.",
+        remediation: "Upgrade {subject} to a supported release. If it cannot be upgraded right away, at least hide the version banner and plan the migration, since attackers actively scan for outdated versions.",
+        references: ["https://endoflife.date/", "https://owasp.org/Top10/A06_2021-Vulnerable_and_Outdated_Components/"],
     },
 ];
 
+const _: () = assert!(codes_are_unique(FINDINGS), "two knowledge-base entries share a finding code");
+
+/// Whether no two findings share a code. A `const fn`, so the check runs at compile time.
+const fn codes_are_unique(findings: &[FindingDetail]) -> bool {
+    let mut i = 0;
+    while i < findings.len() {
+        let mut j = i + 1;
+        while j < findings.len() {
+            if str_eq(findings[i].code, findings[j].code) {
+                return false;
+            }
+            j += 1;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// String equality usable in a `const fn`, where `==` on `&str` is not.
+const fn str_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
 /// Retrieves the full detail for a given finding code from the static knowledge base.
 ///
 /// # Arguments
@@ -195,4 +648,92 @@ static FINDINGS: &[FindingDetail] = &[
 /// or `None` if the code does not exist in the knowledge base.
 pub fn get_finding_detail(code: &str) -> Option<&'static FindingDetail> {
     FINDINGS.iter().find(|f| f.code == code)
-}
\ No newline at end of file
+}
+
+/// Returns a copy-pasteable configuration snippet that fixes a finding on the given web server.
+///
+/// Snippets only exist where the fix is the same for every site, which today means the
+/// header findings with a fixed recommended value. Everything else (e.g. a CSP, which depends
+/// on the site's own resources) returns `None`, and the generic remediation applies.
+/// The nginx lines belong in the `server` block: an `add_header` in a `location` block
+/// replaces every header inherited from the server block.
+pub fn config_snippet(code: &str, server: WebServer) -> Option<String> {
+    let snippet = match (code, server) {
+        ("HEADERS_HSTS_MISSING", WebServer::Nginx) => "add_header Strict-Transport-Security \"max-age=31536000; includeSubDomains\" always;",
+        ("HEADERS_HSTS_MISSING", WebServer::Apache) => "Header always set Strict-Transport-Security \"max-age=31536000; includeSubDomains\"",
+        ("HEADERS_X_FRAME_OPTIONS_MISSING" | "HEADERS_XFO_INVALID", WebServer::Nginx) => "add_header X-Frame-Options \"SAMEORIGIN\" always;",
+        ("HEADERS_X_FRAME_OPTIONS_MISSING" | "HEADERS_XFO_INVALID", WebServer::Apache) => "Header always set X-Frame-Options \"SAMEORIGIN\"",
+        ("HEADERS_X_CONTENT_TYPE_OPTIONS_MISSING", WebServer::Nginx) => "add_header X-Content-Type-Options \"nosniff\" always;",
+        ("HEADERS_X_CONTENT_TYPE_OPTIONS_MISSING", WebServer::Apache) => "Header always set X-Content-Type-Options \"nosniff\"",
+        // nginx cannot drop a header it sets itself, so the "0" value is the portable fix.
+        ("HEADERS_XSS_PROTECTION_LEGACY", WebServer::Nginx) => "proxy_hide_header X-XSS-Protection;\nadd_header X-XSS-Protection \"0\" always;",
+        ("HEADERS_XSS_PROTECTION_LEGACY", WebServer::Apache) => "Header always unset X-XSS-Protection",
+        ("HEADERS_EXPECT_CT_OBSOLETE", WebServer::Nginx) => "# Remove any 'add_header Expect-CT' line; when proxying, also hide the upstream's:\nproxy_hide_header Expect-CT;",
+        ("HEADERS_EXPECT_CT_OBSOLETE", WebServer::Apache) => "Header always unset Expect-CT",
+        _ => return None,
+    };
+    Some(snippet.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    /// The literal codes passed to `AnalysisFinding::new` in the given Rust sources.
+    fn emitted_codes(source: &str) -> Vec<String> {
+        source.split("AnalysisFinding::new(").skip(1)
+            .filter_map(|call| {
+                // The code is the second argument; a first argument never contains a comma.
+                let (_, rest) = call.split_once(',')?;
+                let literal = rest.trim_start().strip_prefix('"')?;
+                Some(literal[..literal.find('"')?].to_string())
+            })
+            .collect()
+    }
+
+    fn sources(dir: &Path, out: &mut String) {
+        for entry in std::fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                sources(&path, out);
+            } else if path.extension().is_some_and(|extension| extension == "rs") {
+                out.push_str(&std::fs::read_to_string(&path).unwrap());
+            }
+        }
+    }
+
+    #[test]
+    fn every_emitted_code_has_an_entry() {
+        let mut source = String::new();
+        sources(&Path::new(env!("CARGO_MANIFEST_DIR")).join("src"), &mut source);
+        let codes = emitted_codes(&source);
+        assert!(codes.len() > FINDINGS.len() / 2, "the scan for emitted codes found too few: {:?}", codes);
+        let missing: Vec<&String> = codes.iter().filter(|code| get_finding_detail(code).is_none()).collect();
+        assert!(missing.is_empty(), "codes without a knowledge-base entry: {:?}", missing);
+    }
+
+    #[test]
+    fn every_finding_has_a_reference() {
+        let unreferenced: Vec<&str> = FINDINGS.iter()
+            .filter(|detail| detail.references.is_empty() || !detail.references.iter().all(|link| link.starts_with("https://")))
+            .map(|detail| detail.code)
+            .collect();
+        assert!(unreferenced.is_empty(), "findings without an https reference: {:?}", unreferenced);
+    }
+
+    #[test]
+    fn detects_duplicate_codes() {
+        let entry = |code| finding! {
+            code: code,
+            title: "",
+            category: Dns,
+            severity: Info,
+            description: "",
+            remediation: "",
+            references: ["https://example.com/"],
+        };
+        assert!(codes_are_unique(&[entry("DNS_A"), entry("DNS_B")]));
+        assert!(!codes_are_unique(&[entry("DNS_A"), entry("DNS_B"), entry("DNS_A")]));
+    }
+}
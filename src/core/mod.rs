@@ -15,4 +15,13 @@ pub mod scanner;
 /// Contains the business logic for analyzing scan results and generating
 /// findings and recommendations. It acts as a repository of known issues
 /// and best practices.
-pub mod knowledge_base;
\ No newline at end of file
+pub mod knowledge_base;
+
+/// Plain-text renderings of scan reports for use outside the TUI.
+pub mod export;
+
+/// Offline ASN and country lookups for scanned hosts, backed by MaxMind-format databases.
+pub mod geoip;
+
+/// Offline counts of the domains sharing an IP address, backed by a local reverse-IP dataset.
+pub mod reverse_ip;
@@ -2,12 +2,20 @@
 
 use serde::{Serialize, Deserialize};
 use chrono::{DateTime, Utc};
+use crate::core::knowledge_base;
+use std::fmt;
 
 // A custom type alias for a Result that can hold an optional success value or a String error.
 // This is used throughout the scanners to represent operations that might fail or might not
 // find a specific piece of data.
 pub type ScanResult<T> = Result<Option<T>, String>;
 
+/// Serde default for `ScanResult` fields added after the first report format,
+/// so older exported reports still deserialize.
+fn default_scan_result<T>() -> ScanResult<T> {
+    Ok(None)
+}
+
 /// Represents the severity level of an analysis finding.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub enum Severity {
@@ -19,21 +27,80 @@ pub enum Severity {
     Info,
 }
 
+impl Severity {
+    /// Returns a rank where a lower value means a more severe finding.
+    pub fn rank(&self) -> u8 {
+        match self {
+            Severity::Critical => 0,
+            Severity::Warning => 1,
+            Severity::Info => 2,
+        }
+    }
+
+    /// Returns `true` if this severity is at least as severe as `threshold`.
+    pub fn is_at_least(&self, threshold: &Severity) -> bool {
+        self.rank() <= threshold.rank()
+    }
+}
+
+impl std::str::FromStr for Severity {
+    type Err = String;
+
+    /// Parses a severity name case-insensitively (e.g. "critical", "Warning", "INFO").
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "critical" => Ok(Severity::Critical),
+            "warning" => Ok(Severity::Warning),
+            "info" => Ok(Severity::Info),
+            other => Err(format!("unknown severity '{}' (expected critical, warning or info)", other)),
+        }
+    }
+}
+
 /// Represents a single analysis finding, identified by a unique code.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AnalysisFinding {
     pub severity: Severity,
     pub code: String,
+    /// Reference links copied from the knowledge base so exported reports are self-contained.
+    #[serde(default)]
+    pub references: Vec<String>,
+    /// What the finding is about, for generic findings whose knowledge-base text is a
+    /// template (e.g. the header name of `HEADERS_CUSTOM_MISSING`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
+    /// The severity the finding had before strict mode escalated it to Critical.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub escalated_from: Option<Severity>,
 }
 
 impl AnalysisFinding {
     /// Constructs a new `AnalysisFinding`.
     ///
+    /// The reference links are looked up in the knowledge base; unknown codes get none.
+    ///
     /// # Arguments
     /// * `severity` - The severity level of the finding.
     /// * `code` - A unique string identifier for the finding.
     pub fn new(severity: Severity, code: &str) -> Self {
-        Self { severity, code: code.to_string() }
+        let references = knowledge_base::get_finding_detail(code)
+            .map(|detail| detail.references.iter().map(|r| r.to_string()).collect())
+            .unwrap_or_default();
+        Self { severity, code: code.to_string(), references, subject: None, escalated_from: None }
+    }
+
+    /// Attaches the subject that fills in the `{subject}` placeholder of the knowledge-base text.
+    pub fn with_subject(mut self, subject: &str) -> Self {
+        self.subject = Some(subject.to_string());
+        self
+    }
+
+    /// Fills the `{subject}` placeholder of a knowledge-base text with this finding's subject.
+    pub fn fill_template(&self, text: &str) -> String {
+        match &self.subject {
+            Some(subject) => text.replace("{subject}", subject),
+            None => text.to_string(),
+        }
     }
 }
 
@@ -45,6 +112,43 @@ impl AnalysisFinding {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SpfData {
     pub record: String,
+    /// The record broken into its terms; the raw `record` stays authoritative.
+    #[serde(default)]
+    pub parsed: SpfParsed,
+}
+
+/// An SPF record split into its mechanisms and modifiers.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SpfParsed {
+    /// The version tag, normally "spf1".
+    pub version: String,
+    /// The mechanisms in evaluation order.
+    pub mechanisms: Vec<SpfMechanism>,
+    /// Modifiers such as "redirect=_spf.example.com", kept verbatim.
+    pub modifiers: Vec<String>,
+}
+
+/// A single SPF mechanism, e.g. `include:_spf.google.com` or `-all`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpfMechanism {
+    /// The qualifier: '+' (pass, the default when omitted), '-' (fail), '~' (softfail) or '?' (neutral).
+    pub qualifier: char,
+    /// The mechanism name in lowercase: "all", "include", "a", "mx", "ptr", "ip4", "ip6" or "exists".
+    pub kind: String,
+    /// The argument, if any: the domain or network after ':', or a CIDR length such as "/24".
+    pub value: Option<String>,
+}
+
+impl SpfMechanism {
+    /// Describes what the qualifier makes a matching sender result in.
+    pub fn qualifier_meaning(&self) -> &'static str {
+        match self.qualifier {
+            '-' => "fail",
+            '~' => "softfail",
+            '?' => "neutral",
+            _ => "pass",
+        }
+    }
 }
 
 /// Holds data for a Domain-based Message Authentication, Reporting, and Conformance (DMARC) record.
@@ -52,6 +156,13 @@ pub struct SpfData {
 pub struct DmarcData {
     pub record: String,
     pub policy: Option<String>,
+    /// The record's tags in order, as (name, value) pairs with whitespace trimmed, e.g. ("p", "reject").
+    #[serde(default)]
+    pub tags: Vec<(String, String)>,
+    /// What makes the record invalid (e.g. "invalid pct value '150'"); empty for a well-formed record.
+    /// Receivers ignore an invalid record, so the domain is effectively unprotected.
+    #[serde(default)]
+    pub errors: Vec<String>,
 }
 
 /// Holds data for a DomainKeys Identified Mail (DKIM) record.
@@ -59,31 +170,181 @@ pub struct DmarcData {
 pub struct DkimRecord {
     pub selector: String,
     pub record: String,
+    /// The email provider implied by the selector (e.g. "Google Workspace"), if it is vendor-specific.
+    #[serde(default)]
+    pub provider: Option<String>,
+}
+
+/// A mail exchanger of the domain, from its MX records.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MxRecord {
+    /// Lower is tried first.
+    pub preference: u16,
+    /// The mail server's host name, without the trailing dot.
+    pub exchange: String,
+    /// The addresses `exchange` resolved to; empty if it did not resolve.
+    pub addresses: Vec<String>,
+}
+
+/// Where the scanned host is served from, as far as the offline GeoIP and reverse-IP data know.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct HostingInfo {
+    /// The first address the host resolved to.
+    pub ip: String,
+    pub asn: Option<u32>,
+    pub as_org: Option<String>,
+    /// The ISO 3166-1 alpha-2 country code, e.g. "US".
+    pub country: Option<String>,
+    /// How many domains the reverse-IP dataset lists on `ip`; `None` without a dataset entry.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shared_domains: Option<usize>,
 }
 
 /// Aggregates the results of a DNS scan.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DnsResults {
+    /// The resolver the lookups went through, e.g. "DNS-over-HTTPS (Cloudflare)". Empty for reports saved by older versions.
+    #[serde(default)]
+    pub resolver: String,
     pub spf: ScanResult<SpfData>,
     pub dmarc: ScanResult<DmarcData>,
     pub dkim: ScanResult<Vec<DkimRecord>>,
     pub caa: ScanResult<Vec<String>>,
+    /// The mail exchangers, by preference. Empty for reports saved by older versions.
+    #[serde(default = "default_scan_result")]
+    pub mx: ScanResult<Vec<MxRecord>>,
+    /// `None` if the host did not resolve. The GeoIP fields stay empty without a database.
+    #[serde(default)]
+    pub hosting: Option<HostingInfo>,
     pub analysis: Vec<AnalysisFinding>,
+    /// Set when the domain does not exist (NXDOMAIN); the record lookups are then skipped.
+    #[serde(default)]
+    pub domain_not_found: bool,
+    /// Set when this scanner was disabled in the configuration; every other field is left empty.
+    #[serde(default)]
+    pub disabled: bool,
+}
+
+/// A public DNS-over-HTTPS service the DNS scanner can send its lookups to.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum DohProvider {
+    Cloudflare,
+    Google,
+}
+
+impl fmt::Display for DohProvider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DohProvider::Cloudflare => write!(f, "Cloudflare"),
+            DohProvider::Google => write!(f, "Google"),
+        }
+    }
+}
+
+/// A web server the remediation config snippets can be written for.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum WebServer {
+    Nginx,
+    Apache,
+}
+
+impl WebServer {
+    /// Picks the web server from the fingerprint results, if one of the supported ones was detected.
+    pub fn detect(technologies: &[Technology]) -> Option<Self> {
+        technologies.iter().find_map(|tech| match tech.name.as_str() {
+            "Nginx" => Some(WebServer::Nginx),
+            "Apache" => Some(WebServer::Apache),
+            _ => None,
+        })
+    }
+}
+
+impl fmt::Display for WebServer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WebServer::Nginx => write!(f, "nginx"),
+            WebServer::Apache => write!(f, "Apache"),
+        }
+    }
 }
 
 impl Default for DnsResults {
     /// Provides a default, empty state for `DnsResults`.
     fn default() -> Self {
         Self {
+            resolver: String::new(),
             spf: Ok(None),
             dmarc: Ok(None),
             dkim: Ok(None),
             caa: Ok(None),
+            mx: Ok(None),
+            hosting: None,
             analysis: Vec::new(),
+            domain_not_found: false,
+            disabled: false,
         }
     }
 }
 
+impl DnsResults {
+    /// The 0-100 email security sub-score: how well SPF, DMARC and DKIM protect the domain from
+    /// spoofing, apart from the rest of DNS. Each record earns points by how strictly it is
+    /// enforced, out of 50 for DMARC (reject, or quarantine for 35, scaled down by `pct`), 30 for
+    /// SPF (`-all`, or `~all` for 20, less 10 when it leaves the domain's own mail servers out)
+    /// and 20 for DKIM (any record). BIMI is not looked up, so it does not count.
+    ///
+    /// Returns `None` when the records are unknown: the scan was disabled, the domain does not
+    /// exist, or one of the lookups failed.
+    pub fn email_security_score(&self) -> Option<u8> {
+        if self.disabled || self.domain_not_found {
+            return None;
+        }
+        let (Ok(spf), Ok(dmarc), Ok(dkim)) = (&self.spf, &self.dmarc, &self.dkim) else { return None };
+
+        let dmarc_points = match dmarc {
+            Some(dmarc) if dmarc.errors.is_empty() => {
+                let enforced: u32 = match dmarc.policy.as_deref() {
+                    Some("reject") => 50,
+                    Some("quarantine") => 35,
+                    _ => 0,
+                };
+                // `pct` applies the policy to a share of the failing mail; the rest is only monitored.
+                let pct = dmarc.tags.iter()
+                    .find(|(name, _)| name == "pct")
+                    .and_then(|(_, value)| value.parse::<u32>().ok())
+                    .unwrap_or(100)
+                    .min(100);
+                // Publishing a record at all earns the points of monitoring (p=none).
+                10 + enforced.saturating_sub(10) * pct / 100
+            }
+            _ => 0,
+        };
+        // An enforcing policy with neither SPF nor DKIM to pass rejects legitimate mail too.
+        let dmarc_points = if spf.is_none() && dkim.is_none() { dmarc_points.min(10) } else { dmarc_points };
+
+        let spf_points = match spf {
+            Some(spf) => {
+                let all = spf.parsed.mechanisms.iter().rev().find(|mechanism| mechanism.kind == "all");
+                let redirect = spf.parsed.modifiers.iter().any(|modifier| modifier.to_ascii_lowercase().starts_with("redirect="));
+                let points: u32 = match all.map(|mechanism| mechanism.qualifier) {
+                    Some('-') => 30,
+                    Some('~') => 20,
+                    // The redirected record's policy is not looked up, so it gets the benefit of the doubt.
+                    None if redirect => 20,
+                    Some('?') | None => 5,
+                    _ => 0,
+                };
+                let mx_not_covered = self.analysis.iter().any(|finding| finding.code == "DNS_SPF_MX_NOT_COVERED");
+                if mx_not_covered { points.saturating_sub(10) } else { points }
+            }
+            None => 0,
+        };
+
+        let dkim_points = if dkim.is_some() { 20 } else { 0 };
+        Some((dmarc_points + spf_points + dkim_points) as u8)
+    }
+}
+
 //====================================================================================
 // SSL/TLS Scanner Models
 //====================================================================================
@@ -93,9 +354,22 @@ impl Default for DnsResults {
 pub struct CertificateInfo {
     pub subject_name: String,
     pub issuer_name: String,
+    /// The organization (O=) of the issuer, e.g. "Let's Encrypt", used to identify the CA.
+    #[serde(default)]
+    pub issuer_organization: Option<String>,
+    /// The certificate serial number in hex, used to tell certificates apart.
+    #[serde(default)]
+    pub serial_number: Option<String>,
+    /// The DNS names from the Subject Alternative Name extension, e.g. "*.example.com".
+    #[serde(default)]
+    pub subject_alt_names: Vec<String>,
     pub not_before: DateTime<Utc>,
     pub not_after: DateTime<Utc>,
     pub days_until_expiry: i64,
+    /// How many hours ago the certificate became valid (`not_before`), at scan time. Zero for
+    /// reports saved by older versions.
+    #[serde(default)]
+    pub hours_since_issued: i64,
 }
 
 /// Holds the core data from an SSL/TLS scan.
@@ -103,6 +377,34 @@ pub struct CertificateInfo {
 pub struct SslData {
     pub is_valid: bool,
     pub certificate_info: CertificateInfo,
+    /// Whether the certificate covers names through a wildcard SAN (`*.example.com`).
+    #[serde(default)]
+    pub is_wildcard: bool,
+    /// The application protocol (e.g. "h2") selected by the server via ALPN during the handshake.
+    /// `native-tls` only exposes the negotiated protocol, so this holds at most one entry
+    /// chosen among the protocols the scanner offered; empty if the server ignored ALPN.
+    #[serde(default)]
+    pub alpn: Vec<String>,
+    /// Whether TLS-level compression was negotiated (which enables the CRIME attack) by a
+    /// TLS 1.2 handshake offering it. `None` when the TLS backend cannot tell: it needs OpenSSL
+    /// built with compression support, and native-tls does not use OpenSSL on Windows and macOS.
+    #[serde(default)]
+    pub compression_enabled: Option<bool>,
+    /// The distinct leaf certificates seen when handshaking with RSA-only and ECDSA-only
+    /// preferences. Empty unless the (opt-in) dual-certificate probe ran.
+    #[serde(default)]
+    pub certificates: Vec<CertificateInfo>,
+    /// Whether the certificate carries the OCSP Must-Staple (TLS Feature `status_request`) extension.
+    #[serde(default)]
+    pub must_staple: bool,
+    /// Whether the server stapled an OCSP response when asked. Only probed for Must-Staple
+    /// certificates, and `None` when not probed or when the TLS backend cannot tell.
+    #[serde(default)]
+    pub ocsp_stapled: Option<bool>,
+    /// Whether a second handshake could resume the first one's session (session ID or ticket).
+    /// `None` unless the opt-in probe ran and completed, which needs the OpenSSL backend.
+    #[serde(default)]
+    pub resumption_supported: Option<bool>,
 }
 
 /// Aggregates the results of an SSL/TLS scan.
@@ -110,6 +412,14 @@ pub struct SslData {
 pub struct SslResults {
     pub scan: ScanResult<SslData>,
     pub analysis: Vec<AnalysisFinding>,
+    /// The hosting default (e.g. "Traefik") behind the certificate served instead of one for
+    /// the scanned host, i.e. the server ignored SNI. A verified handshake proves the certificate
+    /// covers the host, so this is only looked for after the handshake failed.
+    #[serde(default)]
+    pub default_certificate: Option<String>,
+    /// Set when this scanner was disabled in the configuration; every other field is left empty.
+    #[serde(default)]
+    pub disabled: bool,
 }
 
 impl Default for SslResults {
@@ -118,6 +428,8 @@ impl Default for SslResults {
         Self {
             scan: Ok(None),
             analysis: Vec::new(),
+            default_certificate: None,
+            disabled: false,
         }
     }
 }
@@ -129,7 +441,28 @@ impl Default for SslResults {
 /// A generic struct to hold the value of a single HTTP header.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HeaderData {
+    /// The first value sent for the header.
     pub value: String,
+    /// Every value, in order, when the header was sent more than once; empty otherwise.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub all_values: Vec<String>,
+}
+
+/// An additional header an organization requires, configured in `custom_headers`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomHeaderCheck {
+    /// The header name, e.g. "X-Permitted-Cross-Domain-Policies".
+    pub name: String,
+    /// The severity to report the header's absence with.
+    pub severity: Severity,
+}
+
+/// The outcome of a `CustomHeaderCheck` against the main response.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomHeaderResult {
+    pub name: String,
+    pub severity: Severity,
+    pub header: ScanResult<HeaderData>,
 }
 
 /// Aggregates the results of an HTTP security headers scan.
@@ -139,10 +472,97 @@ pub struct HeadersResults {
     pub csp: ScanResult<HeaderData>,
     pub x_frame_options: ScanResult<HeaderData>,
     pub x_content_type_options: ScanResult<HeaderData>,
+    /// The deprecated `X-XSS-Protection` header, captured so its presence can be flagged.
+    #[serde(default = "default_scan_result")]
+    pub x_xss_protection: ScanResult<HeaderData>,
+    /// The `Cache-Control` header, checked together with `session_cookies`.
+    #[serde(default = "default_scan_result")]
+    pub cache_control: ScanResult<HeaderData>,
+    /// The obsolete `Expect-CT` header, captured so its presence can be flagged.
+    #[serde(default = "default_scan_result")]
+    pub expect_ct: ScanResult<HeaderData>,
+    /// Names of the session-like cookies set by the response (e.g. "PHPSESSID").
+    #[serde(default)]
+    pub session_cookies: Vec<String>,
+    /// The session-like cookies set to live longer than `cookie_max_lifetime_days`, with their
+    /// lifetime in days.
+    #[serde(default)]
+    pub long_lived_session_cookies: Vec<(String, u64)>,
+    /// Names of the `__Host-`/`__Secure-` cookies set without the attributes their prefix requires.
+    #[serde(default)]
+    pub cookie_prefix_violations: Vec<String>,
+    /// Whether a `TRACE` request echoed the probe's marker header back (Cross-Site Tracing).
+    /// `None` when the opt-in probe did not run or its request failed.
+    #[serde(default)]
+    pub trace_reflected: Option<bool>,
+    /// The `Content-Type` the main response was served with.
+    #[serde(default = "default_scan_result")]
+    pub content_type: ScanResult<HeaderData>,
+    /// The `Access-Control-Allow-Origin` header of the main response (CORS).
+    #[serde(default = "default_scan_result")]
+    pub access_control_allow_origin: ScanResult<HeaderData>,
+    /// Whether a request from a foreign `Origin` had that origin echoed back as allowed.
+    /// `None` when the opt-in probe did not run or its request failed.
+    #[serde(default)]
+    pub cors_origin_reflected: Option<bool>,
+    /// Whether the body of the main response looks like an HTML document.
+    #[serde(default)]
+    pub html_body: bool,
+    /// Set when HTTPS was unreachable and these headers were read over plain HTTP.
+    #[serde(default)]
+    pub http_only: bool,
+    /// The WAF whose challenge or block page answered the request, e.g. "Cloudflare".
+    /// The headers then belong to that page, so they are not checked.
+    #[serde(default)]
+    pub blocked_by_waf: Option<String>,
+    /// The headers required by the configuration, with what the response sent for each.
+    #[serde(default)]
+    pub custom_headers: Vec<CustomHeaderResult>,
+    /// The protocols the main response advertises beyond the one it was served over, e.g.
+    /// "h3 (Alt-Svc)" for HTTP/3 or "h2c (Upgrade)" for cleartext HTTP/2, in header order.
+    #[serde(default)]
+    pub advertised_protocols: Vec<String>,
+    /// The request the headers were read from; `None` for reports saved by older versions.
+    #[serde(default)]
+    pub request: Option<RequestDetails>,
+    /// Every header of the main response as (name, value) pairs, sorted by name, for debugging.
+    #[serde(default)]
+    pub raw_headers: Vec<(String, String)>,
+    /// The start of the response body, cut at `RAW_BODY_LIMIT` bytes.
+    #[serde(default)]
+    pub raw_body: Option<String>,
+    /// The full size of the response body in bytes, before truncation. Only the start of the
+    /// body is downloaded, so for a longer body this is its `Content-Length`.
+    #[serde(default)]
+    pub raw_body_size: usize,
+    /// Set when the body was longer than what the scan downloads and gave no `Content-Length`,
+    /// so `raw_body_size` only counts the downloaded part.
+    #[serde(default)]
+    pub raw_body_size_unknown: bool,
     pub error: Option<String>,
     pub analysis: Vec<AnalysisFinding>,
+    /// Set when this scanner was disabled in the configuration; every other field is left empty.
+    #[serde(default)]
+    pub disabled: bool,
 }
 
+/// The main-page request an HTTP-based scanner sent, for reproducing a disputed finding by hand.
+/// The URLs never carry credentials.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RequestDetails {
+    /// E.g. "GET", or "HEAD" for the quick fingerprint.
+    pub method: String,
+    /// The URL requested, after any fallback to plain HTTP, e.g. "https://example.com/".
+    pub url: String,
+    /// Where the redirects led, when that is not `url`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub final_url: Option<String>,
+    pub user_agent: String,
+}
+
+/// How much of the main response body `HeadersResults` keeps for the raw response view.
+pub const RAW_BODY_LIMIT: usize = 4096;
+
 impl Default for HeadersResults {
     /// Provides a default, empty state for `HeadersResults`.
     fn default() -> Self {
@@ -151,8 +571,29 @@ impl Default for HeadersResults {
             csp: Ok(None),
             x_frame_options: Ok(None),
             x_content_type_options: Ok(None),
+            x_xss_protection: Ok(None),
+            expect_ct: Ok(None),
+            cache_control: Ok(None),
+            session_cookies: Vec::new(),
+            long_lived_session_cookies: Vec::new(),
+            cookie_prefix_violations: Vec::new(),
+            trace_reflected: None,
+            content_type: Ok(None),
+            access_control_allow_origin: Ok(None),
+            cors_origin_reflected: None,
+            html_body: false,
+            http_only: false,
+            custom_headers: Vec::new(),
+            advertised_protocols: Vec::new(),
+            request: None,
+            raw_headers: Vec::new(),
+            raw_body: None,
+            raw_body_size: 0,
+            raw_body_size_unknown: false,
+            blocked_by_waf: None,
             error: None,
             analysis: Vec::new(),
+            disabled: false,
         }
     }
 }
@@ -173,6 +614,27 @@ pub struct Technology {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FingerprintResults {
     pub technologies: Result<Vec<Technology>, String>,
+    #[serde(default)]
+    pub analysis: Vec<AnalysisFinding>,
+    /// Set when HTTPS was unreachable and the page was fingerprinted over plain HTTP.
+    #[serde(default)]
+    pub http_only: bool,
+    /// The WAF whose challenge or block page was fingerprinted instead of the site, if any.
+    #[serde(default)]
+    pub blocked_by_waf: Option<String>,
+    /// Set when only the response headers were fingerprinted (the quick `HEAD` mode), so
+    /// technologies only visible in the page itself were not looked for.
+    #[serde(default)]
+    pub headers_only: bool,
+    /// Set when the error page or admin panel probe ran, so the paths they expose are known.
+    #[serde(default)]
+    pub paths_probed: bool,
+    /// The request the page was fingerprinted from; `None` for reports saved by older versions.
+    #[serde(default)]
+    pub request: Option<RequestDetails>,
+    /// Set when this scanner was disabled in the configuration; every other field is left empty.
+    #[serde(default)]
+    pub disabled: bool,
 }
 
 impl Default for FingerprintResults {
@@ -180,6 +642,59 @@ impl Default for FingerprintResults {
     fn default() -> Self {
         Self {
             technologies: Ok(Vec::new()),
+            analysis: Vec::new(),
+            http_only: false,
+            blocked_by_waf: None,
+            headers_only: false,
+            paths_probed: false,
+            request: None,
+            disabled: false,
+        }
+    }
+}
+
+//====================================================================================
+// Open Redirect Scanner Models
+//====================================================================================
+
+/// Aggregates the redirect chain of the main page and the results of the (opt-in) open redirect probe.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RedirectResults {
+    /// Every URL visited when following the main page's redirects, starting with the
+    /// initial one. A loop ends with the first repeated URL.
+    #[serde(default = "default_scan_result")]
+    pub chain: ScanResult<Vec<String>>,
+    /// The probe URLs that redirected to the injected external site.
+    /// `Ok(None)` when the probe is disabled, and an error when every probe request failed.
+    pub scan: ScanResult<Vec<String>>,
+    /// The probe URLs whose request failed or timed out, so their parameter was not tested.
+    #[serde(default)]
+    pub failed_probes: Vec<String>,
+    pub analysis: Vec<AnalysisFinding>,
+    /// Set when this scanner was disabled in the configuration; every other field is left empty.
+    #[serde(default)]
+    pub disabled: bool,
+}
+
+impl Default for RedirectResults {
+    /// Provides a default, empty state for `RedirectResults`.
+    fn default() -> Self {
+        Self {
+            chain: Ok(None),
+            scan: Ok(None),
+            failed_probes: Vec::new(),
+            analysis: Vec::new(),
+            disabled: false,
+        }
+    }
+}
+
+impl RedirectResults {
+    /// Whether the redirect chain loops, i.e. ends with a URL visited before.
+    pub fn has_loop(&self) -> bool {
+        match &self.chain {
+            Ok(Some(chain)) => chain.split_last().is_some_and(|(last, earlier)| earlier.contains(last)),
+            _ => false,
         }
     }
 }
@@ -196,4 +711,279 @@ pub struct ScanReport {
     pub ssl_results: SslResults,
     pub headers_results: HeadersResults,
     pub fingerprint_results: FingerprintResults,
-}
\ No newline at end of file
+    #[serde(default)]
+    pub redirect_results: RedirectResults,
+    /// How long the scan took, in milliseconds. Zero for reports saved by older versions.
+    #[serde(default)]
+    pub duration_ms: u64,
+    /// A free-text note attached by the user before exporting, e.g. "pre-migration baseline".
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
+    /// The baseline profile the score is computed against. `General` for reports saved by older versions.
+    #[serde(default)]
+    pub scoring_profile: ScoringProfile,
+    /// Set when a WAF answered the HTTP-based scanners with a challenge or block page (see `blocking_waf`).
+    #[serde(default)]
+    pub scan_blocked: bool,
+    /// Set when the scan was re-pointed at the host the requested one redirects to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub followed_redirect: Option<FollowedRedirect>,
+    /// Problems the scanners ran into that left some data missing or incomplete, e.g. an
+    /// unparseable certificate (see `diagnostics::collect_warnings`). Not findings about the site.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+    /// Every host the scan connected to, DNS resolvers included, for auditing. Empty for
+    /// reports saved by older versions.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub contacted_hosts: Vec<String>,
+    /// The hosts offline mode refused to connect to, as they are not the target.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub blocked_hosts: Vec<String>,
+}
+
+/// The requested target and the host the scan actually ran against after following its redirects.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FollowedRedirect {
+    /// The host the user asked for, e.g. "example.com".
+    pub requested: String,
+    /// The host its main page redirects to, which every scanner ran against, e.g. "www.example.com".
+    pub scanned: String,
+}
+
+/// A baseline of what matters for a kind of site, used to weigh findings in the score.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ScoringProfile {
+    /// Every finding counts at its severity's full weight.
+    #[default]
+    General,
+    /// Handles accounts and payments: session, transport and anti-phishing issues weigh more.
+    Ecommerce,
+    /// Mostly static content with little or no email: email DNS and session issues weigh less.
+    Blog,
+}
+
+impl ScoringProfile {
+    /// The findings each profile weighs differently, as a percentage of the severity's penalty.
+    /// Findings that are not listed count at 100%.
+    fn weights(&self) -> &'static [(&'static str, u32)] {
+        match self {
+            ScoringProfile::General => &[],
+            ScoringProfile::Ecommerce => &[
+                ("HEADERS_HSTS_MISSING", 150),
+                ("HEADERS_CSP_MISSING", 150),
+                ("HTTP_SENSITIVE_CACHEABLE", 150),
+                ("COOKIE_PREFIX_VIOLATION", 150),
+                ("HTTP_OPEN_REDIRECT", 150),
+                ("DNS_DMARC_MISSING", 150),
+            ],
+            ScoringProfile::Blog => &[
+                ("DNS_DMARC_MISSING", 50),
+                ("DNS_DMARC_POLICY_NONE", 50),
+                ("DNS_DMARC_UNENFORCEABLE", 50),
+                ("DNS_SPF_MISSING", 50),
+                ("HTTP_SENSITIVE_CACHEABLE", 50),
+            ],
+        }
+    }
+
+    /// The weight of a finding code in this profile, as a percentage.
+    pub fn weight(&self, code: &str) -> u32 {
+        self.weights().iter().find(|(weighted, _)| *weighted == code).map_or(100, |(_, weight)| *weight)
+    }
+}
+
+impl fmt::Display for ScoringProfile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScoringProfile::General => write!(f, "General"),
+            ScoringProfile::Ecommerce => write!(f, "E-commerce"),
+            ScoringProfile::Blog => write!(f, "Blog"),
+        }
+    }
+}
+
+impl std::str::FromStr for ScoringProfile {
+    type Err = String;
+
+    /// Parses a profile name case-insensitively (e.g. "general", "Ecommerce", "BLOG").
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "general" => Ok(ScoringProfile::General),
+            "ecommerce" => Ok(ScoringProfile::Ecommerce),
+            "blog" => Ok(ScoringProfile::Blog),
+            other => Err(format!("unknown scoring profile '{}' (expected general, ecommerce or blog)", other)),
+        }
+    }
+}
+
+/// Calculates the 0-100 security score of a set of findings:
+/// 15 points off for each critical finding and 5 for each warning, weighted by the profile.
+pub fn score_findings<'a>(findings: impl IntoIterator<Item = &'a AnalysisFinding>, profile: ScoringProfile) -> u8 {
+    let penalty: u32 = findings.into_iter().map(|finding| finding_penalty(finding, profile)).sum();
+    score_from_penalty(penalty)
+}
+
+/// The points one finding takes off the score, in hundredths of a point so the profile's
+/// percentages stay exact (e.g. 750 for a Warning weighted at 150%).
+pub fn finding_penalty(finding: &AnalysisFinding, profile: ScoringProfile) -> u32 {
+    let base = match finding.severity {
+        Severity::Critical => 15,
+        Severity::Warning => 5,
+        Severity::Info => 0,
+    };
+    base * profile.weight(&finding.code)
+}
+
+/// Turns a total penalty in hundredths of a point into the score. Fractions of a point are
+/// rounded up, so any penalty at all costs at least one point.
+pub fn score_from_penalty(penalty: u32) -> u8 {
+    100_u32.saturating_sub(penalty.div_ceil(100)) as u8
+}
+
+/// Returns `true` when none of the findings needs action, i.e. there is no Critical or
+/// Warning finding. Info findings do not make a scan "dirty".
+pub fn findings_are_clean<'a>(findings: impl IntoIterator<Item = &'a AnalysisFinding>) -> bool {
+    findings.into_iter().all(|finding| finding.severity == Severity::Info)
+}
+
+/// Maps a score to a school-style letter grade, from A (90+) down to F (below 60).
+pub fn grade(score: u8) -> char {
+    match score {
+        90..=100 => 'A',
+        80..=89 => 'B',
+        70..=79 => 'C',
+        60..=69 => 'D',
+        _ => 'F',
+    }
+}
+
+impl ScanReport {
+    /// Iterates over the findings of every scanner, in DNS, SSL, Headers, Fingerprint, Redirect order.
+    pub fn findings(&self) -> impl Iterator<Item = &AnalysisFinding> {
+        self.dns_results.analysis.iter()
+            .chain(self.ssl_results.analysis.iter())
+            .chain(self.headers_results.analysis.iter())
+            .chain(self.fingerprint_results.analysis.iter())
+            .chain(self.redirect_results.analysis.iter())
+    }
+
+    /// The WAF that blocked the HTTP-based scanners, if any, as recorded by the headers
+    /// or the fingerprint scanner.
+    pub fn blocking_waf(&self) -> Option<&str> {
+        self.headers_results.blocked_by_waf.as_deref()
+            .or(self.fingerprint_results.blocked_by_waf.as_deref())
+    }
+
+    /// Mutable access to each scanner's analysis vector, for passes that edit findings in place.
+    pub fn analyses_mut(&mut self) -> [&mut Vec<AnalysisFinding>; 5] {
+        [
+            &mut self.dns_results.analysis,
+            &mut self.ssl_results.analysis,
+            &mut self.headers_results.analysis,
+            &mut self.fingerprint_results.analysis,
+            &mut self.redirect_results.analysis,
+        ]
+    }
+
+    /// The security score of the report's findings, against its scoring profile. See `score_findings`.
+    pub fn score(&self) -> u8 {
+        score_findings(self.findings(), self.scoring_profile)
+    }
+
+    /// Whether the scan passed: no scanner reported a Critical or Warning finding.
+    /// See `findings_are_clean`.
+    pub fn is_clean(&self) -> bool {
+        findings_are_clean(self.findings())
+    }
+
+    /// A one-line, grep-friendly summary of the report, e.g.
+    /// `example.com score=72 grade=C critical=1 warning=3 info=2 clean=false duration=1843ms`.
+    /// A followed redirect adds the host actually scanned, e.g. `scanned=www.example.com`.
+    pub fn status_line(&self, target: &str) -> String {
+        let count = |severity: Severity| self.findings().filter(|finding| finding.severity == severity).count();
+        let score = self.score();
+        let scanned = self.followed_redirect.as_ref().map(|redirect| format!(" scanned={}", redirect.scanned)).unwrap_or_default();
+        format!(
+            "{} score={} grade={} critical={} warning={} info={} clean={} duration={}ms{}",
+            target,
+            score,
+            grade(score),
+            count(Severity::Critical),
+            count(Severity::Warning),
+            count(Severity::Info),
+            self.is_clean(),
+            self.duration_ms,
+            scanned,
+        )
+    }
+
+    /// Returns a copy of the report that only keeps findings at or above `min_severity`.
+    /// The scan data itself is left untouched.
+    pub fn filtered_by_severity(&self, min_severity: &Severity) -> ScanReport {
+        let mut report = self.clone();
+        for analysis in report.analyses_mut() {
+            analysis.retain(|finding| finding.severity.is_at_least(min_severity));
+        }
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dns(spf: Option<&str>, dmarc_tags: &[(&str, &str)], dkim: bool) -> DnsResults {
+        let tags: Vec<(String, String)> = dmarc_tags.iter().map(|(name, value)| (name.to_string(), value.to_string())).collect();
+        DnsResults {
+            spf: Ok(spf.map(|record| SpfData {
+                record: record.to_string(),
+                parsed: crate::core::scanner::dns_scanner::parse_spf(record),
+            })),
+            dmarc: Ok((!tags.is_empty()).then(|| DmarcData {
+                record: String::new(),
+                policy: tags.iter().find(|(name, _)| name == "p").map(|(_, value)| value.clone()),
+                tags,
+                errors: Vec::new(),
+            })),
+            dkim: Ok(dkim.then(|| vec![DkimRecord { selector: "default".to_string(), record: String::new(), provider: None }])),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn email_security_score_rewards_enforcement() {
+        let strict = dns(Some("v=spf1 mx -all"), &[("v", "DMARC1"), ("p", "reject")], true);
+        assert_eq!(strict.email_security_score(), Some(100));
+        let quarantine = dns(Some("v=spf1 mx ~all"), &[("v", "DMARC1"), ("p", "quarantine")], true);
+        assert_eq!(quarantine.email_security_score(), Some(35 + 20 + 20));
+        let partial = dns(Some("v=spf1 mx -all"), &[("v", "DMARC1"), ("p", "reject"), ("pct", "50")], false);
+        assert_eq!(partial.email_security_score(), Some(30 + 30));
+        assert_eq!(dns(None, &[], false).email_security_score(), Some(0));
+
+        let failed = DnsResults { dmarc: Err("SERVFAIL".to_string()), ..strict };
+        assert_eq!(failed.email_security_score(), None);
+    }
+
+    #[test]
+    fn only_warning_and_critical_findings_make_a_scan_dirty() {
+        let mut report = ScanReport::default();
+        assert!(report.is_clean());
+        report.headers_results.analysis.push(AnalysisFinding::new(Severity::Info, "HEADERS_EXPECT_CT_OBSOLETE"));
+        assert!(report.is_clean());
+        report.dns_results.analysis.push(AnalysisFinding::new(Severity::Warning, "DNS_DMARC_MISSING"));
+        assert!(!report.is_clean());
+        assert!(!findings_are_clean(&[AnalysisFinding::new(Severity::Critical, "SSL_EXPIRED")]));
+    }
+
+    #[test]
+    fn disabled_and_failed_scanners_do_not_change_cleanliness() {
+        // The definition only looks at findings: a scanner that did not run reports none.
+        let mut report = ScanReport::default();
+        report.ssl_results.disabled = true;
+        report.fingerprint_results.technologies = Err("connection refused".to_string());
+        report.dns_results.spf = Err("SERVFAIL".to_string());
+        assert!(report.is_clean());
+        report.redirect_results.analysis.push(AnalysisFinding::new(Severity::Warning, "HTTP_OPEN_REDIRECT"));
+        assert!(!report.is_clean());
+    }
+}
@@ -0,0 +1,87 @@
+// src/core/reverse_ip.rs
+
+//! Offline reverse-IP counts: how many domains a local dataset lists on an IP address, to
+//! tell a dedicated server from a shared hosting one.
+//!
+//! Like the GeoIP databases, the dataset is read once at startup and never leaves the machine.
+//! Online reverse-IP services are not used, since they would disclose every scanned host to a
+//! third party.
+
+use std::collections::{HashMap, HashSet};
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::net::IpAddr;
+use std::path::Path;
+use std::sync::OnceLock;
+use tracing::{info, warn};
+
+/// The domain counts loaded by `init`, shared by every scan.
+static COUNTS: OnceLock<HashMap<IpAddr, usize>> = OnceLock::new();
+
+/// Loads the dataset at `path`. A file that is missing or unreadable is logged and skipped,
+/// in which case lookups simply return nothing. Only the first call has an effect.
+pub fn init(path: &Path) {
+    let counts = match File::open(path).and_then(|file| parse(BufReader::new(file))) {
+        Ok(counts) => {
+            info!(path = %path.display(), ips = counts.len(), "Reverse-IP dataset loaded.");
+            counts
+        }
+        Err(e) => {
+            warn!(path = %path.display(), error = %e, "Reverse-IP dataset unavailable.");
+            HashMap::new()
+        }
+    };
+    let _ = COUNTS.set(counts);
+}
+
+/// How many domains the dataset lists on `ip`. Returns `None` when no dataset is loaded or
+/// it does not list the address.
+pub fn domain_count(ip: IpAddr) -> Option<usize> {
+    COUNTS.get()?.get(&ip).copied()
+}
+
+/// Reads a dataset with one entry per line, separated by whitespace or a comma: either
+/// `IP DOMAIN`, counted once per distinct domain, or `IP COUNT`, for datasets that are
+/// already aggregated. Blank lines, `#` comments and lines that do not parse are skipped.
+fn parse(reader: impl BufRead) -> io::Result<HashMap<IpAddr, usize>> {
+    let mut counts: HashMap<IpAddr, usize> = HashMap::new();
+    let mut domains: HashMap<IpAddr, HashSet<String>> = HashMap::new();
+    for line in reader.lines() {
+        let line = line?;
+        let mut fields = line.split(|c: char| c == ',' || c.is_whitespace()).filter(|field| !field.is_empty());
+        let (Some(ip), Some(value)) = (fields.next(), fields.next()) else { continue };
+        let Ok(ip) = ip.parse::<IpAddr>() else { continue };
+        match value.parse::<usize>() {
+            Ok(count) => *counts.entry(ip).or_default() += count,
+            Err(_) => {
+                domains.entry(ip).or_default().insert(value.trim_end_matches('.').to_ascii_lowercase());
+            }
+        }
+    }
+    for (ip, names) in domains {
+        *counts.entry(ip).or_default() += names.len();
+    }
+    Ok(counts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_distinct_domains_and_aggregated_entries() {
+        let dataset = "\
+# ip,domain
+192.0.2.1,a.example
+192.0.2.1 A.example.
+192.0.2.1\tb.example
+
+198.51.100.7,1200
+not-an-ip,c.example
+";
+        let counts = parse(dataset.as_bytes()).unwrap();
+        assert_eq!(counts.get(&"192.0.2.1".parse().unwrap()), Some(&2));
+        assert_eq!(counts.get(&"198.51.100.7".parse().unwrap()), Some(&1200));
+        assert_eq!(counts.len(), 2);
+    }
+}
@@ -0,0 +1,97 @@
+// src/core/scanner/correlation.rs
+
+use tracing::debug;
+use crate::core::models::{AnalysisFinding, ScanReport, Severity};
+use crate::core::scanner::dns_scanner::{caa_issuer_domains, caa_restricts_issuance};
+
+/// Maps a CA's CAA identifier domain to the issuer organization names it signs with.
+struct CaIdentity<'a> {
+    /// The domain used in CAA `issue` properties (e.g. "letsencrypt.org").
+    caa_domain: &'a str,
+    /// Substrings of the certificate issuer's organization (O=) that identify this CA.
+    issuer_organizations: &'a [&'a str],
+}
+
+/// The known Certificate Authorities. Issuers not listed here are never flagged,
+/// since we cannot tell which CAA domain they correspond to.
+static CA_IDENTITIES: &[CaIdentity] = &[
+    CaIdentity { caa_domain: "letsencrypt.org", issuer_organizations: &["Let's Encrypt"] },
+    CaIdentity { caa_domain: "pki.goog", issuer_organizations: &["Google Trust Services"] },
+    CaIdentity { caa_domain: "digicert.com", issuer_organizations: &["DigiCert"] },
+    CaIdentity { caa_domain: "sectigo.com", issuer_organizations: &["Sectigo", "COMODO"] },
+    CaIdentity { caa_domain: "comodoca.com", issuer_organizations: &["Sectigo", "COMODO"] },
+    CaIdentity { caa_domain: "globalsign.com", issuer_organizations: &["GlobalSign"] },
+    CaIdentity { caa_domain: "amazon.com", issuer_organizations: &["Amazon"] },
+    CaIdentity { caa_domain: "amazontrust.com", issuer_organizations: &["Amazon"] },
+    CaIdentity { caa_domain: "awstrust.com", issuer_organizations: &["Amazon"] },
+    CaIdentity { caa_domain: "godaddy.com", issuer_organizations: &["GoDaddy"] },
+    CaIdentity { caa_domain: "starfieldtech.com", issuer_organizations: &["Starfield"] },
+    CaIdentity { caa_domain: "zerossl.com", issuer_organizations: &["ZeroSSL"] },
+    CaIdentity { caa_domain: "buypass.com", issuer_organizations: &["Buypass"] },
+    CaIdentity { caa_domain: "entrust.net", issuer_organizations: &["Entrust"] },
+    CaIdentity { caa_domain: "ssl.com", issuer_organizations: &["SSL Corporation", "SSL.com"] },
+    CaIdentity { caa_domain: "identrust.com", issuer_organizations: &["IdenTrust"] },
+    CaIdentity { caa_domain: "certum.pl", issuer_organizations: &["Asseco", "Certum", "Unizeto"] },
+    CaIdentity { caa_domain: "actalis.it", issuer_organizations: &["Actalis"] },
+    CaIdentity { caa_domain: "harica.gr", issuer_organizations: &["HARICA", "Hellenic Academic"] },
+];
+
+/// Runs the analyses that need results from more than one scanner and appends
+/// their findings to the relevant scanner's analysis vector.
+///
+/// # Arguments
+/// * `report` - The report assembled from all individual scans.
+pub fn correlate_results(report: &mut ScanReport) {
+    if report.dns_results.domain_not_found {
+        drop_unreachable_findings(report);
+    }
+    if let Some(finding) = check_caa_compliance(report) {
+        report.ssl_results.analysis.push(finding);
+    }
+}
+
+/// Clears the findings of the scans that failed because the domain does not exist: they
+/// only repeat `DNS_DOMAIN_NOT_FOUND`.
+fn drop_unreachable_findings(report: &mut ScanReport) {
+    if report.ssl_results.scan.is_err() {
+        report.ssl_results.analysis.clear();
+    }
+    if report.headers_results.error.is_some() {
+        report.headers_results.analysis.clear();
+        report.fingerprint_results.analysis.clear();
+        report.redirect_results.analysis.clear();
+    }
+    debug!("Domain does not exist, dropped the findings of the failed scans.");
+}
+
+/// Compares the served certificate's issuer against the CAs authorized by the domain's CAA records.
+///
+/// Emits `SSL_CAA_VIOLATION` only when issuance is restricted and the issuer is a known CA
+/// whose CAA domain is not among the authorized ones. A wildcard certificate is checked
+/// against `issuewild` when the records have it, and any other against `issue`.
+fn check_caa_compliance(report: &ScanReport) -> Option<AnalysisFinding> {
+    let Ok(Some(caa_records)) = &report.dns_results.caa else { return None; };
+    let Ok(Some(ssl_data)) = &report.ssl_results.scan else { return None; };
+    if !caa_restricts_issuance(caa_records, ssl_data.is_wildcard) {
+        return None;
+    }
+
+    let issuer = ssl_data.certificate_info.issuer_organization.as_deref()?;
+    let issuer_domains: Vec<&str> = CA_IDENTITIES.iter()
+        .filter(|ca| ca.issuer_organizations.iter().any(|org| issuer.contains(org)))
+        .map(|ca| ca.caa_domain)
+        .collect();
+    if issuer_domains.is_empty() {
+        debug!(issuer, "Certificate issuer is not a known CA, skipping CAA correlation.");
+        return None;
+    }
+
+    let authorized = caa_issuer_domains(caa_records, ssl_data.is_wildcard);
+    if issuer_domains.iter().any(|domain| authorized.iter().any(|a| a == domain)) {
+        debug!(issuer, "Certificate issuer is authorized by CAA.");
+        return None;
+    }
+
+    debug!(issuer, authorized = ?authorized, "Certificate issuer is not authorized by CAA, adding Warning.");
+    Some(AnalysisFinding::new(Severity::Warning, "SSL_CAA_VIOLATION"))
+}
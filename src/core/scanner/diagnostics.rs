@@ -0,0 +1,101 @@
+// src/core/scanner/diagnostics.rs
+
+//! Collects the non-fatal problems the scanners ran into, such as a certificate that could
+//! not be parsed or a header that is not valid UTF-8.
+//!
+//! These describe the scan, not the site: the affected data is missing or incomplete, so
+//! the findings built from it may be too. Keeping them apart from the findings tells
+//! "the scan had trouble" from "the site is insecure".
+
+use crate::core::models::ScanReport;
+use crate::core::scanner::headers_scanner::{checked_headers, has_invalid_utf8};
+use crate::core::scanner::ssl_scanner::HANDSHAKE_ERROR_PREFIX;
+
+/// Returns one message per problem, each prefixed with the scanner it comes from.
+///
+/// Failures that are findings themselves (a failed TLS handshake, an unreachable site)
+/// are not repeated here.
+pub fn collect_warnings(report: &ScanReport) -> Vec<String> {
+    let mut warnings = Vec::new();
+
+    // A refused host may be why an HTTP-based scanner failed, so these come first.
+    for host in &report.blocked_hosts {
+        warnings.push(format!("Offline mode: refused to connect to {}, which is not the target", host));
+    }
+
+    // A handshake failure is the SSL_HANDSHAKE_FAILED finding; anything else went wrong after
+    // connecting, e.g. the certificate could not be parsed.
+    if let Err(e) = &report.ssl_results.scan
+        && !e.starts_with(HANDSHAKE_ERROR_PREFIX)
+    {
+        warnings.push(format!("SSL/TLS: {}", e));
+    }
+
+    // An unreachable site is the HEADERS_REQUEST_FAILED finding, and the other HTTP-based
+    // scanners fail the same way, so only their problems with a reachable site are collected.
+    if report.headers_results.error.is_some() {
+        return warnings;
+    }
+
+    for (name, header) in checked_headers(&report.headers_results) {
+        if let Ok(Some(data)) = header
+            && has_invalid_utf8(data)
+        {
+            warnings.push(format!("Headers: the {} header is not valid UTF-8, so its value was not checked", name));
+        }
+    }
+
+    if let Err(e) = &report.fingerprint_results.technologies {
+        warnings.push(format!("Technologies: {}", e));
+    }
+
+    // Both redirect results carry the same error when the client could not be built.
+    let redirects = &report.redirect_results;
+    for error in [redirects.chain.as_ref().err(), redirects.scan.as_ref().err()].into_iter().flatten() {
+        let warning = format!("Redirects: {}", error);
+        if !warnings.contains(&warning) {
+            warnings.push(warning);
+        }
+    }
+    if redirects.scan.is_ok() && !redirects.failed_probes.is_empty() {
+        warnings.push(format!("Redirects: {} open redirect probe(s) failed, so their parameters were not tested", redirects.failed_probes.len()));
+    }
+
+    warnings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::models::HeaderData;
+    use crate::core::scanner::headers_scanner::{DEFAULT_COOKIE_MAX_LIFETIME_DAYS, INVALID_UTF8_PLACEHOLDER, scan_headers};
+    use crate::core::scanner::http_client_builder;
+    use crate::core::scanner::test_support::{MockHttpServer, MockResponse, codes};
+    use crate::core::scanner::throttle::Throttle;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn reports_a_header_value_that_is_not_utf8() {
+        // reqwest only reads visible ASCII as text, so the encoded "É" makes the value unreadable.
+        let server = MockHttpServer::start(MockResponse::html("<html></html>").with_header("X-Frame-Options", "DÉNY")).await;
+        let client = http_client_builder(false).build().unwrap();
+        let headers_results = scan_headers(&client, &server.page, &[], false, false, DEFAULT_COOKIE_MAX_LIFETIME_DAYS, &Throttle::new(Duration::ZERO)).await;
+        // The placeholder is not checked as a value, so it is not reported as an invalid one.
+        assert!(!codes(&headers_results.analysis).contains(&"HEADERS_XFO_INVALID"));
+        let report = ScanReport { headers_results, ..Default::default() };
+        assert_eq!(collect_warnings(&report), ["Headers: the X-Frame-Options header is not valid UTF-8, so its value was not checked"]);
+    }
+
+    #[test]
+    fn leaves_out_failures_already_reported_as_findings() {
+        let mut report = ScanReport::default();
+        report.ssl_results.scan = Err(format!("{}: connection reset", HANDSHAKE_ERROR_PREFIX));
+        report.headers_results.error = Some("HTTP request failed: timed out".to_string());
+        report.headers_results.csp = Ok(Some(HeaderData { value: INVALID_UTF8_PLACEHOLDER.to_string(), all_values: Vec::new() }));
+        report.fingerprint_results.technologies = Err("HTTP request failed: timed out".to_string());
+        assert!(collect_warnings(&report).is_empty());
+
+        report.ssl_results.scan = Err("Failed to parse certificate".to_string());
+        assert_eq!(collect_warnings(&report), ["SSL/TLS: Failed to parse certificate"]);
+    }
+}
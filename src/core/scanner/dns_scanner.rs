@@ -1,29 +1,125 @@
 // src/core/scanner/dns_scanner.rs
 
+use std::net::IpAddr;
 use tracing::{debug, info, warn};
 
+use crate::core::geoip;
+use crate::core::reverse_ip;
 use crate::core::models::{
-    AnalysisFinding, DmarcData, DnsResults, Severity, SpfData, DkimRecord, ScanResult,
+    AnalysisFinding, DmarcData, DnsResults, DohProvider, HostingInfo, MxRecord, Severity, SpfData, SpfMechanism, SpfParsed, DkimRecord, ScanResult,
 };
-use hickory_resolver::config::{ResolverConfig, ResolverOpts};
+use crate::core::scanner::{ScanOptions, ScanSection};
+use crate::core::scanner::domain;
+use crate::core::scanner::pipeline::{ScanContext, Scanner};
+use futures::future::{BoxFuture, join_all};
+use hickory_resolver::config::{NameServerConfigGroup, ResolverConfig};
+use hickory_resolver::error::{ResolveError, ResolveErrorKind};
+use hickory_resolver::proto::op::ResponseCode;
 use hickory_resolver::proto::rr::RecordType;
+use hickory_resolver::proto::rr::rdata::TXT;
 use hickory_resolver::TokioAsyncResolver;
 
-/// A list of common DKIM selectors to check for when a specific one is not known.
-const COMMON_DKIM_SELECTORS: &[&str] = &["google", "selector1", "selector2", "default", "dkim"];
+/// A well-known DKIM selector and the email provider that publishes it.
+struct DkimSelector<'a> {
+    /// The selector label queried at `<selector>._domainkey.<domain>`.
+    selector: &'a str,
+    /// The provider this selector implies, or `None` for generic selectors used by many setups.
+    provider: Option<&'a str>,
+}
+
+/// The master list of DKIM selectors to check for when a specific one is not known,
+/// grouped by the email provider that uses them.
+static DKIM_SELECTORS: &[DkimSelector] = &[
+    // Google Workspace
+    DkimSelector { selector: "google", provider: Some("Google Workspace") },
+    // Microsoft 365
+    DkimSelector { selector: "selector1", provider: Some("Microsoft 365") },
+    DkimSelector { selector: "selector2", provider: Some("Microsoft 365") },
+    // Mailchimp / Mandrill
+    DkimSelector { selector: "k1", provider: Some("Mailchimp") },
+    DkimSelector { selector: "k2", provider: Some("Mailchimp") },
+    DkimSelector { selector: "k3", provider: Some("Mailchimp") },
+    DkimSelector { selector: "mandrill", provider: Some("Mailchimp") },
+    // SendGrid
+    DkimSelector { selector: "s1", provider: Some("SendGrid") },
+    DkimSelector { selector: "s2", provider: Some("SendGrid") },
+    DkimSelector { selector: "smtpapi", provider: Some("SendGrid") },
+    // Amazon SES
+    DkimSelector { selector: "amazonses", provider: Some("Amazon SES") },
+    // Mailgun
+    DkimSelector { selector: "mailo", provider: Some("Mailgun") },
+    DkimSelector { selector: "krs", provider: Some("Mailgun") },
+    // Postmark
+    DkimSelector { selector: "pm", provider: Some("Postmark") },
+    // Zoho Mail
+    DkimSelector { selector: "zoho", provider: Some("Zoho Mail") },
+    DkimSelector { selector: "zmail", provider: Some("Zoho Mail") },
+    // Fastmail
+    DkimSelector { selector: "fm1", provider: Some("Fastmail") },
+    DkimSelector { selector: "fm2", provider: Some("Fastmail") },
+    DkimSelector { selector: "fm3", provider: Some("Fastmail") },
+    // Proton Mail
+    DkimSelector { selector: "protonmail", provider: Some("Proton Mail") },
+    DkimSelector { selector: "protonmail2", provider: Some("Proton Mail") },
+    DkimSelector { selector: "protonmail3", provider: Some("Proton Mail") },
+    // HubSpot
+    DkimSelector { selector: "hs1", provider: Some("HubSpot") },
+    DkimSelector { selector: "hs2", provider: Some("HubSpot") },
+    // Mailjet
+    DkimSelector { selector: "mailjet", provider: Some("Mailjet") },
+    // Generic selectors
+    DkimSelector { selector: "default", provider: None },
+    DkimSelector { selector: "dkim", provider: None },
+    DkimSelector { selector: "mail", provider: None },
+];
+
+/// The DMARC tags receivers understand (RFC 7489, plus `np`, `psd` and `t` from DMARCbis).
+const DMARC_TAGS: &[&str] = &["v", "p", "sp", "np", "pct", "ri", "adkim", "aspf", "fo", "rf", "rua", "ruf", "psd", "t"];
+
+/// The values the policy tags (`p`, `sp`, `np`) accept.
+const DMARC_POLICIES: &[&str] = &["none", "quarantine", "reject"];
+
+/// How many domains on the host's address make it shared hosting worth a finding.
+const SHARED_HOSTING_THRESHOLD: usize = 50;
 
 /// Runs a comprehensive DNS security scan against the specified target domain.
 ///
-/// This function performs parallel lookups for SPF, DMARC, DKIM, and CAA records.
+/// This function performs parallel lookups for SPF, DMARC, DKIM, and CAA records, and
+/// resolves the host itself to report where it is served from.
 /// After gathering the raw DNS data, it proceeds to analyze the results to identify
 /// potential security misconfigurations or areas for improvement.
 ///
-/// # Arguments
-/// * `target` - The domain name to be scanned.
+/// The lookups go through the context's resolver, which `ScanContext` builds from the
+/// `dns_over_https` and `dns_nameservers` options.
+pub struct DnsScanner;
+
+impl Scanner for DnsScanner {
+    fn name(&self) -> &'static str {
+        "DNS"
+    }
+
+    fn scan<'a>(&'a self, ctx: &'a ScanContext) -> BoxFuture<'a, ScanSection> {
+        Box::pin(async move {
+            if !self.enabled(&ctx.options) {
+                return ScanSection::Dns(DnsResults { disabled: true, ..Default::default() });
+            }
+            ScanSection::Dns(scan_dns(&ctx.resolver, ctx.resolver_name.clone(), &ctx.target).await)
+        })
+    }
+
+    fn enabled(&self, options: &ScanOptions) -> bool {
+        options.scanners.scan_dns
+    }
+
+    fn planned_requests(&self, target: &str, _options: &ScanOptions) -> Vec<String> {
+        vec![format!("DNS lookups for {}: its addresses, and the SPF, DMARC, DKIM, CAA and MX records of its domain", target)]
+    }
+}
+
+/// Runs the DNS scan with the given resolver, recorded in the report as `resolver_name`.
 ///
-/// # Returns
-/// A `DnsResults` struct containing both the raw lookup data and the analysis findings.
-pub async fn run_dns_scan(target: &str) -> DnsResults {
+/// `DnsScanner` passes the context's resolver; tests pass one that queries a local server.
+pub(crate) async fn scan_dns(resolver: &TokioAsyncResolver, resolver_name: String, target: &str) -> DnsResults {
     // Strip "www." prefix to query the root domain, which is standard for these record types.
     let root_target = if let Some(stripped) = target.strip_prefix("www.") {
         stripped
@@ -33,30 +129,45 @@ pub async fn run_dns_scan(target: &str) -> DnsResults {
 
     info!(target = %root_target, "Starting DNS scan.");
 
-    // Initialize a Tokio-based asynchronous DNS resolver.
-    let resolver =
-        TokioAsyncResolver::tokio(ResolverConfig::default(), ResolverOpts::default());
+    // A domain that does not exist (most likely a typo) has no records at all, so every
+    // lookup below would only report another missing record.
+    if domain_not_found(resolver, root_target).await {
+        warn!(target = %root_target, "Domain does not exist (NXDOMAIN), skipping the record lookups.");
+        return DnsResults {
+            resolver: resolver_name,
+            domain_not_found: true,
+            analysis: vec![AnalysisFinding::new(Severity::Critical, "DNS_DOMAIN_NOT_FOUND").with_subject(root_target)],
+            ..Default::default()
+        };
+    }
 
     // Execute all DNS lookups concurrently for better performance.
-    let (spf_result, dmarc_result, dkim_result, caa_result) = tokio::join!(
-        lookup_spf(&resolver, root_target),
-        lookup_dmarc(&resolver, root_target),
-        lookup_dkim(&resolver, root_target),
-        lookup_caa(&resolver, root_target)
+    let (spf_result, dmarc_result, dkim_result, caa_result, mx_result, hosting) = tokio::join!(
+        lookup_spf(resolver, root_target),
+        lookup_dmarc(resolver, root_target),
+        lookup_dkim(resolver, root_target),
+        lookup_caa(resolver, target),
+        lookup_mx(resolver, root_target),
+        lookup_hosting(resolver, target)
     );
 
     debug!("All DNS lookups completed, starting analysis.");
 
     let mut results = DnsResults {
+        resolver: resolver_name,
         spf: spf_result,
         dmarc: dmarc_result,
         dkim: dkim_result,
         caa: caa_result,
+        mx: mx_result,
+        hosting,
         analysis: Vec::new(),
+        domain_not_found: false,
+        disabled: false,
     };
 
     // Analyze the collected data to generate security findings.
-    results.analysis = analyze_dns_results(&results);
+    results.analysis = analyze_dns_results(&results, root_target);
     info!(findings = %results.analysis.len(), "DNS scan finished.");
     results
 }
@@ -65,21 +176,27 @@ pub async fn run_dns_scan(target: &str) -> DnsResults {
 ///
 /// # Arguments
 /// * `results` - A reference to the `DnsResults` containing the data to analyze.
+/// * `domain` - The domain the mail records were looked up for (e.g. "example.com").
 ///
 /// # Returns
 /// A vector of `AnalysisFinding` structs detailing any issues found.
-fn analyze_dns_results(results: &DnsResults) -> Vec<AnalysisFinding> {
+fn analyze_dns_results(results: &DnsResults, domain: &str) -> Vec<AnalysisFinding> {
     let mut analyses = Vec::new();
 
     // Analyze DMARC record.
     match &results.dmarc {
+        // Receivers ignore a record with syntax errors, so its policy is not worth analyzing.
+        Ok(Some(dmarc)) if !dmarc.errors.is_empty() => {
+            debug!(errors = ?dmarc.errors, "DMARC analysis: Malformed record, adding Warning.");
+            analyses.push(AnalysisFinding::new(Severity::Warning, "DNS_DMARC_MALFORMED").with_subject(&dmarc.errors.join("; ")));
+        }
         Ok(Some(dmarc)) => {
             // A DMARC policy of "none" offers no protection and should be flagged.
-            if let Some(policy) = &dmarc.policy {
-                if policy == "none" {
-                    debug!("DMARC analysis: Found policy 'none', adding Warning.");
-                    analyses.push(AnalysisFinding::new(Severity::Warning, "DNS_DMARC_POLICY_NONE"));
-                }
+            if let Some(policy) = &dmarc.policy
+                && policy == "none"
+            {
+                debug!("DMARC analysis: Found policy 'none', adding Warning.");
+                analyses.push(AnalysisFinding::new(Severity::Warning, "DNS_DMARC_POLICY_NONE"));
             }
         }
         // A missing DMARC record is a critical security gap.
@@ -101,6 +218,13 @@ fn analyze_dns_results(results: &DnsResults) -> Vec<AnalysisFinding> {
                 debug!("SPF analysis: Found neutral policy '?all', adding Info finding.");
                 analyses.push(AnalysisFinding::new(Severity::Info, "DNS_SPF_POLICY_NEUTRAL"));
             }
+            if let Ok(Some(mx)) = &results.mx {
+                let uncovered = spf_uncovered_mx_hosts(&spf.parsed, mx, domain);
+                if !uncovered.is_empty() {
+                    debug!(hosts = ?uncovered, "SPF analysis: The domain's own mail servers are not authorized, adding Warning.");
+                    analyses.push(AnalysisFinding::new(Severity::Warning, "DNS_SPF_MX_NOT_COVERED").with_subject(&uncovered.join(", ")));
+                }
+            }
         }
         // A missing SPF record is a notable weakness.
         Ok(None) => {
@@ -111,9 +235,26 @@ fn analyze_dns_results(results: &DnsResults) -> Vec<AnalysisFinding> {
     }
 
     // Check for DKIM records.
-    if let Ok(None) = &results.dkim {
-        debug!("DKIM analysis: No records found, adding Info finding.");
-        analyses.push(AnalysisFinding::new(Severity::Info, "DNS_DKIM_MISSING"));
+    match &results.dkim {
+        Ok(None) => {
+            debug!("DKIM analysis: No records found, adding Info finding.");
+            analyses.push(AnalysisFinding::new(Severity::Info, "DNS_DKIM_MISSING"));
+        }
+        // A vendor-specific selector reveals which provider sends mail for the domain.
+        Ok(Some(records)) if records.iter().any(|r| r.provider.is_some()) => {
+            debug!("DKIM analysis: Selector implies a known email provider, adding Info finding.");
+            analyses.push(AnalysisFinding::new(Severity::Info, "DNS_DKIM_PROVIDER_DETECTED"));
+        }
+        _ => {}
+    }
+
+    // An enforcing DMARC policy needs SPF or DKIM to pass; with neither published, legitimate mail fails it too.
+    if let (Ok(Some(dmarc)), Ok(None), Ok(None)) = (&results.dmarc, &results.spf, &results.dkim)
+        && dmarc.errors.is_empty()
+        && matches!(dmarc.policy.as_deref(), Some("quarantine" | "reject"))
+    {
+        debug!("DMARC analysis: Enforcing policy without SPF or DKIM, adding Warning.");
+        analyses.push(AnalysisFinding::new(Severity::Warning, "DNS_DMARC_UNENFORCEABLE"));
     }
 
     // Check for CAA records.
@@ -121,10 +262,206 @@ fn analyze_dns_results(results: &DnsResults) -> Vec<AnalysisFinding> {
         debug!("CAA analysis: No records found, adding Info finding.");
         analyses.push(AnalysisFinding::new(Severity::Info, "DNS_CAA_MISSING"));
     }
+
+    // Many domains on one address means shared hosting, whose neighbours share its reputation.
+    if let Some(hosting) = &results.hosting
+        && let Some(count) = hosting.shared_domains
+        && count >= SHARED_HOSTING_THRESHOLD
+    {
+        debug!(ip = %hosting.ip, count, "Shared hosting analysis: Many domains on the IP, adding Info finding.");
+        analyses.push(AnalysisFinding::new(Severity::Info, "DNS_SHARED_HOSTING").with_subject(&format!("{} domains on {}", count, hosting.ip)));
+    }
+
+    // A lookup the resolver could not answer says nothing about the record, so it is reported
+    // once here instead of as a missing record.
+    let failed: Vec<&str> = [
+        ("SPF", results.spf.is_err()),
+        ("DMARC", results.dmarc.is_err()),
+        ("DKIM", results.dkim.is_err()),
+        ("CAA", results.caa.is_err()),
+    ]
+        .into_iter()
+        .filter_map(|(record, failed)| failed.then_some(record))
+        .collect();
+    if !failed.is_empty() {
+        debug!(records = ?failed, "Lookups failed, adding DNS_RESOLUTION_ERROR finding.");
+        analyses.push(AnalysisFinding::new(Severity::Warning, "DNS_RESOLUTION_ERROR").with_subject(&failed.join(", ")));
+    }
     
     analyses
 }
 
+/// Returns the full value of a TXT record.
+///
+/// Records longer than 255 bytes are published as several character-strings
+/// (`"v=spf1 ... " "include:... ~all"`). They form a single value and are joined
+/// back-to-back, without quotes or separators, before any parsing.
+fn txt_record_value(record: &TXT) -> String {
+    record.txt_data().iter()
+        .map(|part| String::from_utf8_lossy(part))
+        .collect()
+}
+
+/// Splits an SPF record into its version, mechanisms and modifiers.
+///
+/// Parsing is purely lexical: includes and redirects are listed, not resolved.
+/// E.g. "v=spf1 ip4:192.0.2.0/24 include:_spf.example.net ~all" yields a pass `ip4`
+/// mechanism with value "192.0.2.0/24", a pass `include`, and a softfail `all`.
+pub fn parse_spf(record: &str) -> SpfParsed {
+    let mut parsed = SpfParsed::default();
+    for term in record.split_whitespace() {
+        if let Some(version) = term.strip_prefix("v=") {
+            parsed.version = version.to_string();
+            continue;
+        }
+        // Modifiers are "name=value" with a plain name; mechanism arguments never contain '=' before ':' or '/'.
+        if let Some((name, _)) = term.split_once('=')
+            && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.')
+        {
+            parsed.modifiers.push(term.to_string());
+            continue;
+        }
+
+        let (qualifier, mechanism) = match term.chars().next() {
+            Some(q @ ('+' | '-' | '~' | '?')) => (q, &term[1..]),
+            _ => ('+', term),
+        };
+        // The argument follows ':' (domain or network) or starts at '/' (a bare CIDR length).
+        let (kind, value) = match mechanism.find([':', '/']) {
+            Some(i) if mechanism[i..].starts_with(':') => (&mechanism[..i], Some(mechanism[i + 1..].to_string())),
+            Some(i) => (&mechanism[..i], Some(mechanism[i..].to_string())),
+            None => (mechanism, None),
+        };
+        parsed.mechanisms.push(SpfMechanism { qualifier, kind: kind.to_ascii_lowercase(), value });
+    }
+    parsed
+}
+
+/// Splits a DMARC record into its tags and validates them.
+///
+/// A valid record starts with `v=DMARC1`, has a `p` policy of none/quarantine/reject, uses
+/// each tag once, and gives them well-formed values (e.g. `pct` from 0 to 100).
+/// E.g. "v=DMARC1; p=reject; pct=150" yields three tags and the error "invalid pct value '150'".
+/// As receivers do (RFC 7489 §6.3 and §6.6.3), unknown tags are ignored, and a record without
+/// `p` but with a valid `rua` is not an error: it is read as `p=none`, see `dmarc_policy`.
+///
+/// # Returns
+/// The tags in order, and a description of every violation (empty for a valid record).
+pub fn parse_dmarc(record: &str) -> (Vec<(String, String)>, Vec<String>) {
+    let mut tags: Vec<(String, String)> = Vec::new();
+    let mut errors = Vec::new();
+    for part in record.split(';').map(str::trim).filter(|part| !part.is_empty()) {
+        match part.split_once('=') {
+            Some((name, value)) => tags.push((name.trim().to_ascii_lowercase(), value.trim().to_string())),
+            None => errors.push(format!("'{}' is not a tag=value pair", part)),
+        }
+    }
+
+    match tags.first() {
+        Some((name, value)) if name == "v" && value == "DMARC1" => {}
+        _ => errors.push("the record does not start with 'v=DMARC1'".to_string()),
+    }
+    let has_report_uri = tags.iter().any(|(name, value)| name == "rua" && valid_report_uris(value));
+    if !tags.iter().any(|(name, _)| name == "p") && !has_report_uri {
+        errors.push("the required 'p' tag is missing".to_string());
+    }
+
+    let is_uint = |value: &str| value.chars().all(|c| c.is_ascii_digit()) && value.parse::<u32>().is_ok();
+    for (index, (name, value)) in tags.iter().enumerate() {
+        if !DMARC_TAGS.contains(&name.as_str()) {
+            continue;
+        }
+        if tags[..index].iter().any(|(earlier, _)| earlier == name) {
+            errors.push(format!("the '{}' tag appears more than once", name));
+            continue;
+        }
+        let valid = match name.as_str() {
+            "p" | "sp" | "np" => DMARC_POLICIES.contains(&value.to_ascii_lowercase().as_str()),
+            "pct" => is_uint(value) && value.parse::<u32>().is_ok_and(|pct| pct <= 100),
+            "ri" => is_uint(value),
+            "adkim" | "aspf" => matches!(value.to_ascii_lowercase().as_str(), "r" | "s"),
+            "fo" => value.split(':').all(|option| matches!(option.trim(), "0" | "1" | "d" | "s")),
+            "rf" => value.split(':').all(|format| format.trim().eq_ignore_ascii_case("afrf")),
+            "rua" | "ruf" => valid_report_uris(value),
+            "psd" => matches!(value.to_ascii_lowercase().as_str(), "y" | "n" | "u"),
+            "t" => matches!(value.to_ascii_lowercase().as_str(), "y" | "n"),
+            // `v` is checked above, as it must come first.
+            _ => true,
+        };
+        if !valid {
+            errors.push(format!("invalid {} value '{}'", name, value));
+        }
+    }
+    (tags, errors)
+}
+
+/// Whether a `rua`/`ruf` value is a list of report destinations: comma-separated URIs, normally "mailto:".
+fn valid_report_uris(value: &str) -> bool {
+    value.split(',').all(|uri| uri.trim().split_once(':').is_some_and(|(scheme, rest)| !scheme.is_empty() && !rest.is_empty()))
+}
+
+/// The policy receivers apply for a record parsed by `parse_dmarc`: its `p` tag, or "none" for a
+/// valid record without one, which receivers then only send reports for (RFC 7489 §6.6.3).
+pub fn dmarc_policy(tags: &[(String, String)], errors: &[String]) -> Option<String> {
+    tags.iter().find(|(name, _)| name == "p").map(|(_, value)| value.clone())
+        .or_else(|| errors.is_empty().then(|| "none".to_string()))
+}
+
+/// Picks the upstream for the lookups and names it for the report.
+/// Errors from a DNS-over-HTTPS upstream are the same `ResolveError`s, so they map to the same messages.
+///
+/// In `offline` mode, the public resolvers are never used: without explicit nameservers, the
+/// system's own are, as for every other name the machine resolves.
+pub(crate) fn resolver_config(dns_over_https: Option<DohProvider>, nameservers: &[IpAddr], offline: bool) -> (ResolverConfig, String) {
+    if !nameservers.is_empty() {
+        let group = NameServerConfigGroup::from_ips_clear(nameservers, 53, true);
+        let names: Vec<String> = nameservers.iter().map(IpAddr::to_string).collect();
+        return (ResolverConfig::from_parts(None, Vec::new(), group), format!("Custom nameservers ({})", names.join(", ")));
+    }
+    if offline {
+        if dns_over_https.is_some() {
+            warn!("DNS-over-HTTPS goes to a public service, ignoring it in offline mode.");
+        }
+        return match hickory_resolver::system_conf::read_system_conf() {
+            Ok((config, _)) => (config, "System resolver".to_string()),
+            Err(e) => {
+                // Lookups fail rather than fall back to a public resolver.
+                warn!(error = %e, "Could not read the system's DNS configuration; DNS lookups will fail in offline mode.");
+                (ResolverConfig::from_parts(None, Vec::new(), NameServerConfigGroup::new()), "System resolver (unavailable)".to_string())
+            }
+        };
+    }
+    match dns_over_https {
+        Some(DohProvider::Cloudflare) => (ResolverConfig::cloudflare_https(), "DNS-over-HTTPS (Cloudflare)".to_string()),
+        Some(DohProvider::Google) => (ResolverConfig::google_https(), "DNS-over-HTTPS (Google)".to_string()),
+        // hickory's default configuration queries Google Public DNS over plain UDP/TCP.
+        None => (ResolverConfig::default(), "Google Public DNS".to_string()),
+    }
+}
+
+/// Returns why a lookup failed, or `None` when it only means the record is not published:
+/// the name has no record of that type, or does not exist at all (NXDOMAIN).
+///
+/// Anything else (SERVFAIL, a refused query, a timeout) is the resolver failing to answer,
+/// e.g. because of broken DNSSEC or unreachable nameservers.
+fn resolution_error(e: &ResolveError) -> Option<String> {
+    match e.kind() {
+        ResolveErrorKind::NoRecordsFound { response_code: ResponseCode::NoError | ResponseCode::NXDomain, .. } => None,
+        ResolveErrorKind::NoRecordsFound { response_code, .. } => Some(format!("DNS resolution error: the resolver answered {}", response_code)),
+        ResolveErrorKind::Timeout => Some("DNS resolution error: the query timed out".to_string()),
+        _ => Some(format!("DNS resolution error: {}", e)),
+    }
+}
+
+/// Returns whether the resolver says the domain does not exist (NXDOMAIN). Any other answer,
+/// including a failure, lets the scan go on.
+async fn domain_not_found(resolver: &TokioAsyncResolver, target: &str) -> bool {
+    match resolver.soa_lookup(target).await {
+        Err(e) => matches!(e.kind(), ResolveErrorKind::NoRecordsFound { response_code: ResponseCode::NXDomain, .. }),
+        Ok(_) => false,
+    }
+}
+
 /// Looks up the SPF (Sender Policy Framework) record for a domain.
 /// SPF records are stored in TXT records and start with "v=spf1".
 async fn lookup_spf(resolver: &TokioAsyncResolver, target: &str) -> ScanResult<SpfData> {
@@ -132,19 +469,26 @@ async fn lookup_spf(resolver: &TokioAsyncResolver, target: &str) -> ScanResult<S
     match resolver.txt_lookup(target).await {
         Ok(txt_records) => {
             for record in txt_records.iter() {
-                let record_str = record.to_string();
+                let record_str = txt_record_value(record);
                 if record_str.starts_with("v=spf1") {
                     debug!(record = %record_str, "SPF record found.");
-                    return Ok(Some(SpfData { record: record_str }));
+                    let parsed = parse_spf(&record_str);
+                    return Ok(Some(SpfData { record: record_str, parsed }));
                 }
             }
             debug!(target, "No SPF record found among TXT records.");
             Ok(None)
         },
-        Err(e) => {
-            warn!(target, error = %e, "SPF lookup failed.");
-            Err(format!("DNS Error: {}", e))
-        }
+        Err(e) => match resolution_error(&e) {
+            None => {
+                debug!(target, "No TXT records found.");
+                Ok(None)
+            }
+            Some(error) => {
+                warn!(target, error = %e, "SPF lookup failed.");
+                Err(error)
+            }
+        },
     }
 }
 
@@ -156,58 +500,47 @@ async fn lookup_dmarc(resolver: &TokioAsyncResolver, target: &str) -> ScanResult
     match resolver.txt_lookup(&dmarc_target).await {
         Ok(txt_records) => {
             if let Some(record) = txt_records.iter().next() {
-                let record_str = record.to_string();
+                let record_str = txt_record_value(record);
                 debug!(record = %record_str, "DMARC record found.");
-                // Parse the policy (p=) tag from the record.
-                let policy = record_str.split(';')
-                    .find(|s| s.trim().starts_with("p="))
-                    .and_then(|s| s.trim().split('=').nth(1))
-                    .map(|s| s.to_string());
-                
-                return Ok(Some(DmarcData { record: record_str, policy }));
+                let (tags, errors) = parse_dmarc(&record_str);
+                if !errors.is_empty() {
+                    debug!(errors = ?errors, "DMARC record is malformed.");
+                }
+                let policy = dmarc_policy(&tags, &errors);
+                return Ok(Some(DmarcData { record: record_str, policy, tags, errors }));
             }
             debug!(target = %dmarc_target, "No DMARC record found.");
             Ok(None)
         },
-        Err(e) => {
-            warn!(target = %dmarc_target, error = %e, "DMARC lookup failed.");
-            Err(format!("DNS Error: {}", e))
-        }
+        Err(e) => match resolution_error(&e) {
+            None => {
+                debug!(target = %dmarc_target, "No DMARC record found.");
+                Ok(None)
+            }
+            Some(error) => {
+                warn!(target = %dmarc_target, error = %e, "DMARC lookup failed.");
+                Err(error)
+            }
+        },
     }
 }
 
-/// Looks up DKIM records for a domain using a list of common selectors.
+/// Looks up DKIM records for a domain using the list of well-known selectors.
 /// DKIM records are stored in TXT records at `selector._domainkey.domain`.
+///
+/// All selectors are queried concurrently, so the total time is bounded by the
+/// slowest lookup rather than the sum of all of them.
 async fn lookup_dkim(resolver: &TokioAsyncResolver, target: &str) -> ScanResult<Vec<DkimRecord>> {
-    debug!(target, "Looking up DKIM records for common selectors.");
-    let mut found_records = Vec::new();
-    // Iterate through a predefined list of common selectors.
-    for selector in COMMON_DKIM_SELECTORS {
-        let dkim_target = format!("{selector}._domainkey.{target}");
-        debug!(selector, "Checking for DKIM record.");
-
-        match resolver.txt_lookup(&dkim_target).await {
-            Ok(txt_records) => {
-                for record in txt_records.iter() {
-                    let record_str = record.to_string();
-                    // A valid DKIM record must start with "v=DKIM1".
-                    if record_str.starts_with("v=DKIM1") {
-                        debug!(selector, "Found valid DKIM record.");
-                        found_records.push(DkimRecord {
-                            selector: selector.to_string(),
-                            record: record_str,
-                        });
-                    }
-                }
-            },
-            Err(e) => {
-                // It's common for some selectors not to exist, so this is a warning.
-                warn!(selector, target = %dkim_target, error = %e, "DKIM lookup for this selector failed.");
-            }
-        }
-    }
+    debug!(target, selectors = DKIM_SELECTORS.len(), "Looking up DKIM records for common selectors.");
+    let lookups = DKIM_SELECTORS.iter().map(|selector| lookup_dkim_selector(resolver, target, selector));
+    let results = join_all(lookups).await;
+    let found_records: Vec<DkimRecord> = results.iter().filter_map(|result| result.as_ref().ok()).flatten().cloned().collect();
 
     if found_records.is_empty() {
+        // Without an answer, a selector may still be published.
+        if let Some(Err(error)) = results.into_iter().find(Result::is_err) {
+            return Err(error);
+        }
         debug!(target, "No DKIM records found for any common selector.");
         Ok(None)
     } else {
@@ -216,8 +549,118 @@ async fn lookup_dkim(resolver: &TokioAsyncResolver, target: &str) -> ScanResult<
     }
 }
 
-/// Looks up CAA (Certification Authority Authorization) records for a domain.
+/// Looks up the DKIM records published under a single selector.
+/// A selector that does not exist has no records; only resolver failures are errors.
+async fn lookup_dkim_selector(resolver: &TokioAsyncResolver, target: &str, dkim_selector: &DkimSelector<'_>) -> Result<Vec<DkimRecord>, String> {
+    let DkimSelector { selector, provider } = dkim_selector;
+    let dkim_target = format!("{selector}._domainkey.{target}");
+    debug!(selector, "Checking for DKIM record.");
+
+    match resolver.txt_lookup(&dkim_target).await {
+        Ok(txt_records) => Ok(txt_records.iter()
+            .map(txt_record_value)
+            // A valid DKIM record must start with "v=DKIM1".
+            .filter(|record_str| record_str.starts_with("v=DKIM1"))
+            .map(|record_str| {
+                debug!(selector, "Found valid DKIM record.");
+                DkimRecord {
+                    selector: selector.to_string(),
+                    record: record_str,
+                    provider: provider.map(String::from),
+                }
+            })
+            .collect()),
+        Err(e) => match resolution_error(&e) {
+            // Most selectors do not exist, which is not worth a warning.
+            None => {
+                debug!(selector, "No DKIM record for this selector.");
+                Ok(Vec::new())
+            }
+            Some(error) => {
+                warn!(selector, target = %dkim_target, error = %e, "DKIM lookup for this selector failed.");
+                Err(error)
+            }
+        },
+    }
+}
+
+/// Resolves the host and enriches its first address with ASN and country data, and the
+/// number of domains sharing it.
+/// Unlike the mail records, this uses the full host name, since that is what serves the site.
+async fn lookup_hosting(resolver: &TokioAsyncResolver, target: &str) -> Option<HostingInfo> {
+    debug!(target, "Resolving host address.");
+    let ip = match resolver.lookup_ip(target).await {
+        Ok(lookup) => lookup.iter().next()?,
+        Err(e) => {
+            warn!(target, error = %e, "Host address lookup failed.");
+            return None;
+        }
+    };
+    let geo = geoip::lookup(ip).unwrap_or_default();
+    let shared_domains = reverse_ip::domain_count(ip);
+    debug!(%ip, asn = ?geo.asn, country = ?geo.country, ?shared_domains, "Host address resolved.");
+    Some(HostingInfo { ip: ip.to_string(), asn: geo.asn, as_org: geo.as_org, country: geo.country, shared_domains })
+}
+
+/// Looks up the MX records of a domain and resolves each mail exchanger, for checking that SPF
+/// authorizes the domain's own mail servers. A null MX (".", the domain takes no mail) yields none.
+async fn lookup_mx(resolver: &TokioAsyncResolver, target: &str) -> ScanResult<Vec<MxRecord>> {
+    debug!(target, "Looking up MX records.");
+    let lookup = match resolver.mx_lookup(target).await {
+        Ok(lookup) => lookup,
+        Err(e) => {
+            return match resolution_error(&e) {
+                None => {
+                    debug!(target, "No MX records found.");
+                    Ok(None)
+                }
+                Some(error) => {
+                    warn!(target, error = %e, "MX lookup failed.");
+                    Err(error)
+                }
+            };
+        }
+    };
+    let mut exchanges: Vec<(u16, String)> = lookup.iter()
+        .map(|mx| (mx.preference(), mx.exchange().to_utf8().trim_end_matches('.').to_string()))
+        .filter(|(_, exchange)| !exchange.is_empty())
+        .collect();
+    if exchanges.is_empty() {
+        return Ok(None);
+    }
+    exchanges.sort();
+    let records = join_all(exchanges.into_iter().map(|(preference, exchange)| async move {
+        let addresses = match resolver.lookup_ip(exchange.as_str()).await {
+            Ok(lookup) => lookup.iter().map(|ip| ip.to_string()).collect(),
+            Err(e) => {
+                debug!(exchange = %exchange, error = %e, "Mail exchanger did not resolve.");
+                Vec::new()
+            }
+        };
+        MxRecord { preference, exchange, addresses }
+    })).await;
+    info!(count = %records.len(), "Found MX records.");
+    Ok(Some(records))
+}
+
+/// Looks up the CAA (Certification Authority Authorization) records that govern a host: its
+/// own or, when it has none, those of the closest parent domain that does (RFC 8659 §3), e.g.
+/// `example.com` for `www.example.com`. Top-level domains are not queried.
 async fn lookup_caa(resolver: &TokioAsyncResolver, target: &str) -> ScanResult<Vec<String>> {
+    let mut domain = target.trim_end_matches('.');
+    loop {
+        if let Some(records) = lookup_caa_at(resolver, domain).await? {
+            return Ok(Some(records));
+        }
+        match domain.split_once('.') {
+            Some((_, parent)) if parent.contains('.') => domain = parent,
+            _ => return Ok(None),
+        }
+    }
+}
+
+/// Looks up the CAA records published at exactly `target`.
+async fn lookup_caa_at(resolver: &TokioAsyncResolver, target: &str) -> ScanResult<Vec<String>> {
     debug!(target, "Looking up CAA records.");
     match resolver.lookup(target, RecordType::CAA).await {
         Ok(caa_lookup) => {
@@ -231,9 +674,300 @@ async fn lookup_caa(resolver: &TokioAsyncResolver, target: &str) -> ScanResult<V
             info!(count = %records.len(), "Found CAA records.");
             Ok(Some(records))
         },
-        Err(e) => {
-            warn!(target, error = %e, "CAA lookup failed.");
-            Err(format!("DNS Error: {}", e))
+        Err(e) => match resolution_error(&e) {
+            None => {
+                debug!(target, "No CAA records found.");
+                Ok(None)
+            }
+            Some(error) => {
+                warn!(target, error = %e, "CAA lookup failed.");
+                Err(error)
+            }
+        },
+    }
+}
+
+/// Splits a CAA record in its presentation format (e.g. `0 issue "letsencrypt.org"`) into its
+/// lowercase tag and its value.
+fn caa_property(record: &str) -> Option<(String, &str)> {
+    let mut parts = record.splitn(3, ' ');
+    let (_flags, tag, value) = (parts.next()?, parts.next()?, parts.next()?);
+    Some((tag.to_ascii_lowercase(), value))
+}
+
+/// The CAA tag that governs a certificate (RFC 8659 §4.3): `issuewild` for a wildcard
+/// certificate when the records have one, since it then overrides `issue`, and `issue`
+/// otherwise. `issuewild` never applies to other certificates.
+fn governing_caa_tag(records: &[String], wildcard: bool) -> &'static str {
+    let has_issuewild = records.iter().any(|record| caa_property(record).is_some_and(|(tag, _)| tag == "issuewild"));
+    if wildcard && has_issuewild { "issuewild" } else { "issue" }
+}
+
+/// Extracts the CA domains the CAA records authorize to issue a certificate, wildcard or not
+/// (see `governing_caa_tag`).
+///
+/// Records are in their presentation format (e.g. `0 issue "letsencrypt.org; validationmethods=dns-01"`).
+/// Parameters after the `;` are ignored, and an empty value (`";"`), which forbids issuance, yields no domain.
+///
+/// # Arguments
+/// * `records` - The CAA records as returned by `lookup_caa`.
+/// * `wildcard` - Whether the certificate is a wildcard one.
+///
+/// # Returns
+/// The lowercase CA domains, or an empty vector if no record authorizes any CA.
+pub fn caa_issuer_domains(records: &[String], wildcard: bool) -> Vec<String> {
+    let governing = governing_caa_tag(records, wildcard);
+    records.iter()
+        .filter_map(|record| {
+            let (tag, value) = caa_property(record)?;
+            if tag != governing {
+                return None;
+            }
+            let domain = value.trim_matches('"').split(';').next()?.trim().trim_end_matches('.');
+            (!domain.is_empty()).then(|| domain.to_lowercase())
+        })
+        .collect()
+}
+
+/// Returns `true` if the CAA records restrict the issuance of the certificate, wildcard or not
+/// (possibly to no CA at all): they hold a property with its governing tag.
+pub fn caa_restricts_issuance(records: &[String], wildcard: bool) -> bool {
+    let governing = governing_caa_tag(records, wildcard);
+    records.iter().any(|record| caa_property(record).is_some_and(|(tag, _)| tag == governing))
+}
+
+/// Returns the domain's own mail servers that its SPF record does not authorize to send.
+///
+/// Only MX hosts inside `domain` (e.g. "mail.example.com") are checked: a provider's inbound
+/// servers (e.g. "aspmx.l.google.com") rarely send the domain's mail, which its `include:` is
+/// for. A pass `a` or `mx` mechanism is taken to cover them, as is a `redirect=`, which hands the
+/// policy to another record; otherwise a host is covered when one of its addresses is in a pass
+/// `ip4` or `ip6` network. Includes are not resolved.
+pub fn spf_uncovered_mx_hosts(spf: &SpfParsed, mx: &[MxRecord], domain: &str) -> Vec<String> {
+    let passes = |mechanism: &&SpfMechanism| mechanism.qualifier == '+';
+    if spf.mechanisms.iter().filter(passes).any(|mechanism| mechanism.kind == "a" || mechanism.kind == "mx")
+        || spf.modifiers.iter().any(|modifier| modifier.to_ascii_lowercase().starts_with("redirect="))
+    {
+        return Vec::new();
+    }
+    let networks: Vec<&str> = spf.mechanisms.iter()
+        .filter(passes)
+        .filter(|mechanism| mechanism.kind == "ip4" || mechanism.kind == "ip6")
+        .filter_map(|mechanism| mechanism.value.as_deref())
+        .collect();
+    let domain = domain::normalize(domain);
+    mx.iter()
+        .filter(|record| domain::same_domain(&domain::normalize(&record.exchange), &domain))
+        .filter(|record| !record.addresses.is_empty())
+        .filter(|record| {
+            !record.addresses.iter()
+                .filter_map(|address| address.parse::<IpAddr>().ok())
+                .any(|address| networks.iter().any(|network| network_contains(network, address)))
+        })
+        .map(|record| record.exchange.clone())
+        .collect()
+}
+
+/// Whether `address` is in `network`, an SPF `ip4`/`ip6` value such as "192.0.2.0/24" or a
+/// single address. Networks of the other address family never match.
+fn network_contains(network: &str, address: IpAddr) -> bool {
+    let (base, prefix) = match network.split_once('/') {
+        Some((base, prefix)) => (base, prefix.parse::<u32>().ok()),
+        None => (network, None),
+    };
+    match (base.parse::<IpAddr>(), address) {
+        (Ok(IpAddr::V4(base)), IpAddr::V4(address)) => {
+            let prefix = prefix.unwrap_or(32).min(32);
+            let mask = u32::MAX.checked_shl(32 - prefix).unwrap_or(0);
+            u32::from(base) & mask == u32::from(address) & mask
+        }
+        (Ok(IpAddr::V6(base)), IpAddr::V6(address)) => {
+            let prefix = prefix.unwrap_or(128).min(128);
+            let mask = u128::MAX.checked_shl(128 - prefix).unwrap_or(0);
+            u128::from(base) & mask == u128::from(address) & mask
         }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::scanner::test_support::{MockDnsServer, a, caa, codes, mx, txt, txt_strings};
+    use hickory_resolver::proto::rr::Record;
+    use std::net::Ipv4Addr;
+
+    async fn scan(records: Vec<Record>) -> DnsResults {
+        let server = MockDnsServer::start(records).await;
+        scan_dns(&server.resolver(), "mock".to_string(), "example.com").await
+    }
+
+    #[tokio::test]
+    async fn reports_weak_spf_and_dmarc_policies() {
+        let results = scan(vec![
+            txt("example.com.", "v=spf1 include:_spf.example.net ~all"),
+            txt("_dmarc.example.com.", "v=DMARC1; p=none; rua=mailto:dmarc@example.com"),
+            txt("google._domainkey.example.com.", "v=DKIM1; k=rsa; p=MIGf"),
+            a("example.com.", Ipv4Addr::new(192, 0, 2, 1)),
+        ]).await;
+        assert_eq!(results.resolver, "mock");
+        assert_eq!(results.dmarc.as_ref().unwrap().as_ref().unwrap().policy.as_deref(), Some("none"));
+        assert_eq!(results.hosting.as_ref().map(|hosting| hosting.ip.as_str()), Some("192.0.2.1"));
+        let found = codes(&results.analysis);
+        assert!(found.contains(&"DNS_DMARC_POLICY_NONE"), "{:?}", found);
+        assert!(found.contains(&"DNS_SPF_POLICY_SOFTFAIL"), "{:?}", found);
+        assert!(found.contains(&"DNS_DKIM_PROVIDER_DETECTED"), "{:?}", found);
+    }
+
+    #[test]
+    fn issuewild_only_governs_wildcard_certificates() {
+        let records = vec!["0 issue \"letsencrypt.org\"".to_string(), "0 issuewild \"DigiCert.com.\"".to_string()];
+        assert_eq!(caa_issuer_domains(&records, false), ["letsencrypt.org"]);
+        assert_eq!(caa_issuer_domains(&records, true), ["digicert.com"]);
+
+        // Without issuewild, wildcard certificates fall back to issue.
+        let issue_only = vec!["0 issue \"letsencrypt.org; validationmethods=dns-01\"".to_string()];
+        assert_eq!(caa_issuer_domains(&issue_only, true), ["letsencrypt.org"]);
+
+        // An issuewild alone leaves other certificates unrestricted.
+        let wildcard_only = vec!["0 issuewild \";\"".to_string()];
+        assert!(!caa_restricts_issuance(&wildcard_only, false));
+        assert!(caa_restricts_issuance(&wildcard_only, true));
+        assert!(caa_issuer_domains(&wildcard_only, true).is_empty());
+    }
+
+    #[tokio::test]
+    async fn joins_records_split_across_several_strings() {
+        let results = scan(vec![
+            txt_strings("example.com.", &["v=spf1 include:_spf.exa", "mple.net -a", "ll"]),
+            txt_strings("_dmarc.example.com.", &["v=DMARC1; p=rej", "ect"]),
+        ]).await;
+        let spf = results.spf.as_ref().unwrap().as_ref().unwrap();
+        assert_eq!(spf.record, "v=spf1 include:_spf.example.net -all");
+        let all = spf.parsed.mechanisms.last().unwrap();
+        assert_eq!((all.qualifier, all.kind.as_str()), ('-', "all"));
+        assert_eq!(results.dmarc.as_ref().unwrap().as_ref().unwrap().policy.as_deref(), Some("reject"));
     }
-}
\ No newline at end of file
+
+    #[tokio::test]
+    async fn queries_every_dkim_selector() {
+        let server = MockDnsServer::start(vec![txt("s1._domainkey.example.com.", "v=DKIM1; k=rsa; p=MIGf")]).await;
+        let records = lookup_dkim(&server.resolver(), "example.com").await.unwrap().unwrap();
+        let selectors: Vec<&str> = records.iter().map(|record| record.selector.as_str()).collect();
+        assert_eq!(selectors, ["s1"]);
+
+        let queried = server.queried_names();
+        let missed: Vec<&str> = DKIM_SELECTORS.iter()
+            .map(|dkim| dkim.selector)
+            .filter(|selector| !queried.contains(&format!("{}._domainkey.example.com.", selector)))
+            .collect();
+        assert!(missed.is_empty(), "selectors never queried: {:?}", missed);
+    }
+
+    #[tokio::test]
+    async fn caa_is_inherited_from_the_closest_parent_domain() {
+        let server = MockDnsServer::start(vec![
+            caa("example.com.", false, "letsencrypt.org"),
+            caa("other.example.com.", false, "pki.goog"),
+        ]).await;
+        let resolver = server.resolver();
+        let inherited = lookup_caa(&resolver, "www.shop.example.com").await.unwrap().unwrap();
+        assert_eq!(caa_issuer_domains(&inherited, false), ["letsencrypt.org"]);
+        let own = lookup_caa(&resolver, "other.example.com").await.unwrap().unwrap();
+        assert_eq!(caa_issuer_domains(&own, false), ["pki.goog"]);
+        assert_eq!(lookup_caa(&resolver, "example.org").await, Ok(None));
+    }
+
+    #[test]
+    fn parses_spf_qualifiers_mechanisms_and_modifiers() {
+        let parsed = parse_spf("v=spf1 a mx/24 -ip4:192.0.2.0/24 ~include:_spf.example.net ?exists:%{i}.example.com redirect=_spf.example.com all");
+        assert_eq!(parsed.version, "spf1");
+        let mechanisms: Vec<(char, &str, Option<&str>)> = parsed.mechanisms.iter()
+            .map(|mechanism| (mechanism.qualifier, mechanism.kind.as_str(), mechanism.value.as_deref()))
+            .collect();
+        assert_eq!(mechanisms, [
+            ('+', "a", None),
+            ('+', "mx", Some("/24")),
+            ('-', "ip4", Some("192.0.2.0/24")),
+            ('~', "include", Some("_spf.example.net")),
+            ('?', "exists", Some("%{i}.example.com")),
+            // An `all` without a qualifier passes everyone.
+            ('+', "all", None),
+        ]);
+        assert_eq!(parsed.modifiers, ["redirect=_spf.example.com"]);
+    }
+
+    #[test]
+    fn validates_dmarc_records_as_receivers_read_them() {
+        let errors = |record: &str| parse_dmarc(record).1;
+        assert_eq!(errors("p=reject; v=DMARC1"), ["the record does not start with 'v=DMARC1'"]);
+        assert_eq!(errors("v=DMARC1; p=nonee"), ["invalid p value 'nonee'"]);
+        assert_eq!(errors("v=DMARC1; p=none; P=reject"), ["the 'p' tag appears more than once"]);
+        assert!(errors("v=DMARC1; p=reject; future=1").is_empty());
+        assert_eq!(errors("v=DMARC1; pct=100"), ["the required 'p' tag is missing"]);
+
+        // Without `p`, a valid `rua` makes receivers act as if the policy were "none".
+        let (tags, errors) = parse_dmarc("v=DMARC1; rua=mailto:dmarc@example.com");
+        assert!(errors.is_empty());
+        assert_eq!(dmarc_policy(&tags, &errors).as_deref(), Some("none"));
+    }
+
+    #[tokio::test]
+    async fn reports_malformed_dmarc_instead_of_its_policy() {
+        let results = scan(vec![
+            txt("example.com.", "v=spf1 -all"),
+            txt("_dmarc.example.com.", "v=DMARC1; p=nonee"),
+        ]).await;
+        let found = codes(&results.analysis);
+        assert!(found.contains(&"DNS_DMARC_MALFORMED"), "{:?}", found);
+        assert!(!found.contains(&"DNS_DMARC_POLICY_NONE"), "{:?}", found);
+    }
+
+    #[tokio::test]
+    async fn strict_records_raise_no_email_findings() {
+        let results = scan(vec![
+            txt("example.com.", "v=spf1 -all"),
+            txt("_dmarc.example.com.", "v=DMARC1; p=reject"),
+        ]).await;
+        let found = codes(&results.analysis);
+        assert!(!found.iter().any(|code| code.starts_with("DNS_SPF") || code.starts_with("DNS_DMARC")), "{:?}", found);
+    }
+
+    #[tokio::test]
+    async fn a_domain_that_does_not_exist_is_one_finding() {
+        let server = MockDnsServer::start_with_missing_response(Vec::new(), ResponseCode::NXDomain).await;
+        let results = scan_dns(&server.resolver(), "mock".to_string(), "exmaple.com").await;
+        assert!(results.domain_not_found);
+        assert_eq!(codes(&results.analysis), ["DNS_DOMAIN_NOT_FOUND"]);
+        assert_eq!(results.analysis[0].subject.as_deref(), Some("exmaple.com"));
+    }
+
+    #[tokio::test]
+    async fn resolver_failures_are_not_reported_as_missing_records() {
+        let server = MockDnsServer::start_with_missing_response(Vec::new(), ResponseCode::ServFail).await;
+        let results = scan_dns(&server.resolver(), "mock".to_string(), "example.com").await;
+        assert!(!results.domain_not_found);
+        assert_eq!(codes(&results.analysis), ["DNS_RESOLUTION_ERROR"]);
+        assert_eq!(results.analysis[0].subject.as_deref(), Some("SPF, DMARC, DKIM, CAA"));
+    }
+
+    #[tokio::test]
+    async fn reports_own_mail_servers_missing_from_spf() {
+        let records = |spf: &str| vec![
+            txt("example.com.", spf),
+            mx("example.com.", 10, "mail.example.com."),
+            mx("example.com.", 20, "aspmx.l.google.com."),
+            a("mail.example.com.", Ipv4Addr::new(192, 0, 2, 25)),
+            a("aspmx.l.google.com.", Ipv4Addr::new(198, 51, 100, 1)),
+        ];
+        let results = scan(records("v=spf1 include:_spf.google.com -all")).await;
+        assert!(codes(&results.analysis).contains(&"DNS_SPF_MX_NOT_COVERED"), "{:?}", codes(&results.analysis));
+        let finding = results.analysis.iter().find(|finding| finding.code == "DNS_SPF_MX_NOT_COVERED").unwrap();
+        assert_eq!(finding.subject.as_deref(), Some("mail.example.com"));
+
+        for covered in ["v=spf1 ip4:192.0.2.0/24 include:_spf.google.com -all", "v=spf1 mx -all", "v=spf1 a -all"] {
+            let results = scan(records(covered)).await;
+            assert!(!codes(&results.analysis).contains(&"DNS_SPF_MX_NOT_COVERED"), "{}: {:?}", covered, codes(&results.analysis));
+        }
+    }
+}
@@ -0,0 +1,80 @@
+// src/core/scanner/domain.rs
+
+//! Host name helpers shared by the scanners.
+//!
+//! The registrable domain of a host (its "site", e.g. `example.co.uk` for `www.example.co.uk`)
+//! comes from the Public Suffix List bundled with the binary, so lookups stay offline.
+
+use once_cell::sync::Lazy;
+use publicsuffix::{List, Psl};
+
+/// The Public Suffix List, from https://publicsuffix.org/list/public_suffix_list.dat.
+/// Refresh the file now and then; new suffixes are rare and only matter for hosts under them.
+static PUBLIC_SUFFIXES: Lazy<List> = Lazy::new(|| {
+    include_str!("public_suffix_list.dat").parse().expect("the bundled Public Suffix List is valid")
+});
+
+/// Lowercases a host and strips the trailing dot of a fully qualified name.
+pub(crate) fn normalize(host: &str) -> String {
+    host.trim_end_matches('.').to_ascii_lowercase()
+}
+
+/// Whether two hosts belong to the same domain: they are equal, or one is a subdomain of the
+/// other (e.g. `example.com` and `mail.example.com`). Both must be normalized.
+pub(crate) fn same_domain(host: &str, other: &str) -> bool {
+    host == other || host.ends_with(&format!(".{}", other)) || other.ends_with(&format!(".{}", host))
+}
+
+/// Returns the registrable domain of a host: its public suffix plus one label, e.g.
+/// `example.com` for `shop.example.com` and `example.co.uk` for `www.example.co.uk`.
+/// `None` when the host is itself a public suffix (e.g. `co.uk`) or an IP address.
+pub(crate) fn registrable_domain(host: &str) -> Option<String> {
+    let host = host.trim_end_matches('.').to_ascii_lowercase();
+    if host.parse::<std::net::IpAddr>().is_ok() {
+        return None;
+    }
+    let domain = PUBLIC_SUFFIXES.domain(host.as_bytes())?;
+    std::str::from_utf8(domain.as_bytes()).ok().map(String::from)
+}
+
+/// Whether two hosts share a registrable domain, e.g. `www.example.co.uk` and `shop.example.co.uk`.
+/// Hosts without one (IP addresses and public suffixes) only match themselves.
+pub(crate) fn same_registrable_domain(host: &str, other: &str) -> bool {
+    match (registrable_domain(host), registrable_domain(other)) {
+        (Some(domain), Some(other_domain)) => domain == other_domain,
+        _ => host.trim_end_matches('.').eq_ignore_ascii_case(other.trim_end_matches('.')),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_domain_matches_subdomains_either_way() {
+        assert!(same_domain("mail.example.com", "example.com"));
+        assert!(same_domain("example.com", "www.example.com"));
+        assert!(!same_domain("www.example.com", "shop.example.com"));
+        assert!(!same_domain("notexample.com", "example.com"));
+    }
+
+    #[test]
+    fn registrable_domain_follows_the_public_suffix_list() {
+        assert_eq!(registrable_domain("shop.eu.Example.com.").as_deref(), Some("example.com"));
+        assert_eq!(registrable_domain("www.example.co.uk").as_deref(), Some("example.co.uk"));
+        // Private suffixes count too: each GitHub Pages site is its own domain.
+        assert_eq!(registrable_domain("docs.project.github.io").as_deref(), Some("project.github.io"));
+        assert_eq!(registrable_domain("co.uk"), None);
+        assert_eq!(registrable_domain("192.0.2.1"), None);
+    }
+
+    #[test]
+    fn compares_hosts_by_registrable_domain() {
+        assert!(same_registrable_domain("example.co.uk", "www.example.co.uk"));
+        assert!(same_registrable_domain("shop.example.com", "WWW.example.com."));
+        assert!(!same_registrable_domain("example.co.uk", "other.co.uk"));
+        assert!(!same_registrable_domain("docs.project.github.io", "other.github.io"));
+        assert!(same_registrable_domain("192.0.2.1", "192.0.2.1"));
+        assert!(!same_registrable_domain("192.0.2.1", "192.0.2.2"));
+    }
+}
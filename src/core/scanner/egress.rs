@@ -0,0 +1,163 @@
+// src/core/scanner/egress.rs
+
+//! The record of every host a scan connects to, and the offline mode that refuses
+//! connections to anything but the target.
+//!
+//! The HTTP clients resolve host names through `HostResolver`, which asks `Egress` first, so
+//! no request leaves without being recorded, and none to another host in offline mode. Hosts
+//! given as IP addresses skip name resolution, so redirects to them are checked with
+//! `Egress::admit_url` instead. The TLS handshakes only connect to the target, and the DNS
+//! lookups to the configured resolver, so both are recorded without a check.
+
+use reqwest::dns::{Addrs, Name, Resolve, Resolving};
+use reqwest::redirect::{Attempt, Policy};
+use std::collections::BTreeSet;
+use std::sync::{Arc, Mutex};
+use tracing::{info, warn};
+use url::{Host, Url};
+use crate::core::scanner::domain::normalize;
+
+/// How many redirects the HTTP clients follow, as with reqwest's default policy.
+const MAX_REDIRECTS: usize = 10;
+
+/// The hosts one scan connected to or was refused, shared by all its clients.
+#[derive(Debug)]
+pub struct Egress {
+    /// The scanned target; its domain is all offline mode allows.
+    target: Mutex<String>,
+    offline: bool,
+    contacted: Mutex<BTreeSet<String>>,
+    blocked: Mutex<BTreeSet<String>>,
+}
+
+impl Egress {
+    pub fn new(target: &str, offline: bool) -> Arc<Self> {
+        Arc::new(Self {
+            target: Mutex::new(normalize(target)),
+            offline,
+            contacted: Mutex::new(BTreeSet::new()),
+            blocked: Mutex::new(BTreeSet::new()),
+        })
+    }
+
+    /// Whether a connection to `host` may be made, recording it either way. In offline mode,
+    /// only the target and its subdomains are allowed, never a parent or sibling domain;
+    /// otherwise every host is.
+    pub fn admit(&self, host: &str) -> bool {
+        let host = normalize(host);
+        if self.offline && !within(&host, &self.target.lock().unwrap()) {
+            if self.blocked.lock().unwrap().insert(host.clone()) {
+                warn!(host = %host, "Offline mode: refused a connection to a host other than the target.");
+            }
+            return false;
+        }
+        self.record(&host);
+        true
+    }
+
+    /// Makes `target` the host offline mode allows, when the scan is pointed at another host.
+    /// The hosts contacted and refused so far stay recorded.
+    pub fn retarget(&self, target: &str) {
+        *self.target.lock().unwrap() = normalize(target);
+    }
+
+    /// Like `admit`, for a URL about to be requested. Host names are left to `HostResolver`,
+    /// which sees them anyway; only IP addresses, which it never sees, are checked here.
+    pub fn admit_url(&self, url: &Url) -> bool {
+        match url.host() {
+            Some(Host::Ipv4(ip)) => self.admit(&ip.to_string()),
+            Some(Host::Ipv6(ip)) => self.admit(&ip.to_string()),
+            Some(Host::Domain(_)) | None => true,
+        }
+    }
+
+    /// Records a connection offline mode does not restrict, e.g. to the DNS resolver.
+    pub fn record(&self, host: &str) {
+        let host = normalize(host);
+        if self.contacted.lock().unwrap().insert(host.clone()) {
+            info!(host = %host, "Outbound connection.");
+        }
+    }
+
+    /// The hosts connected to, sorted.
+    pub fn contacted(&self) -> Vec<String> {
+        self.contacted.lock().unwrap().iter().cloned().collect()
+    }
+
+    /// The hosts offline mode refused, sorted.
+    pub fn blocked(&self) -> Vec<String> {
+        self.blocked.lock().unwrap().iter().cloned().collect()
+    }
+
+    /// The name resolver for the HTTP clients of this scan.
+    pub fn resolver(self: &Arc<Self>) -> Arc<HostResolver> {
+        Arc::new(HostResolver { egress: Arc::clone(self) })
+    }
+
+    /// The redirect policy for the HTTP clients that follow redirects: reqwest's default,
+    /// except that redirects to a refused IP address fail.
+    pub fn redirect_policy(self: &Arc<Self>) -> Policy {
+        let egress = Arc::clone(self);
+        Policy::custom(move |attempt: Attempt| {
+            if attempt.previous().len() >= MAX_REDIRECTS {
+                attempt.error("too many redirects")
+            } else if egress.admit_url(attempt.url()) {
+                attempt.follow()
+            } else {
+                let error = format!("offline mode: {} is not the scan target", attempt.url());
+                attempt.error(error)
+            }
+        })
+    }
+}
+
+/// Whether `host` is `target` or one of its subdomains; both are normalized.
+fn within(host: &str, target: &str) -> bool {
+    host == target || host.ends_with(&format!(".{}", target))
+}
+
+/// Resolves host names for reqwest with the system resolver, once `Egress` admits them.
+#[derive(Debug)]
+pub struct HostResolver {
+    egress: Arc<Egress>,
+}
+
+impl Resolve for HostResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        let admitted = self.egress.admit(name.as_str());
+        let host = name.as_str().to_string();
+        Box::pin(async move {
+            if !admitted {
+                return Err(format!("offline mode: {} is not the scan target", host).into());
+            }
+            let addrs = tokio::net::lookup_host((host.as_str(), 0)).await?;
+            Ok(Box::new(addrs.collect::<Vec<_>>().into_iter()) as Addrs)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn offline_mode_only_admits_the_target_and_its_subdomains() {
+        let egress = Egress::new("example.com", true);
+        assert!(egress.admit("www.Example.com."));
+        assert!(!egress.admit("cdn.example.net"));
+        assert!(!egress.admit_url(&Url::parse("http://192.0.2.1/").unwrap()));
+        assert_eq!(egress.contacted(), ["www.example.com"]);
+        assert_eq!(egress.blocked(), ["192.0.2.1", "cdn.example.net"]);
+
+        let egress = Egress::new("www.example.com", true);
+        assert!(egress.admit("static.www.example.com"));
+        assert!(!egress.admit("example.com"));
+        assert!(!egress.admit("shop.example.com"));
+        assert!(!egress.admit("badwww.example.com"));
+        assert_eq!(egress.blocked(), ["badwww.example.com", "example.com", "shop.example.com"]);
+
+        let online = Egress::new("example.com", false);
+        assert!(online.admit("cdn.example.net"));
+        assert!(online.blocked().is_empty());
+    }
+}
@@ -1,7 +1,13 @@
 // src/core/scanner/fingerprint_scanner.rs
 
 use tracing::{debug, error, info};
-use crate::core::models::{FingerprintResults, Technology};
+use crate::core::models::{AnalysisFinding, FingerprintResults, Severity, Technology};
+use crate::core::scanner::{MainPage, ScanOptions, ScanSection, fetch_main_page, fetch_main_page_with, read_body_capped};
+use crate::core::scanner::pipeline::{ScanContext, Scanner};
+use crate::core::scanner::throttle::Throttle;
+use crate::core::scanner::waf;
+use futures::future::BoxFuture;
+use reqwest::{Method, StatusCode};
 use scraper::{Html, Selector};
 use std::collections::HashMap;
 use regex::Regex;
@@ -21,8 +27,25 @@ enum Check<'a> {
     LinkHref(&'a Lazy<Regex>),
     /// Check for a pattern in the `set-cookie` headers.
     Cookie(&'a Lazy<Regex>),
+    /// Check for a session cookie with this name, matched case-insensitively.
+    SessionCookie(&'a str),
 }
 
+impl Check<'_> {
+    /// Whether the check only needs the response headers, so it also works on a `HEAD` response.
+    fn reads_headers_only(&self) -> bool {
+        matches!(self, Check::Header(..) | Check::Cookie(_) | Check::SessionCookie(_))
+    }
+}
+
+/// How much of the page is downloaded unless configured otherwise. The rules look for
+/// markers in the head of the document, which comes well within it.
+pub const DEFAULT_BODY_LIMIT: usize = 512 * 1024;
+
+/// The statuses of servers that do not answer `HEAD` like `GET`; the quick mode falls back to
+/// `GET` on them, as their headers may not be the page's.
+const HEAD_REFUSED_STATUSES: [StatusCode; 2] = [StatusCode::METHOD_NOT_ALLOWED, StatusCode::NOT_IMPLEMENTED];
+
 /// A rule that defines how to detect a specific technology.
 struct FingerprintRule<'a> {
     /// The name of the technology (e.g., "Nginx").
@@ -48,11 +71,8 @@ static RE_JOOMLA: Lazy<Regex> = Lazy::new(|| Regex::new(r"Joomla!").unwrap());
 static RE_SHOPIFY: Lazy<Regex> = Lazy::new(|| Regex::new(r"shopify").unwrap());
 static RE_MAGENTO: Lazy<Regex> = Lazy::new(|| Regex::new(r"magento").unwrap());
 static RE_PHP: Lazy<Regex> = Lazy::new(|| Regex::new(r"PHP/([\d\.]+)").unwrap());
-static RE_PHPSESSID: Lazy<Regex> = Lazy::new(|| Regex::new(r"PHPSESSID").unwrap());
 static RE_ASPNET: Lazy<Regex> = Lazy::new(|| Regex::new(r"ASP\.NET").unwrap());
-static RE_JSESSIONID: Lazy<Regex> = Lazy::new(|| Regex::new(r"JSESSIONID").unwrap());
 static RE_DJANGO_CSRF: Lazy<Regex> = Lazy::new(|| Regex::new(r"csrftoken").unwrap());
-static RE_RUBY_RAILS: Lazy<Regex> = Lazy::new(|| Regex::new(r"_rails_session").unwrap());
 static RE_NEXTJS: Lazy<Regex> = Lazy::new(|| Regex::new(r"Next\.js ([\d\.]+)").unwrap());
 static RE_NEXTJS_SCRIPT: Lazy<Regex> = Lazy::new(|| Regex::new(r"/_next/static/").unwrap());
 static RE_NUXTJS: Lazy<Regex> = Lazy::new(|| Regex::new(r"__NUXT__").unwrap());
@@ -68,6 +88,83 @@ static RE_VUE: Lazy<Regex> = Lazy::new(|| Regex::new(r"data-v-app|__VUE_").unwra
 static RE_BOOTSTRAP: Lazy<Regex> = Lazy::new(|| Regex::new(r"bootstrap.min.css").unwrap());
 static RE_GOOGLE_ANALYTICS: Lazy<Regex> = Lazy::new(|| Regex::new(r"google-analytics.com/|googletagmanager.com/").unwrap());
 
+// Error-page signatures, matched against the page served for a path that does not exist.
+static RE_ERR_DJANGO_DEBUG: Lazy<Regex> = Lazy::new(|| Regex::new(r"You're seeing this error because you have <code>DEBUG = True</code>").unwrap());
+static RE_ERR_WERKZEUG: Lazy<Regex> = Lazy::new(|| Regex::new(r"Werkzeug Debugger|werkzeug\.debug").unwrap());
+static RE_ERR_LARAVEL: Lazy<Regex> = Lazy::new(|| Regex::new(r"Whoops! There was an error|Illuminate\\|ignition-").unwrap());
+static RE_ERR_SYMFONY: Lazy<Regex> = Lazy::new(|| Regex::new(r"Symfony Exception|sf-dump|symfony/error-handler").unwrap());
+static RE_ERR_RAILS_DEBUG: Lazy<Regex> = Lazy::new(|| Regex::new(r"Action Controller: Exception caught|<h1>Routing Error</h1>").unwrap());
+static RE_ERR_RAILS: Lazy<Regex> = Lazy::new(|| Regex::new(r"The page you were looking for doesn't exist").unwrap());
+static RE_ERR_ASPNET: Lazy<Regex> = Lazy::new(|| Regex::new(r"Server Error in '[^']*' Application").unwrap());
+static RE_ERR_ASPNET_VERSION: Lazy<Regex> = Lazy::new(|| Regex::new(r"ASP\.NET Version:([\d\.]+)").unwrap());
+static RE_ERR_SPRING: Lazy<Regex> = Lazy::new(|| Regex::new(r"Whitelabel Error Page").unwrap());
+static RE_ERR_TOMCAT: Lazy<Regex> = Lazy::new(|| Regex::new(r"Apache Tomcat/([\d\.]+)").unwrap());
+static RE_ERR_EXPRESS: Lazy<Regex> = Lazy::new(|| Regex::new(r"<pre>Cannot GET /").unwrap());
+static RE_ERR_PHP: Lazy<Regex> = Lazy::new(|| Regex::new(r"<b>(?:Fatal error|Warning|Parse error)</b>:.+ on line <b>\d+</b>").unwrap());
+/// Stack traces of the common runtimes: Python, Java/.NET, Node.js, and PHP.
+static RE_STACK_TRACE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"Traceback \(most recent call last\)|\n\s*at [\w.$<>]+\([\w.]+\.(?:java|cs):\d+\)|\n\s*at .+ \(/.+:\d+:\d+\)|Stack Trace:|#0 /.+\.php\(\d+\)").unwrap()
+});
+
+/// A framework's error page.
+struct ErrorPageSignature<'a> {
+    /// The technology it reveals, named as in `RULES` where both detect it.
+    tech_name: &'a str,
+    category: &'a str,
+    /// Matches the page; a first capture group is taken as the version.
+    pattern: &'a Lazy<Regex>,
+    /// Whether the page is a debug page, which exposes source code, settings or a stack trace.
+    debug: bool,
+}
+
+/// The master list of error-page signatures, debug pages first: a debug page is the most
+/// specific match, and the one that matters.
+static ERROR_PAGE_SIGNATURES: &[ErrorPageSignature] = &[
+    ErrorPageSignature { tech_name: "Python/Django", category: "Framework", pattern: &RE_ERR_DJANGO_DEBUG, debug: true },
+    ErrorPageSignature { tech_name: "Flask", category: "Framework", pattern: &RE_ERR_WERKZEUG, debug: true },
+    ErrorPageSignature { tech_name: "Laravel", category: "Framework", pattern: &RE_ERR_LARAVEL, debug: true },
+    ErrorPageSignature { tech_name: "Symfony", category: "Framework", pattern: &RE_ERR_SYMFONY, debug: true },
+    ErrorPageSignature { tech_name: "Ruby on Rails", category: "Framework", pattern: &RE_ERR_RAILS_DEBUG, debug: true },
+    ErrorPageSignature { tech_name: "PHP", category: "Language", pattern: &RE_ERR_PHP, debug: true },
+    ErrorPageSignature { tech_name: "ASP.NET", category: "Framework", pattern: &RE_ERR_ASPNET_VERSION, debug: false },
+    ErrorPageSignature { tech_name: "ASP.NET", category: "Framework", pattern: &RE_ERR_ASPNET, debug: false },
+    ErrorPageSignature { tech_name: "Ruby on Rails", category: "Framework", pattern: &RE_ERR_RAILS, debug: false },
+    ErrorPageSignature { tech_name: "Spring Boot", category: "Framework", pattern: &RE_ERR_SPRING, debug: false },
+    ErrorPageSignature { tech_name: "Apache Tomcat", category: "Web Server", pattern: &RE_ERR_TOMCAT, debug: false },
+    ErrorPageSignature { tech_name: "Express", category: "Framework", pattern: &RE_ERR_EXPRESS, debug: false },
+];
+
+// Admin panel signatures, matched against the page served at the panel's path.
+static RE_PANEL_WP_LOGIN: Lazy<Regex> = Lazy::new(|| Regex::new(r#"id=["']loginform["']|name=["']wp-submit["']"#).unwrap());
+static RE_PANEL_JOOMLA: Lazy<Regex> = Lazy::new(|| Regex::new(r"com_login").unwrap());
+static RE_PANEL_MAGENTO: Lazy<Regex> = Lazy::new(|| Regex::new(r#"name=["']login\[username\]["']"#).unwrap());
+static RE_PANEL_PHPMYADMIN: Lazy<Regex> = Lazy::new(|| Regex::new(r"phpMyAdmin").unwrap());
+static RE_PANEL_LOGIN_FORM: Lazy<Regex> = Lazy::new(|| Regex::new(r#"type=["']?password"#).unwrap());
+
+/// An admin panel worth looking for, at the path a technology installs it at.
+struct AdminPanel<'a> {
+    /// The technology it belongs to, named as in `RULES`; `None` looks for it on every site.
+    tech_name: Option<&'a str>,
+    path: &'a str,
+    /// Matches the panel's page, so a soft 404 served for the path is not mistaken for it.
+    pattern: &'a Lazy<Regex>,
+    /// A CMS login is expected to exist and is only worth knowing about; a database
+    /// console is an attack surface of its own.
+    severity: Severity,
+}
+
+/// The admin panels probed for, kept short: only the default paths of the detected stack.
+static ADMIN_PANELS: &[AdminPanel] = &[
+    AdminPanel { tech_name: Some("WordPress"), path: "/wp-login.php", pattern: &RE_PANEL_WP_LOGIN, severity: Severity::Info },
+    AdminPanel { tech_name: Some("Joomla"), path: "/administrator/", pattern: &RE_PANEL_JOOMLA, severity: Severity::Info },
+    AdminPanel { tech_name: Some("Magento"), path: "/admin", pattern: &RE_PANEL_MAGENTO, severity: Severity::Info },
+    AdminPanel { tech_name: Some("PHP"), path: "/phpmyadmin/", pattern: &RE_PANEL_PHPMYADMIN, severity: Severity::Warning },
+    AdminPanel { tech_name: None, path: "/admin/", pattern: &RE_PANEL_LOGIN_FORM, severity: Severity::Info },
+];
+
+/// How much of a panel's page is read to match its signature; login forms are small.
+const PANEL_BODY_LIMIT: usize = 64 * 1024;
+
 /// The master list of all fingerprinting rules.
 static RULES: &[FingerprintRule] = &[
     FingerprintRule { tech_name: "Nginx", category: "Web Server", check: Check::Header("server", &RE_NGINX) },
@@ -83,11 +180,15 @@ static RULES: &[FingerprintRule] = &[
     FingerprintRule { tech_name: "Shopify", category: "E-commerce", check: Check::Header("x-shopid", &RE_SHOPIFY) },
     FingerprintRule { tech_name: "Magento", category: "E-commerce", check: Check::Cookie(&RE_MAGENTO) },
     FingerprintRule { tech_name: "PHP", category: "Language", check: Check::Header("x-powered-by", &RE_PHP) },
-    FingerprintRule { tech_name: "PHP", category: "Language", check: Check::Cookie(&RE_PHPSESSID) },
+    FingerprintRule { tech_name: "PHP", category: "Language", check: Check::SessionCookie("PHPSESSID") },
     FingerprintRule { tech_name: "ASP.NET", category: "Framework", check: Check::Header("x-aspnet-version", &RE_ASPNET) },
-    FingerprintRule { tech_name: "Java", category: "Language", check: Check::Cookie(&RE_JSESSIONID) },
+    FingerprintRule { tech_name: "ASP.NET", category: "Framework", check: Check::SessionCookie("ASP.NET_SessionId") },
+    FingerprintRule { tech_name: "Java", category: "Language", check: Check::SessionCookie("JSESSIONID") },
     FingerprintRule { tech_name: "Python/Django", category: "Framework", check: Check::Cookie(&RE_DJANGO_CSRF) },
-    FingerprintRule { tech_name: "Ruby on Rails", category: "Framework", check: Check::Cookie(&RE_RUBY_RAILS) },
+    FingerprintRule { tech_name: "Python/Django", category: "Framework", check: Check::SessionCookie("sessionid") },
+    FingerprintRule { tech_name: "Ruby on Rails", category: "Framework", check: Check::SessionCookie("_rails_session") },
+    FingerprintRule { tech_name: "Laravel", category: "Framework", check: Check::SessionCookie("laravel_session") },
+    FingerprintRule { tech_name: "Express", category: "Framework", check: Check::SessionCookie("connect.sid") },
     FingerprintRule { tech_name: "Next.js", category: "JS Framework", check: Check::Header("x-powered-by", &RE_NEXTJS) },
     FingerprintRule { tech_name: "Next.js", category: "JS Framework", check: Check::ScriptSrc(&RE_NEXTJS_SCRIPT) },
     FingerprintRule { tech_name: "Nuxt.js", category: "JS Framework", check: Check::Body(&RE_NUXTJS) },
@@ -104,96 +205,350 @@ static RULES: &[FingerprintRule] = &[
     FingerprintRule { tech_name: "Google Analytics", category: "Analytics", check: Check::ScriptSrc(&RE_GOOGLE_ANALYTICS) },
 ];
 
+/// Whether a cookie with this name carries a session identifier: it is the default session
+/// cookie of one of the stacks in `RULES`. The headers scan uses it to spot responses that
+/// authenticate a visitor.
+pub fn is_known_session_cookie(name: &str) -> bool {
+    RULES.iter().any(|rule| matches!(rule.check, Check::SessionCookie(known) if known.eq_ignore_ascii_case(name)))
+}
+
+/// A rule marking every version of a technology below `supported_from` as end-of-life.
+struct EolRule<'a> {
+    /// The technology name, matching `FingerprintRule::tech_name`.
+    tech_name: &'a str,
+    /// The oldest version line that still receives security fixes.
+    supported_from: &'a str,
+}
+
+/// The end-of-life table. Keep it conservative: only list version lines that are
+/// unambiguously out of support, so a match is always actionable.
+static EOL_RULES: &[EolRule] = &[
+    EolRule { tech_name: "PHP", supported_from: "8.1" },
+    EolRule { tech_name: "jQuery", supported_from: "3.0" },
+    EolRule { tech_name: "Bootstrap", supported_from: "4.0" },
+    EolRule { tech_name: "Apache", supported_from: "2.4" },
+    EolRule { tech_name: "Nginx", supported_from: "1.20" },
+    EolRule { tech_name: "Angular", supported_from: "15.0" },
+];
 
 /// Runs a technology fingerprinting scan against the target.
 ///
-/// It sends an HTTP GET request to the target, then applies a series of rules
-/// to the response headers, cookies, and body to identify the technologies in use.
+/// It sends an HTTP GET request to the target (over plain HTTP if HTTPS is unreachable),
+/// then applies a series of rules to the response headers, cookies, and body to identify
+/// the technologies in use. With `quick_fingerprint`, it sends a HEAD request instead and
+/// only applies the header and cookie rules.
 ///
-/// # Arguments
-/// * `target` - The domain or IP address to scan.
-///
-/// # Returns
-/// A `FingerprintResults` struct containing a list of identified technologies.
-pub async fn run_fingerprint_scan(target: &str) -> FingerprintResults {
-    info!(target, "Starting fingerprint scan.");
+/// The main page is fetched with the context's shared HTTP client.
+pub struct FingerprintScanner;
 
-    let client = match reqwest::Client::builder().user_agent("VanguardRS/0.1").build() {
-        Ok(c) => c,
-        Err(e) => {
-            error!(error = %e, "Failed to build HTTP client");
-            return FingerprintResults { technologies: Err(format!("HTTP client error: {}", e)) };
+impl Scanner for FingerprintScanner {
+    fn name(&self) -> &'static str {
+        "Fingerprint"
+    }
+
+    fn scan<'a>(&'a self, ctx: &'a ScanContext) -> BoxFuture<'a, ScanSection> {
+        Box::pin(async move {
+            if !self.enabled(&ctx.options) {
+                return ScanSection::Fingerprint(FingerprintResults { disabled: true, ..Default::default() });
+            }
+            info!(target = %ctx.target, "Starting fingerprint scan.");
+            let results = match &ctx.http_client {
+                Ok(client) => {
+                    let options = &ctx.options;
+                    let body_limit = options.fingerprint_body_limit.unwrap_or(DEFAULT_BODY_LIMIT);
+                    fingerprint(client, &ctx.page, &ctx.throttle, options.quick_fingerprint, options.active_probes, options.active_probes, body_limit).await
+                }
+                Err(e) => FingerprintResults { technologies: Err(format!("HTTP client error: {}", e)), ..Default::default() },
+            };
+            ScanSection::Fingerprint(results)
+        })
+    }
+
+    fn enabled(&self, options: &ScanOptions) -> bool {
+        options.scanners.scan_fingerprint
+    }
+
+    fn planned_requests(&self, target: &str, options: &ScanOptions) -> Vec<String> {
+        let page = MainPage::of(target);
+        let mut requests = vec![page.planned_request(if options.quick_fingerprint { &Method::HEAD } else { &Method::GET })];
+        if options.active_probes {
+            requests.push(format!("GET {}/vanguard-<random> (error page)", page.https_url));
+            requests.push(format!("GET {}/<path> for the admin panels of the detected technologies", page.https_url));
         }
-    };
+        requests
+    }
+}
 
-    let url = format!("https://{}", target);
-    let response = match client.get(&url).send().await {
-        Ok(res) => {
-            info!(status = %res.status(), "Received HTTP response.");
-            res
+/// Fingerprints the given main page with the given client.
+///
+/// `FingerprintScanner` passes the context's; tests pass their own to reach a local server.
+/// With `quick`, only the headers are fetched (unless the server refuses `HEAD`) and only
+/// the rules reading them are applied. With `probe_error_pages`, the error page of a path
+/// that does not exist is fingerprinted too, and with `probe_admin_panels`, the admin panels
+/// of the detected technologies are looked for. Only the first `body_limit` bytes of the page
+/// are downloaded.
+pub(crate) async fn fingerprint(client: &reqwest::Client, page: &MainPage, throttle: &Throttle, quick: bool, probe_error_pages: bool, probe_admin_panels: bool, body_limit: usize) -> FingerprintResults {
+    let mut head = None;
+    if quick {
+        match fetch_main_page_with(client, Method::HEAD, page, throttle).await {
+            Ok((res, _)) if HEAD_REFUSED_STATUSES.contains(&res.status()) => {
+                debug!(status = %res.status(), "HEAD request refused, falling back to GET.");
+            }
+            fetched => head = Some(fetched),
+        }
+    }
+    let headers_only = head.is_some();
+    let method = if headers_only { Method::HEAD } else { Method::GET };
+    let fetched = match head {
+        Some(fetched) => fetched,
+        None => fetch_main_page(client, page, throttle).await,
+    };
+    let (response, http_only) = match fetched {
+        Ok((res, http_only)) => {
+            info!(status = %res.status(), http_only, "Received HTTP response.");
+            (res, http_only)
         },
         Err(e) => {
-            error!(url = %url, error = %e, "HTTP request failed");
-            return FingerprintResults { technologies: Err(format!("HTTP request failed: {}", e)) };
+            error!(url = %page.https_url, error = %e, "HTTP request failed");
+            let request = Some(page.request_details(&method, false, None));
+            return FingerprintResults { technologies: Err(format!("HTTP request failed: {}", e)), request, ..Default::default() };
         }
     };
 
     let headers = response.headers().clone();
+    let status = response.status();
+    // Redirects were followed, so this is where the site's pages actually live.
+    let page_url = response.url().clone();
+    let request = Some(page.request_details(&method, http_only, Some(&page_url)));
     let cookies = headers.get_all("set-cookie").into_iter().filter_map(|v| v.to_str().ok()).collect::<Vec<_>>().join("; ");
+    let cookie_names: Vec<&str> = headers.get_all("set-cookie").into_iter()
+        .filter_map(|v| v.to_str().ok())
+        .filter_map(|cookie| cookie.split_once('=').map(|(name, _)| name.trim()))
+        .collect();
     
-    let body = match response.text().await {
-        Ok(text) => {
-            debug!(bytes = %text.len(), "Successfully read response body.");
-            text
+    // A HEAD response has no body, and no rule reading one is applied.
+    let read = if headers_only { Ok((Vec::new(), false)) } else { read_body_capped(response, body_limit).await };
+    let body = match read {
+        Ok((bytes, truncated)) => {
+            if truncated {
+                info!(limit = body_limit, "Page larger than the body limit, fingerprinting its start only; markers further down are missed.");
+            }
+            debug!(bytes = %bytes.len(), "Successfully read response body.");
+            String::from_utf8_lossy(&bytes).into_owned()
         },
         Err(e) => {
             error!(error = %e, "Failed to read response body");
-            return FingerprintResults { technologies: Err(format!("Failed to read response body: {}", e)) };
+            return FingerprintResults { technologies: Err(format!("Failed to read response body: {}", e)), http_only, request, ..Default::default() };
         }
     };
-    let document = Html::parse_document(&body);
-    
+    let blocked_by_waf = waf::detect_block(status, &headers, body.as_bytes());
     let mut found_techs: HashMap<String, Technology> = HashMap::new();
 
-    debug!(total_rules = %RULES.len(), "Applying fingerprinting rules.");
-    for rule in RULES {
-        // Apply the check defined by the current rule.
-        let version = match &rule.check {
-            Check::Header(name, re) => check_with_regex(headers.get(*name).and_then(|v| v.to_str().ok()), re),
-            Check::MetaTag(name, re) => check_meta_tag(&document, name, re),
-            Check::Body(re) => check_with_regex(Some(&body), re),
-            Check::ScriptSrc(re) => check_script_src(&document, re),
-            Check::LinkHref(re) => check_link_href(&document, re),
-            Check::Cookie(re) => check_with_regex(Some(&cookies), re),
-        };
+    // The parsed document cannot be held across the probe below, as it is not `Send`.
+    {
+        let document = Html::parse_document(&body);
+        debug!(total_rules = %RULES.len(), headers_only, "Applying fingerprinting rules.");
+        for rule in RULES.iter().filter(|rule| !headers_only || rule.check.reads_headers_only()) {
+            // Apply the check defined by the current rule.
+            let version = match &rule.check {
+                Check::Header(name, re) => check_with_regex(headers.get(*name).and_then(|v| v.to_str().ok()), re),
+                Check::MetaTag(name, re) => check_meta_tag(&document, name, re),
+                Check::Body(re) => check_with_regex(Some(&body), re),
+                Check::ScriptSrc(re) => check_script_src(&document, re),
+                Check::LinkHref(re) => check_link_href(&document, re),
+                Check::Cookie(re) => check_with_regex(Some(&cookies), re),
+                Check::SessionCookie(name) => cookie_names.iter().any(|set| set.eq_ignore_ascii_case(name)).then_some(None),
+            };
         
-        // If the rule matched, process the result.
-        if let Some(v) = version {
-            debug!(tech = %rule.tech_name, version = ?v, "Rule matched.");
-            let tech_name_str = rule.tech_name.to_string();
-            if let Some(existing_tech) = found_techs.get_mut(&tech_name_str) {
-                // If we already detected this tech but now have a version, update it.
-                if existing_tech.version.is_none() && v.is_some() {
-                    debug!(tech = %existing_tech.name, "Updating technology with found version.");
-                    existing_tech.version = v;
-                }
-            } else {
-                // Add the newly found technology to our results.
-                found_techs.insert(tech_name_str, Technology {
-                    name: rule.tech_name.to_string(),
-                    category: rule.category.to_string(),
-                    version: v,
-                });
+            // If the rule matched, process the result.
+            if let Some(v) = version {
+                debug!(tech = %rule.tech_name, version = ?v, "Rule matched.");
+                record_technology(&mut found_techs, rule.tech_name, rule.category, v);
             }
         }
     }
 
-    info!(count = %found_techs.len(), "Fingerprint scan finished.");
+    // A WAF would answer the probe with its block page too.
+    let error_page = if probe_error_pages && blocked_by_waf.is_none() { probe_error_page(client, &page_url, throttle).await } else { None };
+    if let Some(error_page) = &error_page {
+        record_technology(&mut found_techs, error_page.tech_name, error_page.category, error_page.version.clone());
+    }
+
+    let technologies: Vec<Technology> = found_techs.into_values().collect();
+    // Versions seen on a WAF's challenge page belong to the WAF, not to the site.
+    let mut analysis = if blocked_by_waf.is_some() { Vec::new() } else { analyze_fingerprint_results(&technologies) };
+    if let Some(error_page) = error_page.filter(|error_page| error_page.debug) {
+        debug!(tech = %error_page.tech_name, "Debug error page exposed, adding HTTP_VERBOSE_ERROR finding.");
+        analysis.push(AnalysisFinding::new(Severity::Warning, "HTTP_VERBOSE_ERROR").with_subject(error_page.tech_name));
+    }
+    if probe_admin_panels && blocked_by_waf.is_none() {
+        analysis.extend(find_admin_panels(client, &page_url, throttle, &technologies).await);
+    }
+    info!(count = %technologies.len(), findings = %analysis.len(), "Fingerprint scan finished.");
     FingerprintResults {
-        technologies: Ok(found_techs.into_values().collect()),
+        technologies: Ok(technologies),
+        analysis,
+        http_only,
+        paths_probed: (probe_error_pages || probe_admin_panels) && blocked_by_waf.is_none(),
+        blocked_by_waf: blocked_by_waf.map(String::from),
+        headers_only,
+        request,
+        disabled: false,
     }
 }
 
+/// Adds a detected technology, or fills in the version of one detected without it.
+fn record_technology(found_techs: &mut HashMap<String, Technology>, name: &str, category: &str, version: Option<String>) {
+    if let Some(existing_tech) = found_techs.get_mut(name) {
+        // If we already detected this tech but now have a version, update it.
+        if existing_tech.version.is_none() && version.is_some() {
+            debug!(tech = %existing_tech.name, "Updating technology with found version.");
+            existing_tech.version = version;
+        }
+    } else {
+        // Add the newly found technology to our results.
+        found_techs.insert(name.to_string(), Technology { name: name.to_string(), category: category.to_string(), version });
+    }
+}
+
+/// The framework error page served for a path that does not exist.
+struct ErrorPage {
+    tech_name: &'static str,
+    category: &'static str,
+    version: Option<String>,
+    /// Whether the page exposes debug details: a debug page, or any page with a stack trace.
+    debug: bool,
+}
+
+/// Requests a random path that does not exist and fingerprints the error page served for it.
+///
+/// Returns `None` when the request fails, when no signature matches, and when the path is
+/// answered with a success: such a catch-all (e.g. a single-page app serving its index for
+/// every path) shows its normal page, not an error page.
+async fn probe_error_page(client: &reqwest::Client, page_url: &reqwest::Url, throttle: &Throttle) -> Option<ErrorPage> {
+    let url = page_url.join(&nonexistent_path()).ok()?;
+    throttle.wait().await;
+    let response = match client.get(url).send().await {
+        Ok(response) => response,
+        Err(e) => {
+            debug!(error = %e, "Error page probe request failed.");
+            return None;
+        }
+    };
+    let status = response.status();
+    if status.is_success() {
+        debug!(status = %status, "Every path is served, skipping the error page checks.");
+        return None;
+    }
+    let body = response.text().await.ok()?;
+    let error_page = match_error_page(&body);
+    debug!(status = %status, tech = ?error_page.as_ref().map(|page| page.tech_name), "Error page probe finished.");
+    error_page
+}
+
+/// A random path no site serves on purpose.
+fn nonexistent_path() -> String {
+    format!("/vanguard-{}", chrono::Utc::now().timestamp_nanos_opt().unwrap_or_default())
+}
+
+/// Whether the site answers a path that does not exist with a success, as a catch-all does.
+/// A failed request counts as no.
+async fn serves_every_path(client: &reqwest::Client, page_url: &reqwest::Url, throttle: &Throttle) -> bool {
+    let Ok(url) = page_url.join(&nonexistent_path()) else { return false };
+    throttle.wait().await;
+    client.get(url).send().await.is_ok_and(|response| response.status().is_success())
+}
+
+/// Requests the admin panels of the detected technologies and reports each one reachable
+/// as `HTTP_ADMIN_PANEL_EXPOSED`, with its URL as the subject.
+///
+/// A panel counts as reachable when its path is answered with a success and a page matching
+/// its signature. Nothing is reported for a catch-all site, which would match any path.
+async fn find_admin_panels(client: &reqwest::Client, page_url: &reqwest::Url, throttle: &Throttle, technologies: &[Technology]) -> Vec<AnalysisFinding> {
+    let panels: Vec<&AdminPanel> = ADMIN_PANELS.iter()
+        .filter(|panel| panel.tech_name.is_none_or(|name| technologies.iter().any(|tech| tech.name == name)))
+        .collect();
+    if serves_every_path(client, page_url, throttle).await {
+        debug!("Every path is served, skipping the admin panel checks.");
+        return Vec::new();
+    }
+
+    let mut findings = Vec::new();
+    let mut found_urls: Vec<String> = Vec::new();
+    for panel in panels {
+        let Ok(url) = page_url.join(panel.path) else { continue };
+        throttle.wait().await;
+        let response = match client.get(url).send().await {
+            Ok(response) => response,
+            Err(e) => {
+                debug!(path = panel.path, error = %e, "Admin panel probe request failed.");
+                continue;
+            }
+        };
+        // Redirects were followed; two paths leading to the same login page are one panel.
+        let panel_url = response.url().to_string();
+        if !response.status().is_success() || found_urls.contains(&panel_url) {
+            continue;
+        }
+        let Ok((body, _)) = read_body_capped(response, PANEL_BODY_LIMIT).await else { continue };
+        if panel.pattern.is_match(&String::from_utf8_lossy(&body)) {
+            debug!(url = %panel_url, "Admin panel reachable, adding HTTP_ADMIN_PANEL_EXPOSED finding.");
+            findings.push(AnalysisFinding::new(panel.severity.clone(), "HTTP_ADMIN_PANEL_EXPOSED").with_subject(&panel_url));
+            found_urls.push(panel_url);
+        }
+    }
+    findings
+}
+
+/// Matches an error page against `ERROR_PAGE_SIGNATURES`.
+fn match_error_page(body: &str) -> Option<ErrorPage> {
+    let (signature, version) = ERROR_PAGE_SIGNATURES.iter()
+        .find_map(|signature| check_with_regex(Some(body), signature.pattern).map(|version| (signature, version)))?;
+    Some(ErrorPage {
+        tech_name: signature.tech_name,
+        category: signature.category,
+        version,
+        debug: signature.debug || RE_STACK_TRACE.is_match(body),
+    })
+}
+
+/// Analyzes the detected technologies to generate security findings.
+///
+/// Currently this flags technologies whose detected version is end-of-life.
+///
+/// # Arguments
+/// * `technologies` - The technologies identified by the fingerprinting rules.
+///
+/// # Returns
+/// A vector of `AnalysisFinding` structs.
+fn analyze_fingerprint_results(technologies: &[Technology]) -> Vec<AnalysisFinding> {
+    let mut analyses = Vec::new();
+
+    // One finding per technology, so every report names what to upgrade.
+    for tech in technologies.iter().filter(|t| is_eol_version(t)) {
+        debug!(tech = %tech.name, version = ?tech.version, "End-of-life version detected, adding TECH_EOL_VERSION finding.");
+        let subject = format!("{} {}", tech.name, tech.version.as_deref().unwrap_or_default());
+        analyses.push(AnalysisFinding::new(Severity::Warning, "TECH_EOL_VERSION").with_subject(&subject));
+    }
+
+    analyses
+}
+
+/// Returns `true` if the technology's detected version falls in a known end-of-life range.
+/// Technologies without a parseable version are never flagged.
+pub fn is_eol_version(tech: &Technology) -> bool {
+    let Some(version) = tech.version.as_deref().and_then(parse_version) else { return false; };
+    EOL_RULES.iter()
+        .filter(|rule| rule.tech_name == tech.name)
+        .any(|rule| parse_version(rule.supported_from).is_some_and(|supported| version < supported))
+}
+
+/// Parses a dotted numeric version (e.g. "8.0.30") into its components.
+fn parse_version(version: &str) -> Option<Vec<u32>> {
+    let parts: Option<Vec<u32>> = version.split('.').filter(|p| !p.is_empty()).map(|p| p.parse().ok()).collect();
+    parts.filter(|p| !p.is_empty())
+}
+
 /// A helper function that applies a regex to an optional string slice.
 ///
 /// Returns `Some(version)` if the regex matches. The `version` itself is an `Option<String>`:
@@ -224,10 +579,10 @@ fn check_meta_tag(doc: &Html, name: &str, re: &Regex) -> Option<Option<String>>
 fn check_script_src(doc: &Html, re: &Regex) -> Option<Option<String>> {
     if let Ok(selector) = Selector::parse("script[src]") {
         for el in doc.select(&selector) {
-            if let Some(src) = el.value().attr("src") {
-                if let Some(version) = check_with_regex(Some(src), re) {
-                    return Some(version); // Return on first match.
-                }
+            if let Some(src) = el.value().attr("src")
+                && let Some(version) = check_with_regex(Some(src), re)
+            {
+                return Some(version); // Return on first match.
             }
         }
     }
@@ -238,12 +593,132 @@ fn check_script_src(doc: &Html, re: &Regex) -> Option<Option<String>> {
 fn check_link_href(doc: &Html, re: &Regex) -> Option<Option<String>> {
     if let Ok(selector) = Selector::parse("link[href]") {
         for el in doc.select(&selector) {
-            if let Some(href) = el.value().attr("href") {
-                if let Some(version) = check_with_regex(Some(href), re) {
-                    return Some(version); // Return on first match.
-                }
+            if let Some(href) = el.value().attr("href")
+                && let Some(version) = check_with_regex(Some(href), re)
+            {
+                return Some(version); // Return on first match.
             }
         }
     }
     None
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::scanner::http_client_builder;
+    use crate::core::scanner::test_support::{MockHttpServer, MockResponse, codes};
+    use std::time::Duration;
+
+    async fn scan(response: MockResponse) -> FingerprintResults {
+        let server = MockHttpServer::start(response).await;
+        let client = http_client_builder(false).build().unwrap();
+        fingerprint(&client, &server.page, &Throttle::new(Duration::ZERO), false, false, false, DEFAULT_BODY_LIMIT).await
+    }
+
+    fn technology(results: &FingerprintResults, name: &str) -> Option<Technology> {
+        results.technologies.as_ref().unwrap().iter().find(|tech| tech.name == name).cloned()
+    }
+
+    #[tokio::test]
+    async fn detects_the_server_and_its_version() {
+        let results = scan(MockResponse::html("<html></html>").with_header("Server", "nginx/1.25.3")).await;
+        let nginx = technology(&results, "Nginx").expect("Nginx detected");
+        assert_eq!(nginx.version.as_deref(), Some("1.25.3"));
+        assert!(results.analysis.is_empty());
+    }
+
+    #[tokio::test]
+    async fn reports_end_of_life_versions() {
+        let response = MockResponse::html("<html></html>")
+            .with_header("Server", "Apache/2.2.34 (Unix)")
+            .with_header("X-Powered-By", "PHP/7.4.33");
+        let results = scan(response).await;
+        assert_eq!(technology(&results, "Apache").and_then(|tech| tech.version).as_deref(), Some("2.2.34"));
+        assert_eq!(codes(&results.analysis), ["TECH_EOL_VERSION", "TECH_EOL_VERSION"]);
+        let mut subjects: Vec<&str> = results.analysis.iter().filter_map(|finding| finding.subject.as_deref()).collect();
+        subjects.sort();
+        assert_eq!(subjects, ["Apache 2.2.34", "PHP 7.4.33"]);
+    }
+
+    #[tokio::test]
+    async fn waf_challenge_page_is_not_analyzed() {
+        let response = MockResponse::html("<html><body>Sucuri WebSite Firewall - Access Denied</body></html>")
+            .with_status(403)
+            .with_header("Server", "Apache/2.2.34");
+        let results = scan(response).await;
+        assert_eq!(results.blocked_by_waf.as_deref(), Some("Sucuri"));
+        assert!(results.analysis.is_empty());
+    }
+
+    #[tokio::test]
+    async fn detects_the_stack_from_its_session_cookie_name_only() {
+        let response = MockResponse::html("<html></html>")
+            .with_header("Set-Cookie", "connect.sid=s%3Aabc; HttpOnly")
+            .with_header("Set-Cookie", "tracking=PHPSESSID");
+        let results = scan(response).await;
+        assert!(technology(&results, "Express").is_some());
+        assert!(technology(&results, "PHP").is_none());
+        assert!(is_known_session_cookie("asp.net_sessionid") && !is_known_session_cookie("tracking"));
+    }
+
+    #[tokio::test]
+    async fn quick_mode_only_applies_the_header_rules() {
+        let response = MockResponse::html(r#"<html><script src="/wp-includes/js/wp-embed.min.js"></script></html>"#)
+            .with_header("Server", "nginx/1.25.3")
+            .with_header("Set-Cookie", "PHPSESSID=abc");
+        let server = MockHttpServer::start(response).await;
+        let client = http_client_builder(false).build().unwrap();
+        let results = fingerprint(&client, &server.page, &Throttle::new(Duration::ZERO), true, false, false, DEFAULT_BODY_LIMIT).await;
+        assert!(results.headers_only);
+        assert!(technology(&results, "Nginx").is_some() && technology(&results, "PHP").is_some());
+        assert!(technology(&results, "WordPress").is_none());
+    }
+
+    #[tokio::test]
+    async fn reports_a_debug_error_page() {
+        let body = "<html><title>Page not found</title><p>You're seeing this error because you have <code>DEBUG = True</code> in your Django settings file.</p></html>";
+        let server = MockHttpServer::start(MockResponse::html(body).with_status(404)).await;
+        let client = http_client_builder(false).build().unwrap();
+        let results = fingerprint(&client, &server.page, &Throttle::new(Duration::ZERO), false, true, false, DEFAULT_BODY_LIMIT).await;
+        assert!(technology(&results, "Python/Django").is_some());
+        assert_eq!(codes(&results.analysis), ["HTTP_VERBOSE_ERROR"]);
+        assert_eq!(results.analysis[0].subject.as_deref(), Some("Python/Django"));
+    }
+
+    #[test]
+    fn tells_plain_error_pages_from_leaky_ones() {
+        let tomcat = match_error_page("<h3>Apache Tomcat/9.0.65</h3>").expect("Tomcat detected");
+        assert_eq!((tomcat.tech_name, tomcat.version.as_deref(), tomcat.debug), ("Apache Tomcat", Some("9.0.65"), false));
+        let spring = match_error_page("<h1>Whitelabel Error Page</h1><div>java.lang.IllegalStateException\n\tat com.example.Api.get(Api.java:42)</div>").unwrap();
+        assert!(spring.debug);
+        assert!(match_error_page("<h1>Not Found</h1>").is_none());
+    }
+
+    #[tokio::test]
+    async fn reports_the_admin_panels_of_the_detected_cms() {
+        let home = MockResponse::html(r#"<html><img src="/wp-content/logo.png"></html>"#);
+        let login = MockResponse::html(r#"<form name="loginform" id="loginform" action="/wp-login.php"></form>"#);
+        let not_found = MockResponse::html("<h1>Not Found</h1>").with_status(404);
+        let server = MockHttpServer::start_with_routes(not_found, vec![("/", home.clone()), ("/wp-login.php", login)]).await;
+        let client = http_client_builder(false).build().unwrap();
+        let results = fingerprint(&client, &server.page, &Throttle::new(Duration::ZERO), false, false, true, DEFAULT_BODY_LIMIT).await;
+        assert_eq!(codes(&results.analysis), ["HTTP_ADMIN_PANEL_EXPOSED"]);
+        assert!(results.analysis[0].subject.as_deref().is_some_and(|url| url.ends_with("/wp-login.php")));
+
+        // A site serving the same page for every path has no panel to tell apart.
+        let catch_all = MockHttpServer::start(MockResponse::html(r#"<html><img src="/wp-content/logo.png"><form id="loginform"></form></html>"#)).await;
+        let results = fingerprint(&client, &catch_all.page, &Throttle::new(Duration::ZERO), false, false, true, DEFAULT_BODY_LIMIT).await;
+        assert!(results.analysis.is_empty());
+    }
+
+    #[tokio::test]
+    async fn stops_reading_the_page_at_the_body_limit() {
+        let body = format!("<html>{}<img src=\"/wp-content/logo.png\"></html>", " ".repeat(4096));
+        let server = MockHttpServer::start(MockResponse::html(&body)).await;
+        let client = http_client_builder(false).build().unwrap();
+        let results = fingerprint(&client, &server.page, &Throttle::new(Duration::ZERO), false, false, false, 1024).await;
+        assert!(technology(&results, "WordPress").is_none());
+        assert!(technology(&scan(MockResponse::html(&body)).await, "WordPress").is_some());
+    }
+}
@@ -1,8 +1,45 @@
 // src/core/scanner/headers_scanner.rs
 
 use tracing::{debug, error, info, warn};
-use crate::core::models::{AnalysisFinding, HeaderData, HeadersResults, Severity, ScanResult};
-use reqwest::header::HeaderMap;
+use crate::core::models::{
+    AnalysisFinding, CustomHeaderCheck, CustomHeaderResult, HeaderData, HeadersResults, Severity, ScanResult, RAW_BODY_LIMIT,
+};
+use crate::core::scanner::{MainPage, ScanOptions, ScanSection, fetch_main_page, read_body_capped};
+use crate::core::scanner::pipeline::{ScanContext, Scanner};
+use crate::core::scanner::throttle::Throttle;
+use crate::core::scanner::waf;
+use crate::core::scanner::fingerprint_scanner::is_known_session_cookie;
+use chrono::{DateTime, Utc};
+use futures::future::BoxFuture;
+use reqwest::header::{ACCESS_CONTROL_ALLOW_ORIGIN, CONTENT_LENGTH, DATE, HeaderMap, ORIGIN, SET_COOKIE};
+use reqwest::{Method, Url};
+
+/// The header the TRACE probe sends; a server that echoes requests reflects it in the body.
+const TRACE_MARKER_HEADER: &str = "x-vanguard-trace";
+
+/// The foreign origin the CORS probe claims to come from. `example.org` is reserved for
+/// documentation, so no allow-list legitimately contains it.
+const CORS_PROBE_ORIGIN: &str = "https://cors-probe.example.org";
+
+/// Stands in for a header value that is not valid UTF-8: the header is present, but its
+/// content cannot be checked.
+pub(crate) const INVALID_UTF8_PLACEHOLDER: &str = "[Invalid UTF-8]";
+
+/// How much of the body `looks_like_html` reads to recognize an HTML document.
+const HTML_SNIFF_LIMIT: usize = 1024;
+
+/// How much of the body the headers scan downloads: the WAF signatures are searched furthest
+/// in, and the HTML sniff and the raw body view need less.
+const BODY_READ_LIMIT: usize = waf::BODY_SCAN_LIMIT;
+const _: () = assert!(BODY_READ_LIMIT >= HTML_SNIFF_LIMIT && BODY_READ_LIMIT >= RAW_BODY_LIMIT);
+
+/// Headers that may legitimately be sent more than once: every Content-Security-Policy is
+/// enforced, and Cache-Control lines combine into one directive list.
+const COMBINABLE_HEADERS: &[&str] = &["content-security-policy", "cache-control"];
+
+/// How many days a session cookie may live before it is reported as long-lived, unless
+/// `cookie_max_lifetime_days` is configured.
+pub const DEFAULT_COOKIE_MAX_LIFETIME_DAYS: u64 = 30;
 
 /// Checks for the presence and validity of a specific HTTP header in a `HeaderMap`.
 ///
@@ -14,61 +51,324 @@ use reqwest::header::HeaderMap;
 /// A `ScanResult<HeaderData>` which is `Ok(Some(HeaderData))` if the header is found,
 /// `Ok(None)` if it's not found, or `Err` in case of a lookup error (though this
 /// is less common with `reqwest`). It also handles non-UTF-8 header values gracefully.
+/// When the header is sent more than once, every value is kept in `all_values`.
 fn check_header(headers: &HeaderMap, name: &str) -> ScanResult<HeaderData> {
     debug!(header_name = name, "Checking for header.");
-    if let Some(value) = headers.get(name) {
-        match value.to_str() {
-            Ok(s) => {
-                debug!(header_name = name, value = s, "Header found.");
-                Ok(Some(HeaderData { value: s.to_string() }))
-            },
-            Err(_) => {
-                warn!(header_name = name, "Header found but contained invalid UTF-8.");
-                // Return a placeholder value to indicate presence without valid content.
-                Ok(Some(HeaderData { value: "[Invalid UTF-8]".to_string() }))
-            },
+    let values: Vec<String> = headers.get_all(name).iter()
+        .map(|value| value.to_str().map(str::to_string).unwrap_or_else(|_| {
+            warn!(header_name = name, "Header found but contained invalid UTF-8.");
+            // Use a placeholder value to indicate presence without valid content.
+            INVALID_UTF8_PLACEHOLDER.to_string()
+        }))
+        .collect();
+    match values.as_slice() {
+        [] => {
+            debug!(header_name = name, "Header not found.");
+            Ok(None)
+        }
+        [value] => {
+            debug!(header_name = name, value = %value, "Header found.");
+            Ok(Some(HeaderData { value: value.clone(), all_values: Vec::new() }))
+        }
+        [first, ..] => {
+            debug!(header_name = name, values = ?values, "Header sent more than once.");
+            Ok(Some(HeaderData { value: first.clone(), all_values: values.clone() }))
         }
-    } else {
-        debug!(header_name = name, "Header not found.");
-        Ok(None)
     }
 }
 
-/// Runs a scan for common security-related HTTP headers.
+/// Returns the names of the session-like cookies set by the response.
 ///
-/// This function sends an HTTP GET request to the target, retrieves the response headers,
-/// and then checks for the presence of HSTS, CSP, X-Frame-Options, and
-/// X-Content-Type-Options headers.
+/// A cookie counts as a session cookie when it is the default session cookie of a
+/// fingerprinted stack, or when its name contains "session" (e.g. "app_session").
+fn find_session_cookies(headers: &HeaderMap) -> Vec<String> {
+    headers.get_all(SET_COOKIE).iter()
+        .filter_map(|value| value.to_str().ok())
+        .filter_map(|cookie| cookie.split_once('=').map(|(name, _)| name.trim()))
+        .filter(|name| is_session_cookie_name(name))
+        .map(String::from)
+        .collect()
+}
+
+fn is_session_cookie_name(name: &str) -> bool {
+    is_known_session_cookie(name)
+        || name.to_ascii_lowercase().contains("session")
+}
+
+/// Returns the session-like cookies set to outlive `max_lifetime_days`, with their lifetime in days.
 ///
-/// # Arguments
-/// * `target` - The domain or IP address to scan.
+/// The lifetime comes from `Max-Age`, which takes precedence as in browsers, or else from `Expires`,
+/// measured from the response's `Date` (or now, without one). Cookies without either end with the
+/// browser session and are not reported; neither are "remember me" cookies, which are long-lived
+/// by design.
+fn find_long_lived_session_cookies(headers: &HeaderMap, max_lifetime_days: u64) -> Vec<(String, u64)> {
+    let now = headers.get(DATE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(parse_cookie_date)
+        .unwrap_or_else(Utc::now);
+    headers.get_all(SET_COOKIE).iter()
+        .filter_map(|value| value.to_str().ok())
+        .filter_map(|cookie| {
+            let mut parts = cookie.split(';');
+            let name = parts.next()?.split_once('=')?.0.trim();
+            if !is_session_cookie_name(name) || name.to_ascii_lowercase().contains("remember") {
+                return None;
+            }
+            let (mut max_age, mut expires) = (None, None);
+            for attribute in parts {
+                let Some((key, value)) = attribute.split_once('=') else { continue };
+                match key.trim().to_ascii_lowercase().as_str() {
+                    "max-age" => max_age = value.trim().parse::<i64>().ok(),
+                    "expires" => expires = parse_cookie_date(value.trim()),
+                    _ => {}
+                }
+            }
+            let seconds = max_age.or_else(|| expires.map(|expires| (expires - now).num_seconds()))?;
+            let days = u64::try_from(seconds / 86_400).ok()?;
+            (days > max_lifetime_days).then(|| {
+                debug!(cookie = name, days, "Session cookie outlives the configured lifetime.");
+                (name.to_string(), days)
+            })
+        })
+        .collect()
+}
+
+/// Parses an HTTP date as found in `Expires` and `Date`, including the dashed form older
+/// servers send (e.g. "Wed, 21-Oct-2026 07:28:00 GMT").
+fn parse_cookie_date(value: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc2822(&value.replace('-', " ")).ok().map(|date| date.with_timezone(&Utc))
+}
+
+/// Returns the names of the prefixed cookies that break their prefix's rules, which makes browsers drop them.
 ///
-/// # Returns
-/// A `HeadersResults` struct containing the found headers and analysis findings.
-pub async fn run_headers_scan(target: &str) -> HeadersResults {
-    info!(target, "Starting headers scan.");
+/// `__Secure-` cookies must carry `Secure`; `__Host-` cookies must also have `Path=/` and no `Domain`.
+fn find_cookie_prefix_violations(headers: &HeaderMap) -> Vec<String> {
+    headers.get_all(SET_COOKIE).iter()
+        .filter_map(|value| value.to_str().ok())
+        .filter_map(|cookie| {
+            let mut parts = cookie.split(';');
+            let name = parts.next()?.split_once('=')?.0.trim();
+            let lowercase_name = name.to_ascii_lowercase();
+            let is_host = lowercase_name.starts_with("__host-");
+            if !is_host && !lowercase_name.starts_with("__secure-") {
+                return None;
+            }
 
-    let client = match reqwest::Client::builder()
-        .user_agent("VanguardRS/0.1")
-        .build()
-    {
-        Ok(c) => c,
+            let attributes: Vec<(String, &str)> = parts
+                .map(|attribute| match attribute.split_once('=') {
+                    Some((key, value)) => (key.trim().to_ascii_lowercase(), value.trim()),
+                    None => (attribute.trim().to_ascii_lowercase(), ""),
+                })
+                .collect();
+            let has = |key: &str| attributes.iter().any(|(k, _)| k == key);
+            let problem = if !has("secure") {
+                Some("missing the Secure attribute")
+            } else if is_host && has("domain") {
+                Some("sets a Domain attribute")
+            } else if is_host && !attributes.iter().any(|(k, v)| k == "path" && *v == "/") {
+                Some("not scoped to Path=/")
+            } else {
+                None
+            };
+            problem.map(|problem| {
+                debug!(cookie = name, problem, "Cookie breaks its prefix rules.");
+                name.to_string()
+            })
+        })
+        .collect()
+}
+
+/// Sends a `TRACE` request carrying a unique marker header and returns whether the marker
+/// was echoed in the response body, which confirms Cross-Site Tracing is possible.
+/// Servers that refuse the method (e.g. 405 Method Not Allowed) count as a clean pass.
+async fn probe_trace_reflection(client: &reqwest::Client, url: Url, throttle: &Throttle) -> Option<bool> {
+    throttle.wait().await;
+    let marker = format!("vanguard-{}", chrono::Utc::now().timestamp_nanos_opt().unwrap_or_default());
+    let response = match client.request(Method::TRACE, url).header(TRACE_MARKER_HEADER, &marker).send().await {
+        Ok(response) => response,
         Err(e) => {
-            // If the client cannot be built, it's a critical failure for this scan.
-            error!(error = %e, "Failed to build HTTP client for headers scan.");
-            let mut results = HeadersResults::default();
-            results.error = Some(format!("Failed to build HTTP client: {}", e));
-            results.analysis = analyze_headers_results(&results);
-            return results;
+            debug!(error = %e, "TRACE probe request failed.");
+            return None;
         }
     };
+    let status = response.status();
+    if !status.is_success() {
+        debug!(status = %status, "TRACE refused by the server.");
+        return Some(false);
+    }
+    let reflected = response.text().await.is_ok_and(|body| body.contains(&marker));
+    debug!(reflected, "TRACE probe finished.");
+    Some(reflected)
+}
 
-    let url = format!("https://{}", target);
+/// Requests the page again from a foreign `Origin` and returns whether the response allows
+/// that origin, which means any site can read the page through the visitor's browser.
+async fn probe_cors_reflection(client: &reqwest::Client, url: Url, throttle: &Throttle) -> Option<bool> {
+    throttle.wait().await;
+    let response = match client.get(url).header(ORIGIN, CORS_PROBE_ORIGIN).send().await {
+        Ok(response) => response,
+        Err(e) => {
+            debug!(error = %e, "CORS probe request failed.");
+            return None;
+        }
+    };
+    let reflected = response.headers().get_all(ACCESS_CONTROL_ALLOW_ORIGIN).iter()
+        .filter_map(|value| value.to_str().ok())
+        .any(|value| value.trim().eq_ignore_ascii_case(CORS_PROBE_ORIGIN));
+    debug!(reflected, "CORS probe finished.");
+    Some(reflected)
+}
 
-    match client.get(&url).send().await {
-        Ok(response) => {
+/// Returns whether a `Content-Type` value is JSON (`application/json` or a `+json` type),
+/// the mark of an API response.
+fn is_json_content_type(content_type: &str) -> bool {
+    let media_type = content_type.split(';').next().unwrap_or_default().trim().to_ascii_lowercase();
+    media_type == "application/json" || media_type.ends_with("+json")
+}
+
+/// Returns the protocols advertised by the `Alt-Svc` header (e.g. `h3=":443"; ma=86400`, for
+/// HTTP/3 over QUIC) and the `Upgrade` header (e.g. `h2c`, for cleartext HTTP/2), each tagged with
+/// its header, e.g. "h3 (Alt-Svc)". Alt-Svc's `clear`, which withdraws earlier ads, yields none.
+fn find_advertised_protocols(headers: &HeaderMap) -> Vec<String> {
+    let mut protocols: Vec<String> = Vec::new();
+    let mut add = |protocol: &str, header: &str| {
+        let entry = format!("{} ({})", protocol.trim().to_ascii_lowercase(), header);
+        if !protocol.trim().is_empty() && !protocols.contains(&entry) {
+            protocols.push(entry);
+        }
+    };
+    for value in headers.get_all("alt-svc").iter().filter_map(|value| value.to_str().ok()) {
+        // Each alternative is `protocol-id="authority"` followed by `; parameters`.
+        for alternative in value.split(',') {
+            if let Some((protocol, _)) = alternative.split(';').next().unwrap_or_default().split_once('=') {
+                add(protocol, "Alt-Svc");
+            }
+        }
+    }
+    for value in headers.get_all("upgrade").iter().filter_map(|value| value.to_str().ok()) {
+        // Protocols may carry a version, e.g. `HTTP/2.0`.
+        for protocol in value.split(',') {
+            add(protocol, "Upgrade");
+        }
+    }
+    protocols
+}
+
+/// Returns every header as a (name, value) pair, sorted by name. Repeated headers such as
+/// `Set-Cookie` keep one entry per value, in the order the server sent them.
+fn collect_raw_headers(headers: &HeaderMap) -> Vec<(String, String)> {
+    let mut raw_headers: Vec<(String, String)> = headers.iter()
+        .map(|(name, value)| (name.to_string(), String::from_utf8_lossy(value.as_bytes()).into_owned()))
+        .collect();
+    raw_headers.sort_by(|a, b| a.0.cmp(&b.0));
+    raw_headers
+}
+
+/// Returns whether a response body looks like an HTML document, judging by its first bytes.
+fn looks_like_html(body: &[u8]) -> bool {
+    let start = String::from_utf8_lossy(&body[..body.len().min(HTML_SNIFF_LIMIT)]).to_ascii_lowercase();
+    let start = start.trim_start();
+    start.starts_with("<!doctype html") || start.contains("<html")
+}
+
+/// Returns whether a Content-Security-Policy sets `frame-ancestors`, the directive that
+/// supersedes X-Frame-Options in browsers that support CSP Level 2.
+fn csp_has_frame_ancestors(csp: &str) -> bool {
+    csp.split(';')
+        .filter_map(|directive| directive.split_whitespace().next())
+        .any(|name| name.eq_ignore_ascii_case("frame-ancestors"))
+}
+
+/// Runs a scan for common security-related HTTP headers.
+///
+/// This function sends an HTTP GET request to the target, retrieves the response headers,
+/// and then checks for the presence of HSTS, CSP, X-Frame-Options, and
+/// X-Content-Type-Options headers, as well as the deprecated X-XSS-Protection header.
+/// It also records the caching policy and any session cookies, which are judged together,
+/// and the `Content-Type` of the response, which is checked against the body it labels.
+/// Any `custom_headers` from the configuration are checked the same way.
+/// When `probe_trace` is set, a `TRACE` request is also sent to test for Cross-Site Tracing,
+/// and when `probe_cors` is set, a request from a foreign `Origin` tests whether CORS reflects it.
+/// If the target cannot be reached over HTTPS, the headers are read over plain HTTP instead
+/// and the site is flagged as HTTP-only.
+///
+/// The options come from the context: `custom_headers`, `active_probes` (the TRACE and CORS probes),
+/// `cookie_max_lifetime_days`, and `accept_invalid_certs` through the shared HTTP client.
+pub struct HeadersScanner;
+
+impl Scanner for HeadersScanner {
+    fn name(&self) -> &'static str {
+        "Headers"
+    }
+
+    fn scan<'a>(&'a self, ctx: &'a ScanContext) -> BoxFuture<'a, ScanSection> {
+        Box::pin(async move {
+            if !self.enabled(&ctx.options) {
+                return ScanSection::Headers(HeadersResults { disabled: true, ..Default::default() });
+            }
+            info!(target = %ctx.target, "Starting headers scan.");
+            let client = match &ctx.http_client {
+                Ok(client) => client,
+                Err(e) => {
+                    // Without a client, there is nothing to scan.
+                    let mut results = HeadersResults::default();
+                    results.error = Some(format!("Failed to build HTTP client: {}", e));
+                    results.analysis = analyze_headers_results(&results);
+                    return ScanSection::Headers(results);
+                }
+            };
+            let options = &ctx.options;
+            let cookie_max_lifetime_days = options.cookie_max_lifetime_days.unwrap_or(DEFAULT_COOKIE_MAX_LIFETIME_DAYS);
+            let results = scan_headers(client, &ctx.page, &options.custom_headers, options.active_probes, options.active_probes, cookie_max_lifetime_days, &ctx.throttle).await;
+            ScanSection::Headers(results)
+        })
+    }
+
+    fn enabled(&self, options: &ScanOptions) -> bool {
+        options.scanners.scan_headers
+    }
+
+    fn planned_requests(&self, target: &str, options: &ScanOptions) -> Vec<String> {
+        let page = MainPage::of(target);
+        let mut requests = vec![page.planned_request(&Method::GET)];
+        if options.active_probes {
+            requests.push(format!("TRACE {}", page.https_url));
+            requests.push(format!("GET {} with Origin: {}", page.https_url, CORS_PROBE_ORIGIN));
+        }
+        requests
+    }
+}
+
+/// Runs the headers scan with the given client against the given main page.
+///
+/// `HeadersScanner` passes the context's; tests pass their own to reach a local server.
+pub(crate) async fn scan_headers(client: &reqwest::Client, page: &MainPage, custom_headers: &[CustomHeaderCheck], probe_trace: bool, probe_cors: bool, cookie_max_lifetime_days: u64, throttle: &Throttle) -> HeadersResults {
+    match fetch_main_page(client, page, throttle).await {
+        Ok((response, http_only)) => {
             info!(status = %response.status(), "Received HTTP response for headers scan.");
-            let headers = response.headers();
+            // Keep the headers: reading the body below consumes the response.
+            let headers = &response.headers().clone();
+            let url = response.url().clone();
+            let request = page.request_details(&Method::GET, http_only, Some(&url));
+            let status = response.status();
+            // The body is sniffed to tell whether a generic content type hides an HTML page,
+            // and its start is kept for the raw response view. Nothing reads further than
+            // `BODY_READ_LIMIT`, so the rest of a large page is not downloaded.
+            let content_length = headers.get(CONTENT_LENGTH)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.parse::<usize>().ok());
+            let (body, body_cut) = match read_body_capped(response, BODY_READ_LIMIT).await {
+                Ok((body, cut)) => (Some(body), cut),
+                Err(e) => {
+                    warn!(error = %e, "Could not read the response body for headers scan.");
+                    (None, false)
+                }
+            };
+            let html_body = body.as_deref().is_some_and(looks_like_html);
+            let blocked_by_waf = waf::detect_block(status, headers, body.as_deref().unwrap_or_default());
+            if let Some(waf) = blocked_by_waf {
+                warn!(url = %url, waf, status = %status, "The request was answered by a WAF challenge page.");
+            }
             // Check for each of the target security headers.
             let mut results = HeadersResults {
                 error: None,
@@ -76,7 +376,37 @@ pub async fn run_headers_scan(target: &str) -> HeadersResults {
                 csp: check_header(headers, "content-security-policy"),
                 x_frame_options: check_header(headers, "x-frame-options"),
                 x_content_type_options: check_header(headers, "x-content-type-options"),
+                x_xss_protection: check_header(headers, "x-xss-protection"),
+                expect_ct: check_header(headers, "expect-ct"),
+                cache_control: check_header(headers, "cache-control"),
+                session_cookies: find_session_cookies(headers),
+                long_lived_session_cookies: find_long_lived_session_cookies(headers, cookie_max_lifetime_days),
+                cookie_prefix_violations: find_cookie_prefix_violations(headers),
+                trace_reflected: if probe_trace { probe_trace_reflection(client, url.clone(), throttle).await } else { None },
+                content_type: check_header(headers, "content-type"),
+                access_control_allow_origin: check_header(headers, "access-control-allow-origin"),
+                cors_origin_reflected: if probe_cors { probe_cors_reflection(client, url, throttle).await } else { None },
+                html_body,
+                http_only,
+                custom_headers: custom_headers.iter()
+                    .map(|check| CustomHeaderResult {
+                        name: check.name.clone(),
+                        severity: check.severity.clone(),
+                        header: check_header(headers, &check.name.to_ascii_lowercase()),
+                    })
+                    .collect(),
+                advertised_protocols: find_advertised_protocols(headers),
+                request: Some(request),
+                raw_headers: collect_raw_headers(headers),
+                raw_body: body.as_deref().map(|body| String::from_utf8_lossy(&body[..body.len().min(RAW_BODY_LIMIT)]).into_owned()),
+                raw_body_size: match (body.as_deref(), content_length) {
+                    (Some(_), Some(length)) if body_cut => length,
+                    (body, _) => body.map_or(0, <[u8]>::len),
+                },
+                raw_body_size_unknown: body_cut && content_length.is_none(),
+                blocked_by_waf: blocked_by_waf.map(String::from),
                 analysis: Vec::new(),
+                disabled: false,
             };
             results.analysis = analyze_headers_results(&results);
             info!(findings = %results.analysis.len(), "Headers scan finished.");
@@ -84,15 +414,42 @@ pub async fn run_headers_scan(target: &str) -> HeadersResults {
         }
         Err(e) => {
             // If the HTTP request fails, populate the error field and analyze.
-            error!(url = %url, error = %e, "HTTP request failed for headers scan.");
+            error!(url = %page.https_url, error = %e, "HTTP request failed for headers scan.");
             let mut results = HeadersResults::default();
             results.error = Some(format!("HTTP request failed: {}", e));
+            results.request = Some(page.request_details(&Method::GET, false, None));
             results.analysis = analyze_headers_results(&results);
             results
         }
     }
 }
 
+/// The captured header, when it is present and every value it was sent with is valid UTF-8.
+/// Checks of a header's content go through this, so a placeholder is never checked as a value.
+fn readable(header: &ScanResult<HeaderData>) -> Option<&HeaderData> {
+    header.as_ref().ok()?.as_ref().filter(|data| !has_invalid_utf8(data))
+}
+
+/// Whether any value of the header was not valid UTF-8 (see `INVALID_UTF8_PLACEHOLDER`).
+pub(crate) fn has_invalid_utf8(data: &HeaderData) -> bool {
+    data.value == INVALID_UTF8_PLACEHOLDER || data.all_values.iter().any(|value| value == INVALID_UTF8_PLACEHOLDER)
+}
+
+/// The captured headers, with their display names, including the configured custom ones.
+pub(crate) fn checked_headers(results: &HeadersResults) -> impl Iterator<Item = (&str, &ScanResult<HeaderData>)> {
+    [
+        ("Strict-Transport-Security", &results.hsts),
+        ("Content-Security-Policy", &results.csp),
+        ("X-Frame-Options", &results.x_frame_options),
+        ("X-Content-Type-Options", &results.x_content_type_options),
+        ("X-XSS-Protection", &results.x_xss_protection),
+        ("Expect-CT", &results.expect_ct),
+        ("Cache-Control", &results.cache_control),
+        ("Content-Type", &results.content_type),
+        ("Access-Control-Allow-Origin", &results.access_control_allow_origin),
+    ].into_iter().chain(results.custom_headers.iter().map(|custom| (custom.name.as_str(), &custom.header)))
+}
+
 /// Analyzes the collected header data to generate security findings.
 ///
 /// This function checks for the absence of key security headers and creates findings
@@ -114,6 +471,20 @@ fn analyze_headers_results(results: &HeadersResults) -> Vec<AnalysisFinding> {
         return analyses;
     }
 
+    // Without TLS, everything sent to and from the site can be read and altered in transit.
+    if results.http_only {
+        debug!("Site only reachable over plain HTTP, adding HTTP_ONLY_SITE finding.");
+        analyses.push(AnalysisFinding::new(Severity::Critical, "HTTP_ONLY_SITE"));
+    }
+
+    // A WAF challenge page does not carry the site's headers, so checking them would only
+    // report the WAF's configuration as the site's.
+    if let Some(waf) = &results.blocked_by_waf {
+        debug!(waf = %waf, "Blocked by a WAF, adding SCAN_BLOCKED_BY_WAF instead of the header checks.");
+        analyses.push(AnalysisFinding::new(Severity::Info, "SCAN_BLOCKED_BY_WAF").with_subject(waf));
+        return analyses;
+    }
+
     // Check for missing HSTS header.
     if let Ok(None) = &results.hsts {
         debug!("HSTS header missing, adding Warning finding.");
@@ -126,10 +497,25 @@ fn analyze_headers_results(results: &HeadersResults) -> Vec<AnalysisFinding> {
         analyses.push(AnalysisFinding::new(Severity::Warning, "HEADERS_CSP_MISSING"));
     }
 
+    // CSP frame-ancestors takes precedence over X-Frame-Options, so when it is set, a missing
+    // or unusable X-Frame-Options only matters to browsers too old to support it.
+    let frame_ancestors = readable(&results.csp).is_some_and(|csp| csp_has_frame_ancestors(&csp.value));
+    let framing_severity = if frame_ancestors { Severity::Info } else { Severity::Warning };
+
     // Check for missing X-Frame-Options header.
     if let Ok(None) = &results.x_frame_options {
-        debug!("X-Frame-Options header missing, adding Warning finding.");
-        analyses.push(AnalysisFinding::new(Severity::Warning, "HEADERS_X_FRAME_OPTIONS_MISSING"));
+        debug!(frame_ancestors, "X-Frame-Options header missing, adding finding.");
+        analyses.push(AnalysisFinding::new(framing_severity.clone(), "HEADERS_X_FRAME_OPTIONS_MISSING"));
+    }
+
+    // Only DENY and SAMEORIGIN are honored: browsers ignore ALLOW-FROM and any other value,
+    // leaving the page as frameable as if the header were missing.
+    if let Some(xfo) = readable(&results.x_frame_options) {
+        let value = xfo.value.trim();
+        if !value.eq_ignore_ascii_case("DENY") && !value.eq_ignore_ascii_case("SAMEORIGIN") {
+            debug!(value = %xfo.value, frame_ancestors, "Invalid X-Frame-Options value, adding HEADERS_XFO_INVALID finding.");
+            analyses.push(AnalysisFinding::new(framing_severity, "HEADERS_XFO_INVALID").with_subject(value));
+        }
     }
 
     // Check for missing X-Content-Type-Options header.
@@ -138,5 +524,286 @@ fn analyze_headers_results(results: &HeadersResults) -> Vec<AnalysisFinding> {
         analyses.push(AnalysisFinding::new(Severity::Info, "HEADERS_X_CONTENT_TYPE_OPTIONS_MISSING"));
     }
 
+    // Headers required by the configuration share one generic finding, named by its subject.
+    for custom in &results.custom_headers {
+        if let Ok(None) = &custom.header {
+            debug!(header_name = %custom.name, "Configured header missing, adding HEADERS_CUSTOM_MISSING finding.");
+            analyses.push(AnalysisFinding::new(custom.severity.clone(), "HEADERS_CUSTOM_MISSING").with_subject(&custom.name));
+        }
+    }
+
+    // A header sent twice with different values is combined or picked from differently by each
+    // browser, so which policy applies is unpredictable. Identical repeats apply the same policy
+    // whichever is picked, and only point at a header set in two places.
+    for (name, header) in checked_headers(results) {
+        if let Some(data) = readable(header)
+            && data.all_values.len() > 1
+            && !COMBINABLE_HEADERS.iter().any(|combinable| combinable.eq_ignore_ascii_case(name))
+        {
+            if data.all_values.iter().all(|value| value.trim() == data.value.trim()) {
+                debug!(header_name = name, count = data.all_values.len(), "Identical header repeated, adding HEADERS_REPEATED finding.");
+                let subject = format!("{} (sent {} times)", name, data.all_values.len());
+                analyses.push(AnalysisFinding::new(Severity::Info, "HEADERS_REPEATED").with_subject(&subject));
+            } else {
+                debug!(header_name = name, values = ?data.all_values, "Conflicting header values, adding HEADERS_DUPLICATE finding.");
+                let subject = format!("{} ({})", name, data.all_values.join(" | "));
+                analyses.push(AnalysisFinding::new(Severity::Warning, "HEADERS_DUPLICATE").with_subject(&subject));
+            }
+        }
+    }
+
+    // A missing or generic Content-Type leaves the browser to guess (sniff) the type, which
+    // X-Content-Type-Options: nosniff is meant to prevent.
+    match (&results.content_type, readable(&results.content_type)) {
+        (Ok(None), _) => {
+            debug!("Content-Type header missing, adding Info finding.");
+            analyses.push(AnalysisFinding::new(Severity::Info, "HTTP_MISSING_CONTENT_TYPE"));
+        }
+        (_, Some(content_type)) if results.html_body => {
+            let media_type = content_type.value.split(';').next().unwrap_or_default().trim();
+            if media_type.eq_ignore_ascii_case("application/octet-stream") {
+                debug!("HTML page served as application/octet-stream, adding Info finding.");
+                analyses.push(AnalysisFinding::new(Severity::Info, "HTTP_GENERIC_CONTENT_TYPE"));
+            }
+        }
+        _ => {}
+    }
+
+    // Flag the deprecated X-XSS-Protection header when it enables the legacy filter.
+    // Its absence is the modern recommendation, so a missing header is not reported, and
+    // neither is an unreadable value, which may well be "0".
+    if let Some(xss) = readable(&results.x_xss_protection)
+        && !xss.value.trim().starts_with('0')
+    {
+        debug!(value = %xss.value, "Legacy X-XSS-Protection enabled, adding Info finding.");
+        analyses.push(AnalysisFinding::new(Severity::Info, "HEADERS_XSS_PROTECTION_LEGACY"));
+    }
+
+    // Expect-CT has no effect anymore, so its presence is only worth a cleanup note;
+    // its absence is the modern recommendation and is not reported.
+    if let Ok(Some(expect_ct)) = &results.expect_ct {
+        debug!(value = %expect_ct.value, "Obsolete Expect-CT header present, adding Info finding.");
+        analyses.push(AnalysisFinding::new(Severity::Info, "HEADERS_EXPECT_CT_OBSOLETE"));
+    }
+
+    // A response that sets a session cookie must not be stored by shared or browser caches.
+    // An unreadable Cache-Control may well forbid it, so it is given the benefit of the doubt.
+    if !results.session_cookies.is_empty() {
+        let uncacheable = match &results.cache_control {
+            Ok(Some(cache_control)) if has_invalid_utf8(cache_control) => true,
+            Ok(Some(cache_control)) => cache_control.value.split(',')
+                .map(|directive| directive.trim().to_ascii_lowercase())
+                .any(|directive| directive == "no-store" || directive == "private"),
+            _ => false,
+        };
+        if !uncacheable {
+            debug!(cookies = ?results.session_cookies, "Session cookie on a cacheable response, adding HTTP_SENSITIVE_CACHEABLE finding.");
+            analyses.push(AnalysisFinding::new(Severity::Warning, "HTTP_SENSITIVE_CACHEABLE"));
+        }
+    }
+
+    // An echoed TRACE request lets a script read headers it cannot normally access, such as HttpOnly cookies.
+    if results.trace_reflected == Some(true) {
+        debug!("TRACE echoed the marker header, adding HTTP_XST finding.");
+        analyses.push(AnalysisFinding::new(Severity::Warning, "HTTP_XST"));
+    }
+
+    // A wildcard lets any site read the response from a visitor's browser. That is fine for
+    // public content, but a JSON response is an API, whose data is rarely meant for every site.
+    if let (Some(acao), Some(content_type)) = (readable(&results.access_control_allow_origin), readable(&results.content_type))
+        && acao.value.trim() == "*"
+        && is_json_content_type(&content_type.value)
+    {
+        debug!(content_type = %content_type.value, "Wildcard CORS on a JSON response, adding HTTP_CORS_WILDCARD finding.");
+        analyses.push(AnalysisFinding::new(Severity::Warning, "HTTP_CORS_WILDCARD"));
+    }
+
+    // An echoed origin is a wildcard that also works with credentials, which a real `*` does not.
+    if results.cors_origin_reflected == Some(true) {
+        debug!("CORS allowed the probe's foreign origin, adding HTTP_CORS_REFLECTED_ORIGIN finding.");
+        analyses.push(AnalysisFinding::new(Severity::Critical, "HTTP_CORS_REFLECTED_ORIGIN"));
+    }
+
+    // Newer protocols are a note on the infrastructure, not an issue either way.
+    if !results.advertised_protocols.is_empty() {
+        debug!(protocols = ?results.advertised_protocols, "Protocols advertised, adding Info finding.");
+        analyses.push(AnalysisFinding::new(Severity::Info, "HTTP_PROTOCOLS_ADVERTISED").with_subject(&results.advertised_protocols.join(", ")));
+    }
+
+    // Browsers reject prefixed cookies that break their prefix's rules, so the protection never applies.
+    for cookie in &results.cookie_prefix_violations {
+        debug!(cookie = %cookie, "Prefixed cookie breaks its rules, adding COOKIE_PREFIX_VIOLATION finding.");
+        analyses.push(AnalysisFinding::new(Severity::Warning, "COOKIE_PREFIX_VIOLATION").with_subject(cookie));
+    }
+
+    // A session cookie that survives for months widens the window in which a stolen one can be replayed.
+    for (cookie, days) in &results.long_lived_session_cookies {
+        debug!(cookie = %cookie, days, "Long-lived session cookie, adding COOKIE_LONG_LIVED finding.");
+        analyses.push(AnalysisFinding::new(Severity::Info, "COOKIE_LONG_LIVED").with_subject(&format!("{} ({} days)", cookie, days)));
+    }
+
     analyses
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::scanner::http_client_builder;
+    use crate::core::scanner::test_support::{MockHttpServer, MockResponse, codes};
+    use std::time::Duration;
+
+    async fn scan(response: MockResponse) -> HeadersResults {
+        let server = MockHttpServer::start(response).await;
+        let client = http_client_builder(false).build().unwrap();
+        scan_headers(&client, &server.page, &[], false, false, DEFAULT_COOKIE_MAX_LIFETIME_DAYS, &Throttle::new(Duration::ZERO)).await
+    }
+
+    fn hardened(response: MockResponse) -> MockResponse {
+        response
+            .with_header("Strict-Transport-Security", "max-age=31536000; includeSubDomains")
+            .with_header("Content-Security-Policy", "default-src 'self'")
+            .with_header("X-Frame-Options", "DENY")
+            .with_header("X-Content-Type-Options", "nosniff")
+    }
+
+    #[tokio::test]
+    async fn reads_only_the_start_of_a_large_body() {
+        let page = format!("<html>{}</html>", "x".repeat(BODY_READ_LIMIT * 4));
+        let results = scan(MockResponse::html(&page)).await;
+        assert_eq!(results.raw_body.as_deref().map(str::len), Some(RAW_BODY_LIMIT));
+        // The size still comes from the Content-Length of the response.
+        assert_eq!(results.raw_body_size, page.len());
+        assert!(!results.raw_body_size_unknown && results.html_body);
+    }
+
+    #[tokio::test]
+    async fn reports_each_missing_security_header() {
+        let results = scan(MockResponse::html("<html></html>")).await;
+        assert_eq!(codes(&results.analysis), [
+            "HEADERS_HSTS_MISSING",
+            "HEADERS_CSP_MISSING",
+            "HEADERS_X_FRAME_OPTIONS_MISSING",
+            "HEADERS_X_CONTENT_TYPE_OPTIONS_MISSING",
+        ]);
+        assert!(!results.http_only);
+    }
+
+    #[tokio::test]
+    async fn hardened_response_has_no_findings() {
+        let results = scan(hardened(MockResponse::html("<html></html>"))).await;
+        assert!(results.analysis.is_empty(), "unexpected findings: {:?}", codes(&results.analysis));
+        assert_eq!(results.x_frame_options.unwrap().unwrap().value, "DENY");
+    }
+
+    #[tokio::test]
+    async fn reports_a_header_sent_twice() {
+        let results = scan(hardened(MockResponse::html("<html></html>")).with_header("X-Frame-Options", "SAMEORIGIN")).await;
+        assert_eq!(codes(&results.analysis), ["HEADERS_DUPLICATE"]);
+        assert_eq!(results.analysis[0].subject.as_deref(), Some("X-Frame-Options (DENY | SAMEORIGIN)"));
+
+        let results = scan(hardened(MockResponse::html("<html></html>")).with_header("X-Frame-Options", "DENY")).await;
+        assert_eq!(codes(&results.analysis), ["HEADERS_REPEATED"]);
+        assert_eq!(results.analysis[0].severity, Severity::Info);
+        assert_eq!(results.analysis[0].subject.as_deref(), Some("X-Frame-Options (sent 2 times)"));
+    }
+
+    #[tokio::test]
+    async fn reports_session_cookie_on_cacheable_response() {
+        let response = hardened(MockResponse::html("<html></html>")).with_header("Set-Cookie", "PHPSESSID=abc; Secure; HttpOnly");
+        assert_eq!(codes(&scan(response.clone()).await.analysis), ["HTTP_SENSITIVE_CACHEABLE"]);
+        assert!(scan(response.with_header("Cache-Control", "no-store")).await.analysis.is_empty());
+    }
+
+    #[tokio::test]
+    async fn only_reports_a_readable_x_xss_protection_that_enables_the_filter() {
+        let response = hardened(MockResponse::html("<html></html>"));
+        assert_eq!(codes(&scan(response.clone().with_header("X-XSS-Protection", "1; mode=block")).await.analysis), ["HEADERS_XSS_PROTECTION_LEGACY"]);
+        assert!(scan(response.clone().with_header("X-XSS-Protection", "0")).await.analysis.is_empty());
+        // reqwest only reads visible ASCII as text, so the encoded "É" makes the value unreadable.
+        assert!(scan(response.with_header("X-XSS-Protection", "1; mÉde=block")).await.analysis.is_empty());
+    }
+
+    #[tokio::test]
+    async fn reports_session_cookies_that_outlive_the_limit() {
+        let response = hardened(MockResponse::html("<html></html>"))
+            .with_header("Cache-Control", "no-store")
+            .with_header("Date", "Fri, 16 Oct 2026 12:00:00 GMT")
+            .with_header("Set-Cookie", "PHPSESSID=a; Max-Age=31536000; Expires=Sat, 17 Oct 2026 12:00:00 GMT")
+            .with_header("Set-Cookie", "app_session=b; Expires=Sun, 15-Nov-2026 12:00:00 GMT")
+            .with_header("Set-Cookie", "remember_session=c; Max-Age=31536000")
+            .with_header("Set-Cookie", "theme=dark; Max-Age=31536000")
+            .with_header("Set-Cookie", "sessionid=d; Max-Age=86400");
+        let results = scan(response).await;
+        assert_eq!(results.long_lived_session_cookies, [("PHPSESSID".to_string(), 365)]);
+        assert_eq!(codes(&results.analysis), ["COOKIE_LONG_LIVED"]);
+        assert_eq!(results.analysis[0].subject.as_deref(), Some("PHPSESSID (365 days)"));
+        assert_eq!(find_long_lived_session_cookies(&HeaderMap::new(), 0), []);
+        let mut headers = HeaderMap::new();
+        headers.insert(DATE, "Fri, 16 Oct 2026 12:00:00 GMT".parse().unwrap());
+        headers.insert(SET_COOKIE, "app_session=b; Expires=Sun, 15-Nov-2026 12:00:00 GMT".parse().unwrap());
+        assert_eq!(find_long_lived_session_cookies(&headers, 7), [("app_session".to_string(), 30)]);
+    }
+
+    #[tokio::test]
+    async fn reports_an_x_frame_options_value_browsers_ignore() {
+        let response = MockResponse::html("<html></html>")
+            .with_header("Strict-Transport-Security", "max-age=31536000")
+            .with_header("Content-Security-Policy", "default-src 'self'")
+            .with_header("X-Frame-Options", "ALLOW-FROM https://partner.example")
+            .with_header("X-Content-Type-Options", "nosniff");
+        let results = scan(response).await;
+        assert_eq!(codes(&results.analysis), ["HEADERS_XFO_INVALID"]);
+        assert_eq!(results.analysis[0].severity, Severity::Warning);
+        assert_eq!(results.analysis[0].subject.as_deref(), Some("ALLOW-FROM https://partner.example"));
+    }
+
+    #[tokio::test]
+    async fn frame_ancestors_downgrades_missing_x_frame_options() {
+        let response = MockResponse::html("<html></html>")
+            .with_header("Strict-Transport-Security", "max-age=31536000")
+            .with_header("Content-Security-Policy", "default-src 'self'; frame-ancestors 'self'")
+            .with_header("X-Content-Type-Options", "nosniff");
+        let results = scan(response).await;
+        assert_eq!(codes(&results.analysis), ["HEADERS_X_FRAME_OPTIONS_MISSING"]);
+        assert_eq!(results.analysis[0].severity, Severity::Info);
+    }
+
+    #[tokio::test]
+    async fn wildcard_cors_is_only_reported_on_json() {
+        let json = hardened(MockResponse::json("{}")).with_header("Access-Control-Allow-Origin", "*");
+        assert!(codes(&scan(json).await.analysis).contains(&"HTTP_CORS_WILDCARD"));
+        let html = hardened(MockResponse::html("<html></html>")).with_header("Access-Control-Allow-Origin", "*");
+        assert!(scan(html).await.analysis.is_empty());
+    }
+
+    #[tokio::test]
+    async fn cors_probe_reports_a_reflected_origin() {
+        // The mock answers every request alike, so allowing the probe's origin stands in for echoing it.
+        let server = MockHttpServer::start(hardened(MockResponse::html("<html></html>")).with_header("Access-Control-Allow-Origin", CORS_PROBE_ORIGIN)).await;
+        let client = http_client_builder(false).build().unwrap();
+        let results = scan_headers(&client, &server.page, &[], false, true, DEFAULT_COOKIE_MAX_LIFETIME_DAYS, &Throttle::new(Duration::ZERO)).await;
+        assert_eq!(results.cors_origin_reflected, Some(true));
+        assert_eq!(codes(&results.analysis), ["HTTP_CORS_REFLECTED_ORIGIN"]);
+    }
+
+    #[tokio::test]
+    async fn waf_challenge_replaces_the_header_checks() {
+        let response = MockResponse::html("<html><head><title>Just a moment...</title></head></html>")
+            .with_status(403)
+            .with_header("cf-mitigated", "challenge");
+        let results = scan(response).await;
+        assert_eq!(results.blocked_by_waf.as_deref(), Some("Cloudflare"));
+        assert_eq!(codes(&results.analysis), ["SCAN_BLOCKED_BY_WAF"]);
+    }
+
+    #[tokio::test]
+    async fn notes_the_protocols_the_response_advertises() {
+        let response = hardened(MockResponse::html("<html></html>"))
+            .with_header("Alt-Svc", "h3=\":443\"; ma=86400, h3-29=\":443\"; ma=86400")
+            .with_header("Upgrade", "h2c");
+        let results = scan(response).await;
+        assert_eq!(results.advertised_protocols, ["h3 (Alt-Svc)", "h3-29 (Alt-Svc)", "h2c (Upgrade)"]);
+        assert_eq!(codes(&results.analysis), ["HTTP_PROTOCOLS_ADVERTISED"]);
+        assert!(scan(hardened(MockResponse::html("<html></html>")).with_header("Alt-Svc", "clear")).await.advertised_protocols.is_empty());
+    }
+}
@@ -0,0 +1,259 @@
+// src/core/scanner/pipeline.rs
+
+//! The common interface of the scanners, and the context they share during a scan.
+//!
+//! A full scan runs a list of `Scanner`s concurrently against one `ScanContext`. Adding a
+//! scanner means implementing the trait and adding it to the list, without touching the
+//! orchestration.
+
+use crate::core::scanner::throttle::Throttle;
+use crate::core::scanner::{MainPage, ScanOptions, ScanSection, http_client_builder};
+use crate::core::scanner::dns_scanner::{DnsScanner, resolver_config};
+use crate::core::scanner::egress::Egress;
+use crate::core::scanner::fingerprint_scanner::FingerprintScanner;
+use crate::core::scanner::headers_scanner::HeadersScanner;
+use crate::core::scanner::redirect_scanner::RedirectScanner;
+use crate::core::scanner::ssl_scanner::SslScanner;
+use futures::future::BoxFuture;
+use hickory_resolver::TokioAsyncResolver;
+use hickory_resolver::config::ResolverOpts;
+use serde::Serialize;
+use std::sync::Arc;
+use tracing::{debug, error};
+
+/// Everything the scanners of one scan share: the target, the options, and the network
+/// clients, which are built once instead of once per scanner.
+pub struct ScanContext {
+    /// The host being scanned (e.g. "example.com").
+    pub target: String,
+    /// Where the HTTP-based scanners fetch the main page from.
+    pub page: MainPage,
+    pub options: ScanOptions,
+    /// The HTTP client of the scanners that follow redirects, or why it could not be built.
+    pub http_client: Result<reqwest::Client, String>,
+    /// The resolver for DNS lookups, and its name as recorded in the report.
+    pub resolver: TokioAsyncResolver,
+    pub resolver_name: String,
+    /// Spaces out the HTTP requests of all scanners, so the configured delay holds across them.
+    /// The redirects `http_client` follows are not spaced out (see the `throttle` module).
+    pub throttle: Throttle,
+    /// Records the hosts the scan connects to, and refuses the others in offline mode.
+    pub egress: Arc<Egress>,
+}
+
+impl ScanContext {
+    /// Builds the shared clients for scanning `target` with `options`.
+    pub fn new(target: &str, options: ScanOptions) -> Self {
+        let egress = Egress::new(target, options.offline);
+        let http_client = http_client_builder(options.accept_invalid_certs)
+            .dns_resolver(egress.resolver())
+            .redirect(egress.redirect_policy())
+            .build()
+            .map_err(|e| {
+                error!(error = %e, "Failed to build the shared HTTP client.");
+                e.to_string()
+            });
+        let (resolver_config, resolver_name) = resolver_config(options.dns_over_https, &options.dns_nameservers, options.offline);
+        debug!(resolver = %resolver_name, "DNS resolver selected.");
+        if DnsScanner.enabled(&options) {
+            for nameserver in resolver_config.name_servers() {
+                egress.record(&nameserver.socket_addr.ip().to_string());
+            }
+        }
+        Self {
+            target: target.to_string(),
+            page: MainPage::of(target),
+            http_client,
+            resolver: TokioAsyncResolver::tokio(resolver_config, ResolverOpts::default()),
+            resolver_name,
+            throttle: Throttle::new(options.request_delay),
+            egress,
+            options,
+        }
+    }
+
+    /// Points the scan at another host, keeping the clients and the throttle. Offline mode
+    /// admits the new host from then on, and the hosts already contacted stay recorded.
+    pub fn retarget(&mut self, target: &str) {
+        self.target = target.to_string();
+        self.page = MainPage::of(target);
+        self.egress.retarget(target);
+    }
+}
+
+/// One scanner of the pipeline.
+///
+/// `scan` returns the scanner's typed results as a `ScanSection`, analysis included. A
+/// scanner disabled in `ctx.options.scanners` (see `enabled`) returns its empty results marked `disabled`
+/// instead of touching the network.
+pub trait Scanner: Send + Sync {
+    /// The scanner's name, used in logs.
+    fn name(&self) -> &'static str;
+
+    /// Runs the scan.
+    fn scan<'a>(&'a self, ctx: &'a ScanContext) -> BoxFuture<'a, ScanSection>;
+
+    /// Whether the scanner runs with `options`; when it does not, `scan` returns its results
+    /// marked `disabled`. Scanners that cannot be turned off keep the default.
+    fn enabled(&self, _options: &ScanOptions) -> bool {
+        true
+    }
+
+    /// The requests a scan of `target` would send, described for `--dry-run`, e.g.
+    /// "GET https://example.com". Building them touches nothing.
+    fn planned_requests(&self, _target: &str, _options: &ScanOptions) -> Vec<String> {
+        Vec::new()
+    }
+}
+
+/// What a scan would do, worked out from the options alone, without any network activity.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ScanPlan {
+    /// The host the scanners would be pointed at.
+    pub target: String,
+    /// The DNS resolver the lookups would go to, as recorded in the report.
+    pub resolver: String,
+    /// The scanners that would run, in report order, with the requests each would send.
+    pub scanners: Vec<PlannedScanner>,
+    /// The scanners the options turn off.
+    pub skipped: Vec<&'static str>,
+}
+
+/// One scanner of a `ScanPlan`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PlannedScanner {
+    pub name: &'static str,
+    pub requests: Vec<String>,
+}
+
+/// Plans a scan of `target` with `scanners` and `options`, as `run_scanners` would run it.
+pub fn plan_scan(scanners: &[Box<dyn Scanner>], target: &str, options: &ScanOptions) -> ScanPlan {
+    let (enabled, skipped): (Vec<_>, Vec<_>) = scanners.iter().partition(|scanner| scanner.enabled(options));
+    ScanPlan {
+        target: target.to_string(),
+        resolver: resolver_config(options.dns_over_https, &options.dns_nameservers, options.offline).1,
+        scanners: enabled.iter()
+            .map(|scanner| PlannedScanner { name: scanner.name(), requests: scanner.planned_requests(target, options) })
+            .collect(),
+        skipped: skipped.iter().map(|scanner| scanner.name()).collect(),
+    }
+}
+
+/// The built-in scanners, in the order their sections appear in the report.
+pub fn default_scanners() -> Vec<Box<dyn Scanner>> {
+    vec![
+        Box::new(DnsScanner),
+        Box::new(SslScanner),
+        Box::new(HeadersScanner),
+        Box::new(FingerprintScanner),
+        Box::new(RedirectScanner),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::models::{AnalysisFinding, HeadersResults, Severity};
+    use crate::core::scanner::{EnabledScanners, ScanSectionKind, run_scanners};
+    use crate::core::scanner::test_support::codes;
+    use std::sync::Mutex;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    /// A scanner from outside the built-in set, reporting one fixed finding.
+    struct StaticScanner;
+
+    impl Scanner for StaticScanner {
+        fn name(&self) -> &'static str {
+            "Static"
+        }
+
+        fn scan<'a>(&'a self, _ctx: &'a ScanContext) -> BoxFuture<'a, ScanSection> {
+            Box::pin(async {
+                let analysis = vec![AnalysisFinding::new(Severity::Warning, "HEADERS_CSP_MISSING")];
+                ScanSection::Headers(HeadersResults { analysis, ..Default::default() })
+            })
+        }
+    }
+
+    /// A scanner that takes a while, recording how many scanners run at the same time.
+    struct SlowScanner {
+        running: Arc<AtomicUsize>,
+        most_running: Arc<AtomicUsize>,
+    }
+
+    impl Scanner for SlowScanner {
+        fn name(&self) -> &'static str {
+            "Slow"
+        }
+
+        fn scan<'a>(&'a self, _ctx: &'a ScanContext) -> BoxFuture<'a, ScanSection> {
+            Box::pin(async {
+                let running = self.running.fetch_add(1, Ordering::SeqCst) + 1;
+                self.most_running.fetch_max(running, Ordering::SeqCst);
+                tokio::time::sleep(Duration::from_millis(20)).await;
+                self.running.fetch_sub(1, Ordering::SeqCst);
+                ScanSection::Headers(HeadersResults::default())
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn scan_concurrency_bounds_the_scanners_running_at_once() {
+        for (concurrency, expected) in [(Some(1), 1), (None, 3)] {
+            let (running, most_running) = (Arc::new(AtomicUsize::new(0)), Arc::new(AtomicUsize::new(0)));
+            let scanners: Vec<Box<dyn Scanner>> = (0..3)
+                .map(|_| Box::new(SlowScanner { running: Arc::clone(&running), most_running: Arc::clone(&most_running) }) as Box<dyn Scanner>)
+                .collect();
+            let ctx = ScanContext::new("example.invalid", ScanOptions { scan_concurrency: concurrency, ..Default::default() });
+            run_scanners(&scanners, &ctx, |_| {}).await;
+            assert_eq!(most_running.load(Ordering::SeqCst), expected, "scan_concurrency: {:?}", concurrency);
+        }
+    }
+
+    #[test]
+    fn retargeting_moves_the_offline_egress_check_to_the_canonical_host() {
+        let mut ctx = ScanContext::new("www.example.com", ScanOptions { offline: true, ..Default::default() });
+        assert!(ctx.egress.admit("www.example.com"));
+        assert!(!ctx.egress.admit("shop.example.com"));
+        ctx.retarget("shop.example.com");
+        assert!(ctx.egress.admit("shop.example.com"));
+        let contacted = ctx.egress.contacted();
+        assert!(["www.example.com", "shop.example.com"].iter().all(|host| contacted.iter().any(|c| c == host)), "{:?}", contacted);
+        assert_eq!(ctx.egress.blocked(), vec!["shop.example.com"]);
+    }
+
+    #[tokio::test]
+    async fn runs_any_list_of_scanners() {
+        let ctx = ScanContext::new("example.invalid", ScanOptions::default());
+        let delivered = Mutex::new(Vec::new());
+        let report = run_scanners(&[Box::new(StaticScanner)], &ctx, |section| delivered.lock().unwrap().push(section.kind())).await;
+        assert_eq!(codes(&report.headers_results.analysis), ["HEADERS_CSP_MISSING"]);
+        assert_eq!(*delivered.lock().unwrap(), [ScanSectionKind::Headers]);
+    }
+
+    #[test]
+    fn plans_the_enabled_scanners_and_their_requests() {
+        let scanners = EnabledScanners { scan_ssl: false, scan_fingerprint: false, ..Default::default() };
+        let options = ScanOptions { scanners, active_probes: true, dns_nameservers: vec!["10.0.0.53".parse().unwrap()], ..Default::default() };
+        let plan = plan_scan(&default_scanners(), "example.com", &options);
+        let names: Vec<&str> = plan.scanners.iter().map(|scanner| scanner.name).collect();
+        assert_eq!(names, ["DNS", "Headers", "Redirects"]);
+        assert_eq!(plan.skipped, ["SSL/TLS", "Fingerprint"]);
+        assert_eq!(plan.resolver, "Custom nameservers (10.0.0.53)");
+        assert_eq!(plan.scanners[1].requests, [
+            "GET https://example.com (falls back to http://example.com)",
+            "TRACE https://example.com",
+            "GET https://example.com with Origin: https://cors-probe.example.org",
+        ]);
+    }
+
+    #[tokio::test]
+    async fn disabled_built_in_scanners_stay_offline() {
+        let scanners = EnabledScanners { scan_dns: false, scan_ssl: false, scan_headers: false, scan_fingerprint: false };
+        let ctx = ScanContext::new("example.invalid", ScanOptions { scanners, ..Default::default() });
+        let report = run_scanners(&default_scanners(), &ctx, |_| {}).await;
+        assert!(report.dns_results.disabled && report.ssl_results.disabled && report.headers_results.disabled);
+        assert!(report.fingerprint_results.disabled && report.redirect_results.disabled);
+    }
+}
@@ -0,0 +1,258 @@
+// src/core/scanner/redirect_scanner.rs
+
+use tracing::{debug, error, info};
+use crate::core::models::{AnalysisFinding, RedirectResults, ScanResult, Severity};
+use crate::core::scanner::{ScanOptions, ScanSection, http_client_builder};
+use crate::core::scanner::egress::Egress;
+use crate::core::scanner::pipeline::{ScanContext, Scanner};
+use crate::core::scanner::throttle::Throttle;
+use futures::future::{BoxFuture, join_all};
+use reqwest::header::LOCATION;
+use reqwest::redirect::Policy;
+use std::sync::Arc;
+use url::Url;
+
+/// The external location injected into the probes. `example.org` is reserved for
+/// documentation, so a redirect there can only come from the injected value.
+const PROBE_DESTINATION: &str = "https://example.org/";
+
+/// Well-known query parameters that applications use to decide where to redirect.
+const REDIRECT_PARAMS: &[&str] = &["redirect", "redirect_uri", "url", "next", "return", "returnUrl", "continue", "dest"];
+
+/// How many redirects are followed before the chain is cut off.
+const MAX_REDIRECT_HOPS: usize = 10;
+
+/// Redirect chains longer than this many hops are reported as excessive.
+const EXCESSIVE_REDIRECT_HOPS: usize = 4;
+
+/// Runs `run_redirect_scan` with the context's target and options. It is toggled with the
+/// headers scanner, as both read the same main page.
+pub struct RedirectScanner;
+
+impl Scanner for RedirectScanner {
+    fn name(&self) -> &'static str {
+        "Redirects"
+    }
+
+    fn scan<'a>(&'a self, ctx: &'a ScanContext) -> BoxFuture<'a, ScanSection> {
+        Box::pin(async move {
+            if !self.enabled(&ctx.options) {
+                return ScanSection::Redirect(RedirectResults { disabled: true, ..Default::default() });
+            }
+            let options = &ctx.options;
+            ScanSection::Redirect(run_redirect_scan(&ctx.target, options.active_probes, options.accept_invalid_certs, &ctx.throttle, &ctx.egress).await)
+        })
+    }
+
+    fn enabled(&self, options: &ScanOptions) -> bool {
+        options.scanners.scan_headers
+    }
+
+    fn planned_requests(&self, target: &str, options: &ScanOptions) -> Vec<String> {
+        let mut requests = vec![format!("GET https://{}/ and each redirect it leads to", target)];
+        if options.active_probes {
+            requests.extend(REDIRECT_PARAMS.iter().map(|param| format!("GET https://{}/?{}={}", target, param, PROBE_DESTINATION)));
+        }
+        requests
+    }
+}
+
+/// Traces the redirect chain of the target's main page and, when enabled, probes it
+/// for open redirects.
+///
+/// # Arguments
+/// * `target` - The domain or IP address to scan.
+/// * `probe_open_redirects` - Whether to run the active open redirect probe.
+/// * `accept_invalid_certs` - Whether to skip certificate validation.
+/// * `throttle` - Spaces out the requests sent to the host.
+/// * `egress` - Records the hosts of the chain, and refuses those offline mode does not allow.
+///
+/// # Returns
+/// A `RedirectResults` struct with the redirect chain, the vulnerable probe URLs and the analysis findings.
+pub async fn run_redirect_scan(target: &str, probe_open_redirects: bool, accept_invalid_certs: bool, throttle: &Throttle, egress: &Arc<Egress>) -> RedirectResults {
+    info!(target, "Starting redirect scan.");
+
+    // Redirects are never followed automatically: each `Location` header is read and tracked by hand.
+    let client = match http_client_builder(accept_invalid_certs)
+        .redirect(Policy::none())
+        .dns_resolver(egress.resolver())
+        .build()
+    {
+        Ok(c) => c,
+        Err(e) => {
+            error!(error = %e, "Failed to build HTTP client for redirect scan.");
+            let error = format!("Failed to build HTTP client: {}", e);
+            return RedirectResults { chain: Err(error.clone()), scan: Err(error), ..Default::default() };
+        }
+    };
+
+    let (chain, (scan, failed_probes)) = tokio::join!(
+        trace_redirect_chain(&client, target, throttle, egress),
+        async {
+            // Active probes only run when explicitly enabled.
+            match Url::parse(&format!("https://{}/", target)) {
+                Ok(base) if probe_open_redirects => probe_redirect_params(&client, &base, throttle).await,
+                Ok(_) => (Ok(None), Vec::new()),
+                Err(e) => (Err(format!("Invalid target URL: {}", e)), Vec::new()),
+            }
+        }
+    );
+
+    let mut results = RedirectResults { chain, scan, failed_probes, analysis: Vec::new(), disabled: false };
+    results.analysis = analyze_redirect_results(&results);
+    info!(findings = %results.analysis.len(), "Redirect scan finished.");
+    results
+}
+
+/// Follows the redirects of `https://<target>/` one hop at a time and records every URL visited.
+///
+/// The trace stops at the first non-redirect response, after `MAX_REDIRECT_HOPS` hops, or
+/// when a URL repeats; in that last case the repeated URL ends the chain, marking the loop.
+async fn trace_redirect_chain(client: &reqwest::Client, target: &str, throttle: &Throttle, egress: &Egress) -> ScanResult<Vec<String>> {
+    let mut url = Url::parse(&format!("https://{}/", target)).map_err(|e| format!("Invalid target URL: {}", e))?;
+    let mut chain = vec![url.to_string()];
+
+    for _ in 0..MAX_REDIRECT_HOPS {
+        if !egress.admit_url(&url) {
+            debug!(url = %url, "Redirect target refused in offline mode, ending the chain.");
+            break;
+        }
+        throttle.wait().await;
+        let response = match client.get(url.clone()).send().await {
+            Ok(response) => response,
+            // Failing on the first request means there is no chain to speak of; the headers scan reports it.
+            Err(e) if chain.len() == 1 => return Err(format!("HTTP request failed: {}", e)),
+            Err(e) => {
+                debug!(url = %url, error = %e, "Redirect target unreachable, ending the chain.");
+                break;
+            }
+        };
+        if !response.status().is_redirection() {
+            break;
+        }
+        let Some(next) = response.headers().get(LOCATION)
+            .and_then(|location| location.to_str().ok())
+            .and_then(|location| url.join(location).ok())
+        else {
+            break;
+        };
+
+        let is_loop = chain.contains(&next.to_string());
+        chain.push(next.to_string());
+        if is_loop {
+            debug!(chain = ?chain, "Redirect loop detected.");
+            break;
+        }
+        url = next;
+    }
+    debug!(hops = chain.len() - 1, "Redirect chain traced.");
+    Ok(Some(chain))
+}
+
+/// Probes the target for open redirects.
+///
+/// This is an active check: for each well-known redirect parameter it requests
+/// `<base>?<param>=https://example.org/` with redirects disabled, and
+/// inspects the `Location` header of the response. A redirect that leads to the
+/// injected host confirms the application forwards visitors to arbitrary sites.
+///
+/// # Returns
+/// The probe URLs that were redirected to the injected host, or an error when every probe
+/// request failed, along with the probe URLs whose request failed. A failed probe tested nothing,
+/// so it is never taken to mean the parameter is safe.
+async fn probe_redirect_params(client: &reqwest::Client, base: &Url, throttle: &Throttle) -> (ScanResult<Vec<String>>, Vec<String>) {
+    let probes = REDIRECT_PARAMS.iter().map(|param| {
+        let client = client.clone();
+        let mut url = base.clone();
+        url.query_pairs_mut().append_pair(param, PROBE_DESTINATION);
+        async move {
+            throttle.wait().await;
+            match client.get(url.clone()).send().await {
+                Ok(response) if response.status().is_redirection() => {
+                    let destination = response.headers().get(LOCATION)
+                        .and_then(|location| location.to_str().ok())
+                        // Relative locations stay on the target, so resolve them before comparing hosts.
+                        .and_then(|location| url.join(location).ok());
+                    let injected = Url::parse(PROBE_DESTINATION).ok();
+                    let redirected = destination.is_some_and(|destination| destination.host_str() == injected.as_ref().and_then(Url::host_str));
+                    debug!(param, redirected, "Probe answered with a redirect.");
+                    Ok(redirected.then(|| url.to_string()))
+                }
+                Ok(_) => Ok(None),
+                Err(e) => {
+                    debug!(param, error = %e, "Redirect probe request failed.");
+                    Err((url.to_string(), e.to_string()))
+                }
+            }
+        }
+    });
+    let outcomes = join_all(probes).await;
+    let mut vulnerable = Vec::new();
+    let mut failed = Vec::new();
+    let mut last_error = None;
+    for outcome in outcomes {
+        match outcome {
+            Ok(url) => vulnerable.extend(url),
+            Err((url, error)) => {
+                failed.push(url);
+                last_error = Some(error);
+            }
+        }
+    }
+    match last_error {
+        Some(error) if failed.len() == REDIRECT_PARAMS.len() => (Err(format!("every open redirect probe failed: {}", error)), failed),
+        _ => (Ok(Some(vulnerable)), failed),
+    }
+}
+
+/// Emits `HTTP_REDIRECT_LOOP` or `HTTP_EXCESSIVE_REDIRECTS` for a problematic redirect chain,
+/// and `HTTP_OPEN_REDIRECT` when at least one probe was redirected to the injected host.
+fn analyze_redirect_results(results: &RedirectResults) -> Vec<AnalysisFinding> {
+    let mut analyses = Vec::new();
+    if let Ok(Some(chain)) = &results.chain {
+        if results.has_loop() {
+            debug!(chain = ?chain, "Redirect loop, adding HTTP_REDIRECT_LOOP finding.");
+            analyses.push(AnalysisFinding::new(Severity::Warning, "HTTP_REDIRECT_LOOP"));
+        } else if chain.len() - 1 > EXCESSIVE_REDIRECT_HOPS {
+            debug!(hops = chain.len() - 1, "Long redirect chain, adding HTTP_EXCESSIVE_REDIRECTS finding.");
+            analyses.push(AnalysisFinding::new(Severity::Info, "HTTP_EXCESSIVE_REDIRECTS"));
+        }
+    }
+    if let Ok(Some(vulnerable)) = &results.scan
+        && !vulnerable.is_empty()
+    {
+        debug!(urls = ?vulnerable, "Confirmed external redirect, adding HTTP_OPEN_REDIRECT finding.");
+        analyses.push(AnalysisFinding::new(Severity::Warning, "HTTP_OPEN_REDIRECT"));
+    }
+    analyses
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::scanner::test_support::{MockHttpServer, MockResponse};
+    use std::time::Duration;
+
+    fn client() -> reqwest::Client {
+        http_client_builder(false).redirect(Policy::none()).build().unwrap()
+    }
+
+    #[tokio::test]
+    async fn reports_probes_redirected_to_the_injected_host() {
+        let server = MockHttpServer::start(MockResponse::html("").with_status(302).with_header("Location", PROBE_DESTINATION)).await;
+        let (scan, failed) = probe_redirect_params(&client(), &Url::parse(&server.page.https_url).unwrap(), &Throttle::new(Duration::ZERO)).await;
+        assert_eq!(scan.unwrap().unwrap().len(), REDIRECT_PARAMS.len());
+        assert!(failed.is_empty());
+    }
+
+    #[tokio::test]
+    async fn failed_probes_are_not_taken_as_safe() {
+        // Nothing listens on the port once the listener is dropped, so every request fails.
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let base = Url::parse(&format!("http://{}/", listener.local_addr().unwrap())).unwrap();
+        drop(listener);
+        let (scan, failed) = probe_redirect_params(&client(), &base, &Throttle::new(Duration::ZERO)).await;
+        assert!(scan.is_err());
+        assert_eq!(failed.len(), REDIRECT_PARAMS.len());
+    }
+}
@@ -2,12 +2,156 @@
 
 use tracing::{debug, error, info};
 use crate::core::models::{AnalysisFinding, CertificateInfo, Severity, SslData, SslResults, ScanResult};
-use chrono::{DateTime, Utc};
+use crate::core::scanner::{ExpiryWindows, ScanOptions, ScanSection, domain};
+use crate::core::scanner::pipeline::{ScanContext, Scanner};
+use chrono::{DateTime, NaiveDate, Utc};
+use futures::future::BoxFuture;
 use native_tls::TlsConnector;
-use std::net::TcpStream;
+use std::net::{TcpStream, ToSocketAddrs};
+use std::time::Duration;
 use tokio::task::spawn_blocking;
 use x509_parser::prelude::*;
 
+/// The application protocols offered via ALPN, in order of preference.
+const ALPN_PROTOCOLS: &[&str] = &["h2", "http/1.1"];
+
+/// The OID of the TLS Feature extension (RFC 7633), which carries the OCSP Must-Staple flag.
+const TLS_FEATURE_OID: &str = "1.3.6.1.5.5.7.1.24";
+
+/// The TLS Feature value for `status_request`, i.e. OCSP stapling.
+const STATUS_REQUEST_FEATURE: u32 = 5;
+
+/// How long the session resumption probe waits for the response that carries TLS 1.3 session tickets.
+#[cfg(not(any(target_os = "windows", target_vendor = "apple")))]
+const RESUMPTION_READ_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// How long connecting to port 443 may take before the attempt fails.
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// How long a read or write on a TLS connection may block. The handshakes run in blocking
+/// code that aborting the scan cannot interrupt, so a server that accepts the connection
+/// but never answers would otherwise hold the scan, and quitting the TUI, indefinitely.
+const IO_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The prefix of the error reported when the TLS handshake (including certificate verification) fails.
+pub(crate) const HANDSHAKE_ERROR_PREFIX: &str = "TLS Handshake Error";
+
+/// Connects to port 443 of the target, trying each of its addresses in turn, with
+/// `CONNECT_TIMEOUT` for each attempt and `IO_TIMEOUT` on the resulting stream.
+fn connect_tcp(target: &str) -> std::io::Result<TcpStream> {
+    let mut last_error = None;
+    for address in (target, 443).to_socket_addrs()? {
+        match TcpStream::connect_timeout(&address, CONNECT_TIMEOUT) {
+            Ok(stream) => {
+                stream.set_read_timeout(Some(IO_TIMEOUT))?;
+                stream.set_write_timeout(Some(IO_TIMEOUT))?;
+                return Ok(stream);
+            }
+            Err(e) => last_error = Some(e),
+        }
+    }
+    Err(last_error.unwrap_or_else(|| std::io::Error::new(std::io::ErrorKind::NotFound, "no address found for the host")))
+}
+
+/// Markers of the certificates hosting platforms and proxies serve when no certificate matches
+/// the requested name, paired with the platform they identify. Matched case-insensitively
+/// against the subject and the SAN names.
+const DEFAULT_CERT_MARKERS: &[(&str, &str)] = &[
+    ("Kubernetes Ingress Controller Fake Certificate", "Kubernetes ingress-nginx"),
+    ("TRAEFIK DEFAULT CERT", "Traefik"),
+    ("O=Plesk", "Plesk"),
+    ("localhost.localdomain", "Generic self-signed"),
+    ("CN=localhost", "Generic self-signed"),
+    ("*.herokuapp.com", "Heroku"),
+    ("*.azurewebsites.net", "Azure App Service"),
+    ("*.cloudfront.net", "Amazon CloudFront"),
+    ("*.github.io", "GitHub Pages"),
+    ("*.netlify.app", "Netlify"),
+    ("*.vercel.app", "Vercel"),
+    ("*.wpengine.com", "WP Engine"),
+    ("*.myshopify.com", "Shopify"),
+    ("*.squarespace.com", "Squarespace"),
+];
+
+/// A certificate authority browsers have stopped trusting, identified by the organization
+/// (`O=`) of the issuer names it used.
+struct DistrustedIssuer<'a> {
+    /// The CA as users know it (e.g. "Entrust").
+    ca: &'a str,
+    /// The issuer organizations of its certificates, matched case-insensitively and in full.
+    organizations: &'a [&'a str],
+    /// When the major browsers distrusted it.
+    since: &'a str,
+    /// Only certificates issued (`notBefore`) after this day are distrusted; those issued before
+    /// stay trusted until they expire. `None` when every certificate of the CA is distrusted.
+    distrusted_after: Option<NaiveDate>,
+}
+
+/// The day `year-month-day`, for the cutoffs below.
+const fn day(year: i32, month: u32, day: u32) -> Option<NaiveDate> {
+    NaiveDate::from_ymd_opt(year, month, day)
+}
+
+/// CAs distrusted by the major browsers. Only CAs whose removal was announced by the root
+/// programs belong here; certificates re-issued under the buyer's own name (e.g. DigiCert for
+/// the Symantec brands) carry a different organization and are not matched. The cutoffs are
+/// Chrome's, which the other root programs matched or preceded.
+static DISTRUSTED_ISSUERS: &[DistrustedIssuer] = &[
+    DistrustedIssuer { ca: "Symantec", organizations: &["Symantec Corporation", "VeriSign, Inc.", "GeoTrust Inc.", "thawte, Inc."], since: "2018", distrusted_after: None },
+    DistrustedIssuer { ca: "WoSign", organizations: &["WoSign CA Limited"], since: "2017", distrusted_after: None },
+    DistrustedIssuer { ca: "StartCom", organizations: &["StartCom Ltd."], since: "2017", distrusted_after: None },
+    DistrustedIssuer { ca: "Camerfirma", organizations: &["AC Camerfirma S.A."], since: "2021", distrusted_after: None },
+    DistrustedIssuer { ca: "TrustCor", organizations: &["TrustCor Systems S. de R.L."], since: "2022", distrusted_after: day(2022, 11, 30) },
+    DistrustedIssuer { ca: "Entrust", organizations: &["Entrust, Inc.", "Entrust Limited"], since: "2024", distrusted_after: day(2024, 10, 31) },
+    DistrustedIssuer { ca: "Chunghwa Telecom", organizations: &["Chunghwa Telecom Co., Ltd."], since: "2025", distrusted_after: day(2025, 7, 31) },
+    DistrustedIssuer { ca: "Netlock", organizations: &["NetLock Kft."], since: "2025", distrusted_after: day(2025, 7, 31) },
+];
+
+/// Returns the distrusted CA that issued a certificate with this issuer organization and
+/// `notBefore`, if any. A certificate issued before its CA's cutoff is still trusted.
+fn distrusted_issuer(organization: &str, not_before: DateTime<Utc>) -> Option<&'static DistrustedIssuer<'static>> {
+    let organization = organization.trim();
+    DISTRUSTED_ISSUERS.iter()
+        .find(|issuer| issuer.organizations.iter().any(|known| known.eq_ignore_ascii_case(organization)))
+        .filter(|issuer| issuer.distrusted_after.is_none_or(|cutoff| not_before.date_naive() > cutoff))
+}
+
+/// Runs `run_ssl_scan` with the context's target and TLS probe options.
+pub struct SslScanner;
+
+impl Scanner for SslScanner {
+    fn name(&self) -> &'static str {
+        "SSL/TLS"
+    }
+
+    fn scan<'a>(&'a self, ctx: &'a ScanContext) -> BoxFuture<'a, ScanSection> {
+        Box::pin(async move {
+            if !self.enabled(&ctx.options) {
+                return ScanSection::Ssl(SslResults { disabled: true, ..Default::default() });
+            }
+            // The handshakes connect to the target directly, without the HTTP clients' resolver.
+            ctx.egress.record(&ctx.target);
+            let options = &ctx.options;
+            ScanSection::Ssl(run_ssl_scan(&ctx.target, options.probe_dual_certificates, options.cert_expiry, options.cert_recently_issued_hours, options.probe_session_resumption).await)
+        })
+    }
+
+    fn enabled(&self, options: &ScanOptions) -> bool {
+        options.scanners.scan_ssl
+    }
+
+    fn planned_requests(&self, target: &str, options: &ScanOptions) -> Vec<String> {
+        let mut requests = vec![format!("TLS handshake with {}:443", target)];
+        if options.probe_dual_certificates {
+            requests.push(format!("TLS handshakes with {}:443 forcing RSA, then ECDSA", target));
+        }
+        if options.probe_session_resumption {
+            requests.push(format!("TLS handshakes with {}:443 resuming the first session", target));
+        }
+        requests
+    }
+}
+
 /// Runs an SSL/TLS scan against the specified target.
 ///
 /// This function initiates a TLS connection to the target on port 443. Since the underlying
@@ -17,31 +161,43 @@ use x509_parser::prelude::*;
 ///
 /// # Arguments
 /// * `target` - The domain or IP address to scan.
+/// * `probe_dual_certificates` - Whether to also look for separate RSA and ECDSA certificates.
+/// * `cert_expiry` - How far ahead of expiry the certificate is reported.
+/// * `recently_issued_hours` - Report a certificate issued less than this many hours ago, if set.
+/// * `probe_resumption` - Whether to test TLS session resumption with two extra handshakes.
 ///
 /// # Returns
 /// An `SslResults` struct containing the certificate details and analysis findings.
-pub async fn run_ssl_scan(target: &str) -> SslResults {
+pub async fn run_ssl_scan(target: &str, probe_dual_certificates: bool, cert_expiry: ExpiryWindows, recently_issued_hours: Option<i64>, probe_resumption: bool) -> SslResults {
     info!(target, "Starting SSL/TLS scan.");
     let target_owned = target.to_string();
 
     debug!("Spawning blocking task for TLS connection.");
     // Offload the blocking network I/O to a separate thread pool.
-    let scan_result = spawn_blocking(move || {
-        perform_tls_scan(&target_owned)
+    let (scan_result, default_certificate) = spawn_blocking(move || {
+        let scan = perform_tls_scan(&target_owned, probe_dual_certificates, probe_resumption);
+        // A failed handshake may just mean the server ignored SNI and answered with its default certificate.
+        let default_certificate = match &scan {
+            Err(e) if e.starts_with(HANDSHAKE_ERROR_PREFIX) => detect_default_certificate(&target_owned),
+            _ => None,
+        };
+        (scan, default_certificate)
     }).await
       .unwrap_or_else(|e| {
           // This case handles a panic within the spawned task, which is a severe error.
           error!(panic = %e, "Blocking SSL scan task panicked!");
-          Err(format!("Task panicked: {}", e))
+          (Err(format!("Task panicked: {}", e)), None)
       });
 
     debug!("SSL scan task finished, starting analysis.");
     let mut results = SslResults {
         scan: scan_result,
         analysis: Vec::new(),
+        default_certificate,
+        disabled: false,
     };
 
-    results.analysis = analyze_ssl_results(&results);
+    results.analysis = analyze_ssl_results(&results, target, cert_expiry, recently_issued_hours);
 
     info!(findings = %results.analysis.len(), "SSL/TLS scan finished.");
     results
@@ -54,19 +210,22 @@ pub async fn run_ssl_scan(target: &str) -> SslResults {
 ///
 /// # Arguments
 /// * `target` - The domain name to connect to.
+/// * `probe_dual_certificates` - Whether to run the extra RSA/ECDSA handshakes.
+/// * `probe_resumption` - Whether to run the extra handshakes testing session resumption.
 ///
 /// # Returns
 /// A `ScanResult<SslData>` containing the extracted certificate information or an error string.
-fn perform_tls_scan(target: &str) -> ScanResult<SslData> {
+fn perform_tls_scan(target: &str, probe_dual_certificates: bool, probe_resumption: bool) -> ScanResult<SslData> {
     debug!(target, "Performing TLS connection and handshake.");
 
-    let connector = TlsConnector::new().map_err(|e| {
+    // Offer the common HTTP protocols via ALPN so the server's choice can be reported.
+    let connector = TlsConnector::builder().request_alpns(ALPN_PROTOCOLS).build().map_err(|e| {
         error!(error = %e, "Failed to create TlsConnector");
         format!("TlsConnector Error: {}", e)
     })?;
     
     debug!(target, "Connecting TCP stream to port 443.");
-    let stream = TcpStream::connect((target, 443)).map_err(|e| {
+    let stream = connect_tcp(target).map_err(|e| {
         error!(error = %e, "TCP connection failed");
         format!("TCP Connection Error: {}", e)
     })?;
@@ -74,9 +233,20 @@ fn perform_tls_scan(target: &str) -> ScanResult<SslData> {
     debug!(target, "Performing TLS handshake.");
     let stream = connector.connect(target, stream).map_err(|e| {
         error!(error = %e, "TLS handshake failed");
-        format!("TLS Handshake Error: {}", e)
+        format!("{}: {}", HANDSHAKE_ERROR_PREFIX, e)
     })?;
 
+    // Record the protocol the server selected via ALPN, if any.
+    let alpn: Vec<String> = match stream.negotiated_alpn() {
+        Ok(Some(protocol)) => vec![String::from_utf8_lossy(&protocol).into_owned()],
+        Ok(None) => Vec::new(),
+        Err(e) => {
+            debug!(error = %e, "Could not read the negotiated ALPN protocol.");
+            Vec::new()
+        }
+    };
+    debug!(alpn = ?alpn, "ALPN negotiation finished.");
+
     // Retrieve the server's certificate from the TLS session.
     let cert = match stream.peer_certificate() {
         Ok(Some(c)) => {
@@ -99,33 +269,331 @@ fn perform_tls_scan(target: &str) -> ScanResult<SslData> {
         format!("Could not convert certificate to DER: {}", e)
     })?;
     
+    let certificate_info = parse_certificate(&cert_der)?;
+    info!(subject = %certificate_info.subject_name, issuer = %certificate_info.issuer_name, "Successfully parsed certificate.");
+
+    // Check if the current date is within the certificate's validity period.
+    let is_valid = Utc::now() > certificate_info.not_before && Utc::now() < certificate_info.not_after;
+
+    let certificates = if probe_dual_certificates { probe_certificates(target) } else { Vec::new() };
+    let is_wildcard = certificate_info.subject_alt_names.iter().any(|name| name.starts_with("*."));
+    // Stapling is only probed when the certificate demands it, as it costs an extra handshake.
+    let must_staple = requires_ocsp_stapling(&cert_der);
+    let ocsp_stapled = if must_staple { probe_ocsp_stapling(target) } else { None };
+    let resumption_supported = if probe_resumption { probe_session_resumption(target) } else { None };
+    let compression_enabled = probe_tls_compression(target);
+
+    Ok(Some(SslData {
+        is_valid,
+        is_wildcard,
+        certificate_info,
+        alpn,
+        compression_enabled,
+        certificates,
+        must_staple,
+        ocsp_stapled,
+        resumption_supported,
+    }))
+}
+
+/// Handshakes again without verifying the certificate, to see what the server actually serves.
+///
+/// Returns the hosting platform when the certificate does not cover `target` and looks like a
+/// platform or proxy default, which points at the server ignoring SNI rather than at a certificate
+/// deployed for this host. Returns `None` when nothing could be fetched or the certificate is not
+/// a known default.
+fn detect_default_certificate(target: &str) -> Option<String> {
+    debug!(target, "Handshake failed, fetching the served certificate without verification.");
+    let connector = TlsConnector::builder().danger_accept_invalid_certs(true).build().ok()?;
+    let stream = connect_tcp(target).ok()?;
+    let stream = connector.connect(target, stream).ok()?;
+    let cert_der = stream.peer_certificate().ok()??.to_der().ok()?;
+    let info = parse_certificate(&cert_der).ok()?;
+
+    if certificate_covers(&info, target) {
+        debug!(target, "The served certificate covers the host, so SNI is honored.");
+        return None;
+    }
+    let contains = |haystack: &str, marker: &str| haystack.to_ascii_lowercase().contains(&marker.to_ascii_lowercase());
+    let platform = DEFAULT_CERT_MARKERS.iter()
+        .find(|(marker, _)| contains(&info.subject_name, marker) || info.subject_alt_names.iter().any(|name| contains(name, marker)))
+        .map(|(_, platform)| platform.to_string());
+    debug!(subject = %info.subject_name, platform = ?platform, "Checked the served certificate against known defaults.");
+    platform
+}
+
+/// Returns whether the certificate's names cover `host`, following the one-label wildcard rule.
+/// Without SANs, the subject's common name is used.
+fn certificate_covers(info: &CertificateInfo, host: &str) -> bool {
+    let matches = |name: &str| match name.strip_prefix("*.") {
+        Some(suffix) => host.split_once('.').is_some_and(|(_, parent)| parent.eq_ignore_ascii_case(suffix)),
+        None => name.eq_ignore_ascii_case(host),
+    };
+    if info.subject_alt_names.is_empty() {
+        return info.subject_name.split(", ")
+            .filter_map(|part| part.strip_prefix("CN="))
+            .any(matches);
+    }
+    info.subject_alt_names.iter().any(|name| matches(name))
+}
+
+/// Parses a DER-encoded X.509 certificate into a `CertificateInfo`.
+fn parse_certificate(cert_der: &[u8]) -> Result<CertificateInfo, String> {
     // Parse the DER-encoded certificate into a structured X.509 object.
-    let (_, x509) = parse_x509_certificate(&cert_der).map_err(|e| {
+    let (_, x509) = parse_x509_certificate(cert_der).map_err(|e| {
         error!(error = %e, "Failed to parse X.509 certificate");
         format!("X.509 Parse Error: {}", e)
     })?;
 
-    info!(subject = %x509.subject(), issuer = %x509.issuer(), "Successfully parsed certificate.");
-    
     // Extract validity information from the certificate.
     let validity = x509.validity();
     let not_after = asn1_time_to_chrono_utc(&validity.not_after);
     let not_before = asn1_time_to_chrono_utc(&validity.not_before);
     let days_until_expiry = not_after.signed_duration_since(Utc::now()).num_days();
-    
-    // Check if the current date is within the certificate's validity period.
-    let is_valid = Utc::now() > not_before && Utc::now() < not_after;
+    let hours_since_issued = Utc::now().signed_duration_since(not_before).num_hours();
 
-    Ok(Some(SslData {
-        is_valid,
-        certificate_info: CertificateInfo {
-            subject_name: x509.subject().to_string(),
-            issuer_name: x509.issuer().to_string(),
-            not_before,
-            not_after,
-            days_until_expiry,
-        },
-    }))
+    Ok(CertificateInfo {
+        subject_name: x509.subject().to_string(),
+        issuer_name: x509.issuer().to_string(),
+        issuer_organization: x509.issuer().iter_organization()
+            .next()
+            .and_then(|o| o.as_str().ok())
+            .map(String::from),
+        serial_number: Some(x509.raw_serial_as_string()),
+        subject_alt_names: subject_alt_names(&x509),
+        not_before,
+        not_after,
+        days_until_expiry,
+        hours_since_issued,
+    })
+}
+
+/// Returns the DNS names listed in the certificate's Subject Alternative Name extension.
+fn subject_alt_names(x509: &X509Certificate) -> Vec<String> {
+    match x509.subject_alternative_name() {
+        Ok(Some(san)) => san.value.general_names.iter()
+            .filter_map(|name| match name {
+                GeneralName::DNSName(dns) => Some(dns.to_string()),
+                _ => None,
+            })
+            .collect(),
+        Ok(None) => Vec::new(),
+        Err(e) => {
+            debug!(error = %e, "Could not parse the Subject Alternative Name extension.");
+            Vec::new()
+        }
+    }
+}
+
+/// Returns whether the certificate's TLS Feature extension lists `status_request` (OCSP Must-Staple).
+fn requires_ocsp_stapling(cert_der: &[u8]) -> bool {
+    let Ok((_, x509)) = parse_x509_certificate(cert_der) else { return false; };
+    x509.extensions().iter()
+        .filter(|extension| extension.oid.to_id_string() == TLS_FEATURE_OID)
+        .filter_map(|extension| x509_parser::der_parser::parse_der(extension.value).ok())
+        .any(|(_, features)| features.as_sequence()
+            .is_ok_and(|features| features.iter().any(|feature| feature.as_u32() == Ok(STATUS_REQUEST_FEATURE))))
+}
+
+/// Handshakes once more asking for OCSP stapling and returns whether the server stapled a response.
+/// `None` when the probe could not complete.
+#[cfg(not(any(target_os = "windows", target_vendor = "apple")))]
+fn probe_ocsp_stapling(target: &str) -> Option<bool> {
+    use openssl::ssl::{SslConnector, SslMethod, SslVerifyMode, StatusType};
+
+    let probe = || -> Result<bool, String> {
+        let mut builder = SslConnector::builder(SslMethod::tls_client()).map_err(|e| e.to_string())?;
+        // Only the stapled response is of interest; the certificate is judged by the main handshake.
+        builder.set_verify(SslVerifyMode::NONE);
+        let mut config = builder.build().configure().map_err(|e| e.to_string())?;
+        config.set_status_type(StatusType::OCSP).map_err(|e| e.to_string())?;
+
+        let stream = connect_tcp(target).map_err(|e| format!("TCP Connection Error: {}", e))?;
+        let stream = config.connect(target, stream).map_err(|e| format!("TLS Handshake Error: {}", e))?;
+        Ok(stream.ssl().ocsp_status().is_some())
+    };
+    match probe() {
+        Ok(stapled) => {
+            debug!(stapled, "OCSP stapling probe finished.");
+            Some(stapled)
+        }
+        Err(e) => {
+            debug!(error = %e, "OCSP stapling probe failed.");
+            None
+        }
+    }
+}
+
+/// Requesting a stapled OCSP response needs OpenSSL, which native-tls does not use on this platform.
+#[cfg(any(target_os = "windows", target_vendor = "apple"))]
+fn probe_ocsp_stapling(_target: &str) -> Option<bool> {
+    None
+}
+
+/// Handshakes twice, offering the first handshake's session in the second, and returns whether
+/// the server resumed it. `None` when the probe could not complete.
+///
+/// TLS 1.3 servers only issue their session tickets after the handshake, so a request is sent
+/// and its response read on the first connection before the session is taken.
+#[cfg(not(any(target_os = "windows", target_vendor = "apple")))]
+fn probe_session_resumption(target: &str) -> Option<bool> {
+    use openssl::ssl::{SslConnector, SslMethod, SslSessionRef, SslStream, SslVerifyMode};
+    use std::io::{Read, Write};
+
+    let probe = || -> Result<bool, String> {
+        let mut builder = SslConnector::builder(SslMethod::tls_client()).map_err(|e| e.to_string())?;
+        // Only resumption is of interest; the certificate is judged by the main handshake.
+        builder.set_verify(SslVerifyMode::NONE);
+        let connector = builder.build();
+        let connect = |session: Option<&SslSessionRef>| -> Result<SslStream<TcpStream>, String> {
+            let mut config = connector.configure().map_err(|e| e.to_string())?;
+            if let Some(session) = session {
+                // SAFETY: the session was negotiated through this same connector's context.
+                unsafe { config.set_session(session) }.map_err(|e| e.to_string())?;
+            }
+            let stream = connect_tcp(target).map_err(|e| format!("TCP Connection Error: {}", e))?;
+            stream.set_read_timeout(Some(RESUMPTION_READ_TIMEOUT)).map_err(|e| e.to_string())?;
+            config.connect(target, stream).map_err(|e| format!("TLS Handshake Error: {}", e))
+        };
+
+        let mut first = connect(None)?;
+        let request = format!("HEAD / HTTP/1.1\r\nHost: {}\r\nConnection: close\r\n\r\n", target);
+        first.write_all(request.as_bytes()).map_err(|e| e.to_string())?;
+        // The response only matters for the tickets that arrive with it; a timeout is fine.
+        let _ = first.read(&mut [0u8; 1024]);
+        let Some(session) = first.ssl().session().map(|session| session.to_owned()) else {
+            return Ok(false);
+        };
+        // Close the first connection cleanly, so the session stays resumable and the server is free.
+        let _ = first.shutdown();
+        drop(first);
+        let second = connect(Some(&session))?;
+        Ok(second.ssl().session_reused())
+    };
+    match probe() {
+        Ok(resumed) => {
+            debug!(resumed, "Session resumption probe finished.");
+            Some(resumed)
+        }
+        Err(e) => {
+            debug!(error = %e, "Session resumption probe failed.");
+            None
+        }
+    }
+}
+
+/// Offering a session for resumption needs OpenSSL, which native-tls does not use on this platform.
+#[cfg(any(target_os = "windows", target_vendor = "apple"))]
+fn probe_session_resumption(_target: &str) -> Option<bool> {
+    None
+}
+
+/// Handshakes once more over TLS 1.2 (1.3 has no compression) offering TLS compression, and
+/// returns whether the server accepted it. `None` when the probe could not complete, or when
+/// the local OpenSSL has no compression method to offer (it is often built without zlib), as a
+/// server cannot pick what was not offered. Skipping the probe then costs no handshake.
+#[cfg(not(any(target_os = "windows", target_vendor = "apple")))]
+fn probe_tls_compression(target: &str) -> Option<bool> {
+    use foreign_types::ForeignTypeRef;
+    use openssl::ssl::{SslConnector, SslMethod, SslOptions, SslVersion, SslVerifyMode};
+    use std::ffi::{c_int, c_void};
+
+    // openssl-sys only declares these when OpenSSL was built with compression, but libssl
+    // exports them either way (returning NULL without it), so they are declared here.
+    unsafe extern "C" {
+        fn SSL_COMP_get_compression_methods() -> *mut c_void;
+        fn OPENSSL_sk_num(stack: *const c_void) -> c_int;
+        fn SSL_get_current_compression(ssl: *const c_void) -> *const c_void;
+    }
+
+    // SAFETY: both only read OpenSSL's global list of compression methods; a NULL list counts -1.
+    if unsafe { OPENSSL_sk_num(SSL_COMP_get_compression_methods()) } <= 0 {
+        debug!("The local OpenSSL offers no compression method, skipping the TLS compression probe.");
+        return None;
+    }
+    let probe = || -> Result<bool, String> {
+        let mut builder = SslConnector::builder(SslMethod::tls_client()).map_err(|e| e.to_string())?;
+        // Only compression is of interest; the certificate is judged by the main handshake.
+        builder.set_verify(SslVerifyMode::NONE);
+        builder.set_max_proto_version(Some(SslVersion::TLS1_2)).map_err(|e| e.to_string())?;
+        builder.clear_options(SslOptions::NO_COMPRESSION);
+
+        let stream = connect_tcp(target).map_err(|e| format!("TCP Connection Error: {}", e))?;
+        let stream = builder.build().connect(target, stream).map_err(|e| format!("TLS Handshake Error: {}", e))?;
+        // SAFETY: the pointer is a live SSL owned by the stream; the call only reads its state.
+        Ok(!unsafe { SSL_get_current_compression(stream.ssl().as_ptr() as *const c_void) }.is_null())
+    };
+    match probe() {
+        Ok(compressed) => {
+            debug!(compressed, "TLS compression probe finished.");
+            Some(compressed)
+        }
+        Err(e) => {
+            debug!(error = %e, "TLS compression probe failed.");
+            None
+        }
+    }
+}
+
+/// Reading the negotiated compression needs OpenSSL, which native-tls does not use on this platform.
+#[cfg(any(target_os = "windows", target_vendor = "apple"))]
+fn probe_tls_compression(_target: &str) -> Option<bool> {
+    None
+}
+
+/// The key types a server may hold a separate certificate for.
+#[derive(Debug, Clone, Copy)]
+enum KeyType {
+    Rsa,
+    Ecdsa,
+}
+
+/// Handshakes once with RSA-only and once with ECDSA-only preferences and returns the distinct
+/// leaf certificates presented. A failed probe simply means the server has no certificate of that type.
+fn probe_certificates(target: &str) -> Vec<CertificateInfo> {
+    let mut certificates: Vec<CertificateInfo> = Vec::new();
+    for key_type in [KeyType::Rsa, KeyType::Ecdsa] {
+        match fetch_certificate(target, key_type).and_then(|der| parse_certificate(&der)) {
+            Ok(info) => {
+                debug!(?key_type, serial = ?info.serial_number, "Certificate probe succeeded.");
+                if !certificates.iter().any(|known| known.serial_number == info.serial_number) {
+                    certificates.push(info);
+                }
+            }
+            Err(e) => debug!(?key_type, error = %e, "Certificate probe failed."),
+        }
+    }
+    certificates
+}
+
+/// Connects with only the given key type allowed, and returns the server's leaf certificate in DER form.
+///
+/// TLS 1.2 cipher suites name the authentication algorithm, while TLS 1.3 selects the certificate
+/// from the offered signature algorithms, so both are restricted.
+#[cfg(not(any(target_os = "windows", target_vendor = "apple")))]
+fn fetch_certificate(target: &str, key_type: KeyType) -> Result<Vec<u8>, String> {
+    use openssl::ssl::{SslConnector, SslMethod, SslVerifyMode};
+
+    let (cipher_list, sigalgs) = match key_type {
+        KeyType::Rsa => ("aRSA", "rsa_pss_rsae_sha256:rsa_pss_rsae_sha384:rsa_pss_rsae_sha512:rsa_pkcs1_sha256:rsa_pkcs1_sha384:rsa_pkcs1_sha512"),
+        KeyType::Ecdsa => ("aECDSA", "ecdsa_secp256r1_sha256:ecdsa_secp384r1_sha384:ecdsa_secp521r1_sha512"),
+    };
+    let mut builder = SslConnector::builder(SslMethod::tls_client()).map_err(|e| e.to_string())?;
+    builder.set_cipher_list(cipher_list).map_err(|e| e.to_string())?;
+    builder.set_sigalgs_list(sigalgs).map_err(|e| e.to_string())?;
+    // Only the certificate itself is of interest; its validity is judged by the main handshake.
+    builder.set_verify(SslVerifyMode::NONE);
+
+    let stream = connect_tcp(target).map_err(|e| format!("TCP Connection Error: {}", e))?;
+    let stream = builder.build().connect(target, stream).map_err(|e| format!("TLS Handshake Error: {}", e))?;
+    let cert = stream.ssl().peer_certificate().ok_or("No peer certificate")?;
+    cert.to_der().map_err(|e| e.to_string())
+}
+
+/// Forcing the key type needs OpenSSL, which native-tls does not use on this platform.
+#[cfg(any(target_os = "windows", target_vendor = "apple"))]
+fn fetch_certificate(_target: &str, _key_type: KeyType) -> Result<Vec<u8>, String> {
+    Err("Certificate type probing requires the OpenSSL backend".to_string())
 }
 
 /// A helper function to convert `x509_parser`'s `ASN1Time` to a `chrono::DateTime<Utc>`.
@@ -136,14 +604,18 @@ fn asn1_time_to_chrono_utc(time: &ASN1Time) -> DateTime<Utc> {
 /// Analyzes the results of the SSL scan to generate security findings.
 ///
 /// This function checks for handshake failures, missing certificates, expired certificates,
-/// and certificates that are expiring soon.
+/// certificates that are expiring soon, negotiated TLS compression, wildcard usage,
+/// Must-Staple certificates served without stapling, and per-key-type certificates.
 ///
 /// # Arguments
 /// * `results` - A reference to the `SslResults` from the scan.
+/// * `target` - The scanned host, used to check what a wildcard certificate covers.
+/// * `cert_expiry` - The windows within which an upcoming expiry is a Warning or Critical.
+/// * `recently_issued_hours` - How new a certificate is reported as `SSL_RECENTLY_ISSUED`; never when `None`.
 ///
 /// # Returns
 /// A vector of `AnalysisFinding` structs.
-fn analyze_ssl_results(results: &SslResults) -> Vec<AnalysisFinding> {
+fn analyze_ssl_results(results: &SslResults, target: &str, cert_expiry: ExpiryWindows, recently_issued_hours: Option<i64>) -> Vec<AnalysisFinding> {
     debug!("Analyzing SSL scan results.");
     let mut analyses = Vec::new();
 
@@ -152,6 +624,11 @@ fn analyze_ssl_results(results: &SslResults) -> Vec<AnalysisFinding> {
         Err(_) => {
             debug!("Scan failed, adding SSL_HANDSHAKE_FAILED finding.");
             analyses.push(AnalysisFinding::new(Severity::Critical, "SSL_HANDSHAKE_FAILED"));
+            // Explains the failure: the certificate is the platform's default, not one for this host.
+            if let Some(platform) = &results.default_certificate {
+                debug!(platform, "Default certificate served, adding SSL_DEFAULT_CERT finding.");
+                analyses.push(AnalysisFinding::new(Severity::Info, "SSL_DEFAULT_CERT").with_subject(platform));
+            }
         },
         // Successfully connected, but the server didn't provide a certificate.
         Ok(None) => {
@@ -165,14 +642,190 @@ fn analyze_ssl_results(results: &SslResults) -> Vec<AnalysisFinding> {
                 analyses.push(AnalysisFinding::new(Severity::Critical, "SSL_EXPIRED"));
             }
 
-            // Flag certificates that are expiring within the next 30 days.
+            // Flag certificates that are expiring within the configured windows (30 days by default).
             let days_left = ssl_data.certificate_info.days_until_expiry;
-            if (0..=30).contains(&days_left) {
-                debug!(days_left, "Certificate is expiring soon, adding SSL_EXPIRING_SOON finding.");
-                analyses.push(AnalysisFinding::new(Severity::Warning, "SSL_EXPIRING_SOON"));
+            let expiring_severity = if cert_expiry.cert_expiry_critical_days.is_some_and(|days| (0..=days).contains(&days_left)) {
+                Some(Severity::Critical)
+            } else if (0..=cert_expiry.cert_expiry_warning_days).contains(&days_left) {
+                Some(Severity::Warning)
+            } else {
+                None
+            };
+            if let Some(severity) = expiring_severity {
+                debug!(days_left, ?severity, "Certificate is expiring soon, adding SSL_EXPIRING_SOON finding.");
+                analyses.push(AnalysisFinding::new(severity, "SSL_EXPIRING_SOON"));
+            }
+
+            // A fresh certificate may be a routine renewal, or a re-issuance after a compromise;
+            // either way, a change worth noticing when watching a site.
+            let hours = ssl_data.certificate_info.hours_since_issued;
+            if recently_issued_hours.is_some_and(|threshold| (0..threshold).contains(&hours)) {
+                debug!(hours, "Certificate issued recently, adding SSL_RECENTLY_ISSUED finding.");
+                let subject = format!("{} hour{} ago", hours, if hours == 1 { "" } else { "s" });
+                analyses.push(AnalysisFinding::new(Severity::Info, "SSL_RECENTLY_ISSUED").with_subject(&subject));
+            }
+
+            // The local trust store may still accept a CA the browsers have dropped, so the
+            // handshake passing does not mean visitors can connect.
+            let certificate = &ssl_data.certificate_info;
+            if let Some(issuer) = certificate.issuer_organization.as_deref()
+                .and_then(|organization| distrusted_issuer(organization, certificate.not_before))
+            {
+                debug!(ca = issuer.ca, since = issuer.since, "Issued by a distrusted CA, adding SSL_DISTRUSTED_ISSUER finding.");
+                let subject = format!("{} (distrusted since {})", issuer.ca, issuer.since);
+                analyses.push(AnalysisFinding::new(Severity::Warning, "SSL_DISTRUSTED_ISSUER").with_subject(&subject));
+            }
+
+            // TLS-level compression makes the connection vulnerable to CRIME.
+            if ssl_data.compression_enabled == Some(true) {
+                debug!("TLS compression negotiated, adding SSL_TLS_COMPRESSION finding.");
+                analyses.push(AnalysisFinding::new(Severity::Warning, "SSL_TLS_COMPRESSION"));
+            }
+
+            if ssl_data.is_wildcard {
+                let names = &ssl_data.certificate_info.subject_alt_names;
+                let wildcards = names.iter().filter(|name| name.starts_with("*.")).count();
+                // A certificate made up mostly of wildcards shares one key across many subdomains.
+                if wildcards * 2 > names.len() {
+                    debug!(wildcards, total = names.len(), "Mostly wildcard names, adding SSL_WILDCARD_CERT finding.");
+                    analyses.push(AnalysisFinding::new(Severity::Info, "SSL_WILDCARD_CERT"));
+                }
+
+                // `*.example.com` does not match `example.com`. The handshake already proved the
+                // scanned host is covered, so the registrable domain behind a subdomain is what can be missed.
+                let apex = domain::registrable_domain(target).unwrap_or_else(|| target.to_string());
+                let apex = apex.as_str();
+                let wildcard_for_apex = format!("*.{}", apex);
+                if names.iter().any(|name| name.eq_ignore_ascii_case(&wildcard_for_apex))
+                    && !names.iter().any(|name| name.eq_ignore_ascii_case(apex))
+                {
+                    debug!(apex, "Wildcard does not cover the bare domain, adding SSL_WILDCARD_APEX_NOT_COVERED finding.");
+                    analyses.push(AnalysisFinding::new(Severity::Warning, "SSL_WILDCARD_APEX_NOT_COVERED"));
+                }
+            }
+
+            // Strict clients refuse a Must-Staple certificate served without a stapled OCSP response.
+            if ssl_data.must_staple && ssl_data.ocsp_stapled == Some(false) {
+                debug!("Must-Staple certificate served without stapling, adding SSL_MUSTSTAPLE_NOT_STAPLED finding.");
+                analyses.push(AnalysisFinding::new(Severity::Critical, "SSL_MUSTSTAPLE_NOT_STAPLED"));
+            }
+
+            // Without resumption every connection pays for a full handshake.
+            if ssl_data.resumption_supported == Some(false) {
+                debug!("Session not resumed, adding SSL_NO_SESSION_RESUMPTION finding.");
+                analyses.push(AnalysisFinding::new(Severity::Info, "SSL_NO_SESSION_RESUMPTION"));
+            }
+
+            // Separate RSA and ECDSA certificates were found by the opt-in probe.
+            if ssl_data.certificates.len() > 1 {
+                debug!(count = ssl_data.certificates.len(), "Distinct certificates per key type, adding SSL_DUAL_CERT finding.");
+                analyses.push(AnalysisFinding::new(Severity::Info, "SSL_DUAL_CERT"));
             }
         }
     }
     
     analyses
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::scanner::test_support::codes;
+
+    fn certificate_issued_hours_ago(hours: i64) -> SslResults {
+        let now = Utc::now();
+        let certificate_info = CertificateInfo {
+            subject_name: "CN=example.com".to_string(),
+            issuer_name: "CN=R11, O=Let's Encrypt".to_string(),
+            issuer_organization: Some("Let's Encrypt".to_string()),
+            serial_number: None,
+            subject_alt_names: vec!["example.com".to_string()],
+            not_before: now - chrono::Duration::hours(hours),
+            not_after: now + chrono::Duration::days(89),
+            days_until_expiry: 89,
+            hours_since_issued: hours,
+        };
+        let data = SslData {
+            is_valid: true,
+            certificate_info,
+            is_wildcard: false,
+            alpn: Vec::new(),
+            compression_enabled: None,
+            certificates: Vec::new(),
+            must_staple: false,
+            ocsp_stapled: None,
+            resumption_supported: None,
+        };
+        SslResults { scan: Ok(Some(data)), ..Default::default() }
+    }
+
+    #[test]
+    fn reports_recent_issuance_only_when_enabled() {
+        let fresh = certificate_issued_hours_ago(5);
+        let findings = analyze_ssl_results(&fresh, "example.com", ExpiryWindows::default(), Some(48));
+        assert_eq!(codes(&findings), ["SSL_RECENTLY_ISSUED"]);
+        assert_eq!(findings[0].subject.as_deref(), Some("5 hours ago"));
+        assert!(analyze_ssl_results(&fresh, "example.com", ExpiryWindows::default(), None).is_empty());
+        assert!(analyze_ssl_results(&certificate_issued_hours_ago(72), "example.com", ExpiryWindows::default(), Some(48)).is_empty());
+    }
+
+    #[test]
+    fn reports_negotiated_tls_compression() {
+        let with_compression = |enabled: Option<bool>| {
+            let mut results = certificate_issued_hours_ago(100);
+            if let Ok(Some(data)) = &mut results.scan {
+                data.compression_enabled = enabled;
+            }
+            analyze_ssl_results(&results, "example.com", ExpiryWindows::default(), None)
+        };
+        assert_eq!(codes(&with_compression(Some(true))), ["SSL_TLS_COMPRESSION"]);
+        assert!(with_compression(Some(false)).is_empty());
+        // A backend that cannot tell reports nothing either way.
+        assert!(with_compression(None).is_empty());
+    }
+
+    #[test]
+    fn reports_a_wildcard_that_misses_the_registrable_domain() {
+        let analyze = |names: &[&str], target: &str| {
+            let mut results = certificate_issued_hours_ago(100);
+            if let Ok(Some(data)) = &mut results.scan {
+                data.is_wildcard = true;
+                data.certificate_info.subject_alt_names = names.iter().map(|name| name.to_string()).collect();
+            }
+            analyze_ssl_results(&results, target, ExpiryWindows::default(), None)
+        };
+        let names = ["*.example.co.uk", "shop.example.co.uk"];
+        assert_eq!(codes(&analyze(&names, "shop.example.co.uk")), ["SSL_WILDCARD_APEX_NOT_COVERED"]);
+        assert_eq!(codes(&analyze(&names, "www.example.co.uk")), ["SSL_WILDCARD_APEX_NOT_COVERED"]);
+        assert!(analyze(&["*.example.co.uk", "example.co.uk"], "shop.example.co.uk").is_empty());
+        // A wildcard one level further down says nothing about the bare domain.
+        assert_eq!(codes(&analyze(&["*.shop.example.co.uk"], "eu.shop.example.co.uk")), ["SSL_WILDCARD_CERT"]);
+    }
+
+    #[test]
+    fn matches_distrusted_issuers_by_organization() {
+        let now = Utc::now();
+        assert_eq!(distrusted_issuer("Entrust, Inc.", now).map(|issuer| issuer.ca), Some("Entrust"));
+        assert_eq!(distrusted_issuer(" geotrust inc. ", now).map(|issuer| issuer.ca), Some("Symantec"));
+        // DigiCert now issues under the old Symantec brands with its own organization.
+        assert!(distrusted_issuer("DigiCert Inc", now).is_none());
+        assert!(distrusted_issuer("Let's Encrypt", now).is_none());
+    }
+
+    #[test]
+    fn only_reports_certificates_issued_after_the_distrust_cutoff() {
+        let issued_on = |year, month, day| {
+            let mut results = certificate_issued_hours_ago(0);
+            if let Ok(Some(data)) = &mut results.scan {
+                data.certificate_info.issuer_organization = Some("Entrust, Inc.".to_string());
+                data.certificate_info.not_before = NaiveDate::from_ymd_opt(year, month, day).unwrap().and_hms_opt(12, 0, 0).unwrap().and_utc();
+            }
+            analyze_ssl_results(&results, "example.com", ExpiryWindows::default(), None)
+        };
+        // Entrust certificates issued up to October 31, 2024 are trusted until they expire.
+        assert!(issued_on(2024, 10, 31).is_empty());
+        let findings = issued_on(2024, 11, 1);
+        assert_eq!(codes(&findings), ["SSL_DISTRUSTED_ISSUER"]);
+        assert_eq!(findings[0].subject.as_deref(), Some("Entrust (distrusted since 2024)"));
+    }
+}
@@ -0,0 +1,228 @@
+// src/core/scanner/test_support.rs
+
+//! Local stand-ins for the network, so scanner tests are deterministic and offline:
+//! an HTTP server answering every request with one canned response, and a DNS server
+//! answering from a fixed set of records.
+
+use crate::core::models::AnalysisFinding;
+use crate::core::scanner::MainPage;
+use hickory_resolver::TokioAsyncResolver;
+use hickory_resolver::config::{NameServerConfig, NameServerConfigGroup, Protocol, ResolverConfig, ResolverOpts};
+use hickory_resolver::proto::op::{Message, MessageType, ResponseCode};
+use hickory_resolver::proto::rr::rdata::{A, CAA, MX, TXT};
+use hickory_resolver::proto::rr::{Name, RData, Record};
+use hickory_resolver::proto::serialize::binary::BinEncodable;
+use reqwest::StatusCode;
+use std::net::{Ipv4Addr, SocketAddr};
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, UdpSocket};
+use tokio::task::JoinHandle;
+
+/// Returns the codes of the findings, in order, for compact assertions.
+pub fn codes(findings: &[AnalysisFinding]) -> Vec<&str> {
+    findings.iter().map(|finding| finding.code.as_str()).collect()
+}
+
+/// The response a `MockHttpServer` sends to every request.
+#[derive(Debug, Clone)]
+pub struct MockResponse {
+    status: u16,
+    headers: Vec<(String, String)>,
+    body: String,
+}
+
+impl MockResponse {
+    /// A `200 OK` HTML page with the given body and no other headers.
+    pub fn html(body: &str) -> Self {
+        Self { status: 200, headers: vec![("Content-Type".to_string(), "text/html; charset=utf-8".to_string())], body: body.to_string() }
+    }
+
+    /// A `200 OK` JSON response, as an API would send.
+    pub fn json(body: &str) -> Self {
+        Self { status: 200, headers: vec![("Content-Type".to_string(), "application/json".to_string())], body: body.to_string() }
+    }
+
+    pub fn with_status(mut self, status: u16) -> Self {
+        self.status = status;
+        self
+    }
+
+    /// Adds a header; calling it twice with the same name sends the header twice.
+    pub fn with_header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let reason = StatusCode::from_u16(self.status).ok().and_then(|status| status.canonical_reason()).unwrap_or("");
+        let mut out = format!("HTTP/1.1 {} {}\r\n", self.status, reason);
+        for (name, value) in &self.headers {
+            out.push_str(&format!("{}: {}\r\n", name, value));
+        }
+        out.push_str(&format!("Content-Length: {}\r\nConnection: close\r\n\r\n", self.body.len()));
+        out.push_str(&self.body);
+        out.into_bytes()
+    }
+}
+
+/// A plain HTTP server on a free local port, answering every request with one response (or
+/// one per path, with `start_with_routes`). It stops when dropped.
+pub struct MockHttpServer {
+    /// Points both main-page URLs at the server, so the scanners never fall back to plain HTTP.
+    pub page: MainPage,
+    task: JoinHandle<()>,
+}
+
+impl MockHttpServer {
+    pub async fn start(response: MockResponse) -> Self {
+        Self::start_with_routes(response, Vec::new()).await
+    }
+
+    /// Like `start`, answering requests for the listed paths (e.g. "/wp-login.php") with their
+    /// own response, and every other path with `fallback`.
+    pub async fn start_with_routes(fallback: MockResponse, routes: Vec<(&str, MockResponse)>) -> Self {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).await.expect("bind the mock HTTP server");
+        let url = format!("http://{}/", listener.local_addr().expect("mock HTTP server address"));
+        let fallback = fallback.to_bytes();
+        let routes: Vec<(String, Vec<u8>)> = routes.into_iter().map(|(path, response)| (path.to_string(), response.to_bytes())).collect();
+        let task = tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                let fallback = fallback.clone();
+                let routes = routes.clone();
+                tokio::spawn(async move {
+                    // The scanners only send bodiless requests, so the head is all there is to read.
+                    let mut request = Vec::new();
+                    let mut buf = [0u8; 1024];
+                    while !request.windows(4).any(|window| window == b"\r\n\r\n") {
+                        match stream.read(&mut buf).await {
+                            Ok(0) | Err(_) => return,
+                            Ok(n) => request.extend_from_slice(&buf[..n]),
+                        }
+                    }
+                    // The request line is "METHOD /path HTTP/1.1".
+                    let head = String::from_utf8_lossy(&request);
+                    let path = head.split_whitespace().nth(1).unwrap_or("/");
+                    let reply = routes.iter().find(|(route, _)| route == path).map_or(&fallback, |(_, reply)| reply);
+                    let _ = stream.write_all(reply).await;
+                    let _ = stream.shutdown().await;
+                });
+            }
+        });
+        Self { page: MainPage { https_url: url.clone(), http_url: url }, task }
+    }
+}
+
+impl Drop for MockHttpServer {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// A TXT record, e.g. `txt("_dmarc.example.com.", "v=DMARC1; p=none")`.
+pub fn txt(name: &str, value: &str) -> Record {
+    txt_strings(name, &[value])
+}
+
+/// A TXT record made of several character-strings, as long records are published, e.g.
+/// `txt_strings("example.com.", &["v=spf1 include:_spf.exa", "mple.net -all"])`.
+pub fn txt_strings(name: &str, strings: &[&str]) -> Record {
+    let strings = strings.iter().map(|string| string.to_string()).collect();
+    Record::from_rdata(Name::from_str(name).expect("valid record name"), 300, RData::TXT(TXT::new(strings)))
+}
+
+/// An A record.
+pub fn a(name: &str, ip: Ipv4Addr) -> Record {
+    Record::from_rdata(Name::from_str(name).expect("valid record name"), 300, RData::A(A(ip)))
+}
+
+/// An MX record pointing at `exchange`, e.g. `mx("example.com.", 10, "mail.example.com.")`.
+pub fn mx(name: &str, preference: u16, exchange: &str) -> Record {
+    let exchange = Name::from_str(exchange).expect("valid exchange name");
+    Record::from_rdata(Name::from_str(name).expect("valid record name"), 300, RData::MX(MX::new(preference, exchange)))
+}
+
+/// A CAA record authorizing `issuer`, with the `issuewild` tag when `wildcard` is set, e.g.
+/// `caa("example.com.", false, "letsencrypt.org")`.
+pub fn caa(name: &str, wildcard: bool, issuer: &str) -> Record {
+    let issuer = Some(Name::from_str(issuer).expect("valid issuer name"));
+    let caa = if wildcard { CAA::new_issuewild(false, issuer, Vec::new()) } else { CAA::new_issue(false, issuer, Vec::new()) };
+    Record::from_rdata(Name::from_str(name).expect("valid record name"), 300, RData::CAA(caa))
+}
+
+/// A UDP DNS server on a free local port answering from a fixed set of records. Names
+/// without a matching record get an empty answer, or another response code with
+/// `start_with_missing_response`. It stops when dropped.
+pub struct MockDnsServer {
+    addr: SocketAddr,
+    /// The names queried so far, in arrival order.
+    queries: Arc<Mutex<Vec<String>>>,
+    task: JoinHandle<()>,
+}
+
+impl MockDnsServer {
+    pub async fn start(records: Vec<Record>) -> Self {
+        Self::start_with_missing_response(records, ResponseCode::NoError).await
+    }
+
+    /// Like `start`, answering queries without a matching record with `missing`, e.g.
+    /// `NXDomain` for a domain that does not exist or `ServFail` for a broken zone.
+    pub async fn start_with_missing_response(records: Vec<Record>, missing: ResponseCode) -> Self {
+        let socket = UdpSocket::bind((Ipv4Addr::LOCALHOST, 0)).await.expect("bind the mock DNS server");
+        let addr = socket.local_addr().expect("mock DNS server address");
+        let queries = Arc::new(Mutex::new(Vec::new()));
+        let queried = Arc::clone(&queries);
+        let task = tokio::spawn(async move {
+            let mut buf = [0u8; 512];
+            while let Ok((len, peer)) = socket.recv_from(&mut buf).await {
+                let Ok(request) = Message::from_vec(&buf[..len]) else { continue };
+                let mut response = Message::new();
+                response.set_id(request.id())
+                    .set_message_type(MessageType::Response)
+                    .set_op_code(request.op_code())
+                    .set_recursion_desired(request.recursion_desired())
+                    .set_recursion_available(true)
+                    .set_response_code(ResponseCode::NoError);
+                for query in request.queries() {
+                    queried.lock().unwrap().push(query.name().to_string());
+                    response.add_query(query.clone());
+                    let answers: Vec<Record> = records.iter()
+                        .filter(|record| record.name() == query.name() && record.record_type() == query.query_type())
+                        .cloned()
+                        .collect();
+                    if answers.is_empty() {
+                        response.set_response_code(missing);
+                    }
+                    response.add_answers(answers);
+                }
+                if let Ok(bytes) = response.to_bytes() {
+                    let _ = socket.send_to(&bytes, peer).await;
+                }
+            }
+        });
+        Self { addr, queries, task }
+    }
+
+    /// The names queried so far, e.g. "_dmarc.example.com.", in arrival order.
+    pub fn queried_names(&self) -> Vec<String> {
+        self.queries.lock().unwrap().clone()
+    }
+
+    /// A resolver that sends every query to this server, without caching or retries.
+    pub fn resolver(&self) -> TokioAsyncResolver {
+        let nameservers = NameServerConfigGroup::from(vec![NameServerConfig::new(self.addr, Protocol::Udp)]);
+        let mut opts = ResolverOpts::default();
+        opts.timeout = Duration::from_secs(2);
+        opts.attempts = 1;
+        opts.cache_size = 0;
+        TokioAsyncResolver::tokio(ResolverConfig::from_parts(None, Vec::new(), nameservers), opts)
+    }
+}
+
+impl Drop for MockDnsServer {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
@@ -0,0 +1,46 @@
+// src/core/scanner/throttle.rs
+
+//! Spacing of the requests the HTTP-based scanners send to the scanned host.
+//!
+//! The scanners run concurrently, so without coordination their requests reach the host
+//! all at once. They share one `Throttle` per scan instead, and each waits for its turn
+//! before sending a request.
+//!
+//! A turn covers one request as the scanner sends it. The shared HTTP client follows redirects
+//! on its own, and a redirect policy cannot wait, so the hops of a redirect chain go out right
+//! after each other: a fetch and its redirects count as one request. Only the redirect scanner,
+//! which follows the chain itself, waits before each hop.
+
+use std::sync::Mutex;
+use std::time::Duration;
+use tokio::time::{Instant, sleep_until};
+
+/// Hands out request slots at least `interval` apart. A zero interval never waits.
+#[derive(Debug)]
+pub struct Throttle {
+    interval: Duration,
+    /// The earliest time the next request may be sent; `None` before the first request.
+    next_slot: Mutex<Option<Instant>>,
+}
+
+impl Throttle {
+    pub fn new(interval: Duration) -> Self {
+        Self { interval, next_slot: Mutex::new(None) }
+    }
+
+    /// Waits until this caller may send its request. Callers are served in the order they
+    /// ask, each reserving its slot before sleeping, so concurrent requests stay spaced out.
+    pub async fn wait(&self) {
+        if self.interval.is_zero() {
+            return;
+        }
+        let slot = {
+            let mut next_slot = self.next_slot.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+            let now = Instant::now();
+            let slot = next_slot.map_or(now, |next| next.max(now));
+            *next_slot = Some(slot + self.interval);
+            slot
+        };
+        sleep_until(slot).await;
+    }
+}
@@ -0,0 +1,61 @@
+// src/core/scanner/waf.rs
+
+//! Recognition of the challenge and block pages web application firewalls (WAFs) answer
+//! automated requests with.
+//!
+//! A blocked scanner sees the WAF's page instead of the site, so the headers and body it
+//! records say nothing about the site itself. Recognizing the page lets the scanners report
+//! the block instead of misleading findings.
+
+use reqwest::StatusCode;
+use reqwest::header::HeaderMap;
+
+/// The statuses WAFs answer blocked or challenged requests with.
+const BLOCK_STATUSES: [StatusCode; 2] = [StatusCode::FORBIDDEN, StatusCode::TOO_MANY_REQUESTS];
+
+/// Header signatures: the WAF, a header name and a substring its value must contain
+/// (case-insensitive; empty matches any value).
+const HEADER_SIGNATURES: &[(&str, &str, &str)] = &[
+    ("Cloudflare", "cf-mitigated", "challenge"),
+    ("AWS WAF", "x-amzn-waf-action", ""),
+    ("Akamai", "server", "akamaighost"),
+    ("DataDome", "x-datadome", ""),
+    ("Sucuri", "x-sucuri-block", ""),
+];
+
+/// Body signatures: the WAF and a lowercase marker its challenge or block page contains.
+const BODY_SIGNATURES: &[(&str, &str)] = &[
+    ("Cloudflare", "<title>just a moment...</title>"),
+    ("Cloudflare", "attention required! | cloudflare"),
+    ("Imperva", "incapsula incident id"),
+    ("Imperva", "_incapsula_resource"),
+    ("Sucuri", "sucuri website firewall"),
+    ("F5 BIG-IP ASM", "the requested url was rejected. please consult with your administrator."),
+    ("ModSecurity", "this error was generated by mod_security"),
+    ("Wordfence", "generated by wordfence"),
+];
+
+/// How much of the body is searched for a signature; challenge pages put them near the top.
+pub(crate) const BODY_SCAN_LIMIT: usize = 16 * 1024;
+
+/// Returns the name of the WAF whose challenge or block page this response is, if any.
+///
+/// Only 403 and 429 responses are considered, so a site that merely sits behind a WAF
+/// (and was let through) is never reported as blocked.
+pub fn detect_block(status: StatusCode, headers: &HeaderMap, body: &[u8]) -> Option<&'static str> {
+    if !BLOCK_STATUSES.contains(&status) {
+        return None;
+    }
+    let by_header = HEADER_SIGNATURES.iter().find(|(_, name, marker)| {
+        headers.get_all(*name).iter()
+            .filter_map(|value| value.to_str().ok())
+            .any(|value| value.to_ascii_lowercase().contains(marker))
+    });
+    if let Some((waf, _, _)) = by_header {
+        return Some(waf);
+    }
+    let start = String::from_utf8_lossy(&body[..body.len().min(BODY_SCAN_LIMIT)]).to_ascii_lowercase();
+    BODY_SIGNATURES.iter()
+        .find(|(_, marker)| start.contains(marker))
+        .map(|(waf, _)| *waf)
+}
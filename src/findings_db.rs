@@ -0,0 +1,219 @@
+// src/findings_db.rs
+
+//! An SQLite database of every finding of every scan, for trend reports across a portfolio
+//! of targets (built with the `findings-db` feature).
+//!
+//! The JSON-lines history only keeps each scan's score and counts; this database keeps the
+//! findings themselves, so questions like "which domains still have no DMARC record" or
+//! "what was fixed this month" can be answered with `--query`.
+
+use crate::core::models::AnalysisFinding;
+use crate::logging;
+use chrono::{DateTime, Datelike, Local, SecondsFormat, TimeZone, Utc};
+use rusqlite::{Connection, params};
+use std::fmt;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+/// The name of the database file inside the application's data directory.
+pub const DB_FILE: &str = "findings.sqlite";
+
+/// One row per scan, and one row per finding of that scan. Timestamps are RFC 3339 in UTC,
+/// so they sort and compare as text.
+const SCHEMA: &str = "
+    CREATE TABLE IF NOT EXISTS scans (
+        id INTEGER PRIMARY KEY,
+        target TEXT NOT NULL,
+        scanned_at TEXT NOT NULL,
+        score INTEGER NOT NULL
+    );
+    CREATE TABLE IF NOT EXISTS findings (
+        scan_id INTEGER NOT NULL REFERENCES scans(id),
+        code TEXT NOT NULL,
+        severity TEXT NOT NULL,
+        subject TEXT
+    );
+    CREATE INDEX IF NOT EXISTS scans_by_target ON scans (target, scanned_at);
+    CREATE INDEX IF NOT EXISTS findings_by_scan ON findings (scan_id, code);
+";
+
+/// The targets whose most recent scan reports a finding code, with that scan's time.
+const OPEN_QUERY: &str = "
+    SELECT s.target, f.code, s.scanned_at
+    FROM scans s
+    JOIN findings f ON f.scan_id = s.id
+    WHERE f.code = ?1
+      AND s.scanned_at = (SELECT MAX(latest.scanned_at) FROM scans latest WHERE latest.target = s.target)
+    GROUP BY s.target
+    ORDER BY s.target
+";
+
+/// The findings that stopped being reported since a given time: for each target and code,
+/// the first scan after the last one reporting it is when it was fixed.
+const FIXED_SINCE_QUERY: &str = "
+    WITH last_seen AS (
+        SELECT s.target, f.code, MAX(s.scanned_at) AS seen_at
+        FROM findings f
+        JOIN scans s ON s.id = f.scan_id
+        GROUP BY s.target, f.code
+    )
+    SELECT last_seen.target, last_seen.code, MIN(later.scanned_at) AS fixed_at
+    FROM last_seen
+    JOIN scans later ON later.target = last_seen.target AND later.scanned_at > last_seen.seen_at
+    GROUP BY last_seen.target, last_seen.code
+    HAVING fixed_at >= ?1
+    ORDER BY fixed_at, last_seen.target, last_seen.code
+";
+
+/// The canned trend reports `--query` can run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TrendQuery {
+    /// `open:CODE`: targets whose latest scan still reports the finding, e.g. `open:DNS_DMARC_MISSING`.
+    Open(String),
+    /// `fixed-this-month`: findings that stopped being reported since the 1st of this month.
+    FixedThisMonth,
+}
+
+impl FromStr for TrendQuery {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("fixed-this-month") {
+            return Ok(TrendQuery::FixedThisMonth);
+        }
+        match s.split_once(':') {
+            Some((kind, code)) if kind.eq_ignore_ascii_case("open") && !code.trim().is_empty() => {
+                Ok(TrendQuery::Open(code.trim().to_ascii_uppercase()))
+            }
+            _ => Err(format!("unknown query '{}' (expected 'open:CODE' or 'fixed-this-month')", s)),
+        }
+    }
+}
+
+/// One line of a trend report: a target, a finding code, and when it was last seen or fixed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrendRow {
+    pub target: String,
+    pub code: String,
+    pub at: String,
+}
+
+impl fmt::Display for TrendRow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}\t{}\t{}", self.target, self.code, self.at)
+    }
+}
+
+/// Returns the full path of the database file.
+pub fn path() -> PathBuf {
+    logging::get_data_dir().join(DB_FILE)
+}
+
+/// Opens the database file, creating it and its tables if needed.
+pub fn open() -> rusqlite::Result<Connection> {
+    let path = path();
+    if let Some(parent) = path.parent() {
+        // A missing directory surfaces as the open error below.
+        let _ = std::fs::create_dir_all(parent);
+    }
+    let conn = Connection::open(path)?;
+    conn.execute_batch(SCHEMA)?;
+    Ok(conn)
+}
+
+/// Records a completed scan in the database file, creating it if needed.
+///
+/// # Arguments
+/// * `target` - The scanned host, e.g. "example.com".
+/// * `score` - The scan's score.
+/// * `findings` - The findings as reported, i.e. after severity overrides and ignores.
+/// * `scanned_at` - When the scan finished.
+pub fn append<'a>(target: &str, score: u8, findings: impl IntoIterator<Item = &'a AnalysisFinding>, scanned_at: DateTime<Local>) -> rusqlite::Result<()> {
+    record(&mut open()?, target, score, findings, scanned_at)
+}
+
+/// Records a completed scan and its findings in one transaction.
+fn record<'a>(conn: &mut Connection, target: &str, score: u8, findings: impl IntoIterator<Item = &'a AnalysisFinding>, scanned_at: DateTime<Local>) -> rusqlite::Result<()> {
+    let tx = conn.transaction()?;
+    tx.execute(
+        "INSERT INTO scans (target, scanned_at, score) VALUES (?1, ?2, ?3)",
+        params![target, timestamp(scanned_at), score],
+    )?;
+    let scan_id = tx.last_insert_rowid();
+    {
+        let mut insert = tx.prepare("INSERT INTO findings (scan_id, code, severity, subject) VALUES (?1, ?2, ?3, ?4)")?;
+        for finding in findings {
+            insert.execute(params![scan_id, finding.code, format!("{:?}", finding.severity), finding.subject])?;
+        }
+    }
+    tx.commit()
+}
+
+/// Runs a canned trend report. `now` sets the month of `FixedThisMonth`.
+pub fn run_query(conn: &Connection, query: &TrendQuery, now: DateTime<Local>) -> rusqlite::Result<Vec<TrendRow>> {
+    let (sql, parameter) = match query {
+        TrendQuery::Open(code) => (OPEN_QUERY, code.clone()),
+        TrendQuery::FixedThisMonth => {
+            let month_start = Local.with_ymd_and_hms(now.year(), now.month(), 1, 0, 0, 0).earliest().unwrap_or(now);
+            (FIXED_SINCE_QUERY, timestamp(month_start))
+        }
+    };
+    let mut statement = conn.prepare(sql)?;
+    let rows = statement.query_map([parameter], |row| Ok(TrendRow { target: row.get(0)?, code: row.get(1)?, at: row.get(2)? }))?;
+    rows.collect()
+}
+
+/// Formats a time the way the database stores it.
+fn timestamp(time: DateTime<Local>) -> String {
+    time.with_timezone(&Utc).to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::models::Severity;
+
+    fn day(day: u32) -> DateTime<Local> {
+        Local.with_ymd_and_hms(2026, 3, day, 12, 0, 0).unwrap()
+    }
+
+    fn database(scans: &[(&str, u32, &[&str])]) -> Connection {
+        let mut conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(SCHEMA).unwrap();
+        for (target, scanned_on, codes) in scans {
+            let findings: Vec<AnalysisFinding> = codes.iter().map(|code| AnalysisFinding::new(Severity::Warning, code)).collect();
+            record(&mut conn, target, 100, &findings, day(*scanned_on)).unwrap();
+        }
+        conn
+    }
+
+    #[test]
+    fn open_only_looks_at_the_latest_scan() {
+        let conn = database(&[
+            ("a.example", 1, &["DNS_DMARC_MISSING"]),
+            ("a.example", 2, &[]),
+            ("b.example", 1, &["DNS_DMARC_MISSING", "DNS_SPF_MISSING"]),
+        ]);
+        let rows = run_query(&conn, &TrendQuery::Open("DNS_DMARC_MISSING".to_string()), day(3)).unwrap();
+        let targets: Vec<&str> = rows.iter().map(|row| row.target.as_str()).collect();
+        assert_eq!(targets, ["b.example"]);
+    }
+
+    #[test]
+    fn fixed_this_month_dates_each_fix_to_the_first_clean_scan() {
+        let conn = database(&[
+            ("a.example", 1, &["DNS_DMARC_MISSING", "DNS_SPF_MISSING"]),
+            ("a.example", 5, &["DNS_SPF_MISSING"]),
+            ("a.example", 9, &["DNS_SPF_MISSING"]),
+        ]);
+        let rows = run_query(&conn, &TrendQuery::FixedThisMonth, day(20)).unwrap();
+        assert_eq!(rows, [TrendRow { target: "a.example".to_string(), code: "DNS_DMARC_MISSING".to_string(), at: timestamp(day(5)) }]);
+    }
+
+    #[test]
+    fn parses_queries() {
+        assert_eq!("open:dns_dmarc_missing".parse(), Ok(TrendQuery::Open("DNS_DMARC_MISSING".to_string())));
+        assert_eq!("fixed-this-month".parse(), Ok(TrendQuery::FixedThisMonth));
+        assert!("open:".parse::<TrendQuery>().is_err());
+    }
+}
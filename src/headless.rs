@@ -0,0 +1,336 @@
+// src/headless.rs
+
+use crate::app;
+use crate::cli::OutputFormat;
+use crate::config::Config;
+use crate::core::export::batch_summary::{self, SummaryFormat};
+use crate::core::export::{self, ExportFormat, checklist, tech_inventory, text_table};
+use crate::core::models::{ScanReport, ScoringProfile, Severity};
+use crate::core::scanner;
+use crate::core::scanner::pipeline::{self, ScanPlan};
+use crate::history::{self, HistoryEntry};
+use chrono::Local;
+use color_eyre::eyre::{Result, WrapErr};
+use serde::Serialize;
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::iter;
+use std::path::Path;
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+use tracing::{info, warn};
+
+/// The exit code used when every scan completed but at least one target is not clean.
+/// Failures of the tool itself exit with 1, so scripts can tell the two apart.
+pub const EXIT_NOT_CLEAN: u8 = 2;
+
+/// The target argument that stands for the targets read from stdin.
+pub const STDIN_TARGET: &str = "-";
+
+/// Expands the targets given on the command line, reading stdin in place of `STDIN_TARGET`.
+///
+/// Stdin is read lazily, one line at a time, so each target is scanned as soon as its line
+/// arrives and the run ends at EOF.
+pub fn expand_targets(targets: &[String]) -> impl Iterator<Item = String> + '_ {
+    targets.iter().flat_map(|target| -> Box<dyn Iterator<Item = String>> {
+        if target == STDIN_TARGET {
+            Box::new(read_targets(io::stdin().lock()))
+        } else {
+            Box::new(iter::once(target.clone()))
+        }
+    })
+}
+
+/// A report as a line of JSON lines output, named after its target since lines are read one
+/// at a time.
+#[derive(Serialize)]
+struct JsonLine<'a> {
+    target: &'a str,
+    #[serde(flatten)]
+    report: &'a ScanReport,
+}
+
+/// What `dry_run` prints for one target: its scan plan, with the settings that shape the scan
+/// without showing in it.
+#[derive(Serialize)]
+struct DryRun<'a> {
+    /// The target as given, before normalization.
+    input: &'a str,
+    #[serde(flatten)]
+    plan: ScanPlan,
+    scoring_profile: ScoringProfile,
+    strict: bool,
+    offline: bool,
+    /// Whether the scan would move on to the host the main page redirects to.
+    follow_canonical_redirect: bool,
+}
+
+/// Reads one target per line, skipping blank lines and `#` comments.
+fn read_targets(reader: impl BufRead) -> impl Iterator<Item = String> {
+    reader.lines()
+        .map_while(|line| line.inspect_err(|e| warn!(error = %e, "Stopped reading targets from stdin.")).ok())
+        .map(|line| line.trim().to_string())
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+}
+
+/// Scans the given targets one after another without starting the TUI.
+///
+/// Each report is written to stdout in the requested format (JSON or a text table), honoring
+/// the configured export severity threshold, while a one-line status per target goes to stderr. Keeping the two streams
+/// apart lets the JSON be piped or redirected while progress stays visible. Stdout is flushed
+/// after each target, so a tool reading the output sees every report as soon as it is done.
+///
+/// With `export_dir` set, each report is also saved there as a JSON file, as the TUI would export it.
+/// With `compact`, that file and the `Json` output are minified instead of pretty-printed.
+/// With `summary` set, an overview of all targets is also written to that file once the run ends.
+///
+/// Returns success only if every target is clean (see `ScanReport::is_clean`), and
+/// `EXIT_NOT_CLEAN` otherwise.
+///
+/// # Arguments
+///
+/// * `targets` - The targets as given on the command line (domains or URLs), see `expand_targets`.
+/// * `config` - The loaded configuration, including command-line overrides.
+/// * `format` - The format the reports are written in.
+/// * `color` - Whether the table format may use ANSI colors.
+/// * `compact` - Whether JSON reports are written on a single line.
+/// * `summary` - Where to write the overview of the whole run, if anywhere.
+/// * `export_dir` - Where to save each report as a JSON file, if anywhere.
+pub async fn run(
+    targets: impl IntoIterator<Item = String>,
+    config: &Config,
+    format: OutputFormat,
+    color: bool,
+    compact: bool,
+    summary: Option<&Path>,
+    export_dir: Option<&Path>,
+) -> Result<ExitCode> {
+    let mut all_clean = true;
+    let mut reports = Vec::new();
+    for (index, raw_target) in targets.into_iter().enumerate() {
+        let target = scanner::target_host(&raw_target);
+        info!(target = %target, "Starting headless scan");
+
+        let mut report = scanner::run_full_scan(&target, config.scan_options()).await;
+        config.apply_to_report(&target, &mut report);
+        eprintln!("{}", report.status_line(&target));
+        if config.offline {
+            eprintln!("{}", egress_line(&report));
+        }
+        app::log_findings(&target, report.findings());
+        all_clean &= report.is_clean();
+        record_scan(&target, &report);
+
+        let exported = match &config.export_min_severity {
+            Some(min_severity) => report.filtered_by_severity(min_severity),
+            None => report.clone(),
+        };
+        if let Some(dir) = export_dir {
+            let path = export::write_report(&exported, &target, &ExportFormat::Json, dir, compact)
+                .wrap_err_with(|| format!("Failed to export the report to {}", dir.display()))?;
+            info!(path = %path.display(), "Report exported successfully");
+        }
+        match format {
+            OutputFormat::Json => {
+                let json = export::to_json(&exported, compact).wrap_err("Failed to serialize the report")?;
+                println!("{}", json);
+            }
+            OutputFormat::Jsonl => {
+                let line = JsonLine { target: &target, report: &exported };
+                let json = serde_json::to_string(&line).wrap_err("Failed to serialize the report")?;
+                println!("{}", json);
+            }
+            OutputFormat::Table => {
+                // The blank line from `println!` separates the tables of consecutive targets.
+                println!("{}", text_table::render(&target, &report, config.export_min_severity.as_ref(), color));
+            }
+            OutputFormat::Tech => {
+                // One CSV for the whole run: the header comes with the first target only.
+                let technologies = report.fingerprint_results.technologies.as_deref().unwrap_or_default();
+                print!("{}", tech_inventory::render_csv(&target, technologies, index == 0));
+            }
+            OutputFormat::Checklist => {
+                // The blank line from `println!` separates the checklists of consecutive targets.
+                println!("{}", checklist::render(&target, &exported));
+            }
+        }
+        io::stdout().flush().wrap_err("Failed to write the report")?;
+        if summary.is_some() {
+            reports.push((target, report));
+        }
+    }
+
+    if let Some(path) = summary {
+        let content = batch_summary::render(&reports, config.export_min_severity.as_ref(), Local::now(), SummaryFormat::from_path(path));
+        fs::write(path, content).wrap_err_with(|| format!("Failed to write the summary to {}", path.display()))?;
+        info!(path = %path.display(), "Batch summary written.");
+        eprintln!("Summary written to {}", path.display());
+    }
+    Ok(if all_clean { ExitCode::SUCCESS } else { ExitCode::from(EXIT_NOT_CLEAN) })
+}
+
+/// Prints what scanning the given targets would do, without any network activity: one JSON
+/// document per target with the normalized host, the scanners that would run and the requests
+/// each would send (see `pipeline::plan_scan`). It verifies the configuration and the
+/// command-line overrides before a real scan.
+///
+/// # Arguments
+///
+/// * `targets` - The targets as given on the command line (domains or URLs), see `expand_targets`.
+/// * `config` - The loaded configuration, including command-line overrides.
+/// * `compact` - Whether the JSON is written on a single line.
+pub fn dry_run(targets: impl IntoIterator<Item = String>, config: &Config, compact: bool) -> Result<ExitCode> {
+    let options = config.scan_options();
+    let scanners = pipeline::default_scanners();
+    for raw_target in targets {
+        let target = scanner::target_host(&raw_target);
+        let dry_run = DryRun {
+            input: &raw_target,
+            plan: pipeline::plan_scan(&scanners, &target, &options),
+            scoring_profile: config.scoring_profile,
+            strict: config.strict,
+            offline: config.offline,
+            follow_canonical_redirect: config.follow_canonical_redirect,
+        };
+        let json = if compact { serde_json::to_string(&dry_run) } else { serde_json::to_string_pretty(&dry_run) };
+        println!("{}", json.wrap_err("Failed to serialize the scan plan")?);
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+/// When `run_until_clean` tries again, and when it gives up.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// The wait between two attempts.
+    pub interval: Duration,
+    /// Give up after this many attempts; `None` keeps trying.
+    pub max_attempts: Option<u32>,
+    /// Give up once this much time has passed since the first attempt; `None` keeps trying.
+    pub timeout: Option<Duration>,
+}
+
+impl RetryPolicy {
+    /// Whether another attempt may start after `attempts` of them, `elapsed` into the run.
+    /// An attempt that could only start past the timeout is not made.
+    fn allows_another(&self, attempts: u32, elapsed: Duration) -> bool {
+        self.max_attempts.is_none_or(|max| attempts < max)
+            && self.timeout.is_none_or(|timeout| elapsed + self.interval <= timeout)
+    }
+}
+
+/// Rescans the given targets until every one of them is clean (see `ScanReport::is_clean`),
+/// for waiting on a fix to take effect, e.g. a DNS change to propagate.
+///
+/// Each attempt rescans the targets that were not clean yet and writes one status line per
+/// target to stderr. Only the last scan of each target is recorded in the history.
+///
+/// Returns success once every target is clean, and `EXIT_NOT_CLEAN` when the policy gives up first.
+///
+/// # Arguments
+///
+/// * `targets` - The targets as given on the command line (domains or URLs).
+/// * `config` - The loaded configuration, including command-line overrides.
+/// * `policy` - The interval between attempts and when to give up.
+pub async fn run_until_clean(targets: &[String], config: &Config, policy: &RetryPolicy) -> Result<ExitCode> {
+    let started = Instant::now();
+    let mut pending: Vec<String> = targets.iter().map(|target| scanner::target_host(target)).collect();
+    let mut attempts = 0;
+    loop {
+        attempts += 1;
+        let mut not_clean = Vec::new();
+        for target in pending {
+            info!(target = %target, attempt = attempts, "Starting headless scan");
+            let mut report = scanner::run_full_scan(&target, config.scan_options()).await;
+            config.apply_to_report(&target, &mut report);
+            info!(target = %target, attempt = attempts, score = report.score(), clean = report.is_clean(), "Attempt finished.");
+            eprintln!("attempt {}: {}", attempts, report.status_line(&target));
+            if config.offline {
+                eprintln!("{}", egress_line(&report));
+            }
+            app::log_findings(&target, report.findings());
+            if report.is_clean() {
+                record_scan(&target, &report);
+            } else {
+                not_clean.push((target, report));
+            }
+        }
+
+        if not_clean.is_empty() {
+            eprintln!("Clean after {} attempt(s).", attempts);
+            return Ok(ExitCode::SUCCESS);
+        }
+        if !policy.allows_another(attempts, started.elapsed()) {
+            for (target, report) in &not_clean {
+                record_scan(target, report);
+            }
+            let targets: Vec<&str> = not_clean.iter().map(|(target, _)| target.as_str()).collect();
+            warn!(attempts, targets = ?targets, "Giving up: still not clean.");
+            eprintln!("Still not clean after {} attempt(s): {}", attempts, targets.join(", "));
+            return Ok(ExitCode::from(EXIT_NOT_CLEAN));
+        }
+        pending = not_clean.into_iter().map(|(target, _)| target).collect();
+        tokio::time::sleep(policy.interval).await;
+    }
+}
+
+/// Summarizes the hosts an offline scan contacted, e.g. "offline: all clear, contacted
+/// 8.8.8.8, example.com", or the hosts it refused when there were any.
+fn egress_line(report: &ScanReport) -> String {
+    let contacted = if report.contacted_hosts.is_empty() { "nothing".to_string() } else { report.contacted_hosts.join(", ") };
+    if report.blocked_hosts.is_empty() {
+        format!("offline: all clear, contacted {}", contacted)
+    } else {
+        format!("offline: refused {} (contacted {})", report.blocked_hosts.join(", "), contacted)
+    }
+}
+
+/// Records a finished scan in the history, and in the findings database when built with it.
+fn record_scan(target: &str, report: &ScanReport) {
+    let entry = HistoryEntry {
+        target: target.to_string(),
+        timestamp: Local::now(),
+        score: report.score(),
+        critical: report.findings().filter(|f| f.severity == Severity::Critical).count(),
+        warning: report.findings().filter(|f| f.severity == Severity::Warning).count(),
+    };
+    if let Err(e) = history::append(&entry) {
+        warn!(error = %e, "Could not append to the scan history.");
+    }
+    #[cfg(feature = "findings-db")]
+    if let Err(e) = crate::findings_db::append(target, entry.score, report.findings(), entry.timestamp) {
+        warn!(error = %e, "Could not record the findings in the findings database.");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_one_target_per_line() {
+        let input = "example.com\n\n  # staging\n https://github.com/ \nlast.example";
+        let targets: Vec<String> = read_targets(io::Cursor::new(input)).collect();
+        assert_eq!(targets, ["example.com", "https://github.com/", "last.example"]);
+    }
+
+    #[test]
+    fn retry_policy_stops_at_the_first_limit_reached() {
+        let policy = RetryPolicy { interval: Duration::from_secs(60), max_attempts: Some(3), timeout: Some(Duration::from_secs(150)) };
+        assert!(policy.allows_another(1, Duration::from_secs(10)));
+        assert!(!policy.allows_another(3, Duration::from_secs(10)));
+        // The next attempt would start after the timeout.
+        assert!(!policy.allows_another(2, Duration::from_secs(100)));
+        let unlimited = RetryPolicy { interval: Duration::from_secs(60), max_attempts: None, timeout: None };
+        assert!(unlimited.allows_another(1000, Duration::from_secs(86_400)));
+    }
+
+    #[test]
+    fn egress_line_says_when_nothing_was_contacted() {
+        let mut report = ScanReport { blocked_hosts: vec!["cdn.example.net".to_string()], ..Default::default() };
+        assert_eq!(egress_line(&report), "offline: refused cdn.example.net (contacted nothing)");
+        report.blocked_hosts.clear();
+        assert_eq!(egress_line(&report), "offline: all clear, contacted nothing");
+        report.contacted_hosts = vec!["8.8.8.8".to_string(), "example.com".to_string()];
+        assert_eq!(egress_line(&report), "offline: all clear, contacted 8.8.8.8, example.com");
+    }
+}
@@ -0,0 +1,64 @@
+// src/history.rs
+
+use crate::logging;
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::PathBuf;
+use tracing::warn;
+
+/// The name of the scan history file inside the application's data directory.
+pub const HISTORY_FILE: &str = "history.jsonl";
+
+/// One completed scan, stored as a single JSON line in the history file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    /// The scanned host, e.g. "example.com".
+    pub target: String,
+    pub timestamp: DateTime<Local>,
+    pub score: u8,
+    pub critical: usize,
+    pub warning: usize,
+}
+
+/// Returns the full path of the history file.
+pub fn path() -> PathBuf {
+    logging::get_data_dir().join(HISTORY_FILE)
+}
+
+/// Appends an entry to the history file, creating it if needed.
+pub fn append(entry: &HistoryEntry) -> io::Result<()> {
+    let path = path();
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{}", serde_json::to_string(entry)?)
+}
+
+/// Loads every entry of the history file, oldest first.
+/// A missing file is an empty history; lines that cannot be parsed are skipped.
+pub fn load() -> Vec<HistoryEntry> {
+    let Ok(content) = fs::read_to_string(path()) else { return Vec::new(); };
+    content.lines()
+        .filter(|line| !line.trim().is_empty())
+        .filter_map(|line| match serde_json::from_str(line) {
+            Ok(entry) => Some(entry),
+            Err(e) => {
+                warn!(error = %e, "Skipping unreadable history entry.");
+                None
+            }
+        })
+        .collect()
+}
+
+/// The distinct targets of the history, ordered from least to most recently scanned.
+pub fn recent_targets(entries: &[HistoryEntry]) -> Vec<String> {
+    let mut targets: Vec<String> = Vec::new();
+    for entry in entries {
+        targets.retain(|target| target != &entry.target);
+        targets.push(entry.target.clone());
+    }
+    targets
+}
@@ -43,6 +43,18 @@ pub fn get_data_dir() -> PathBuf {
     }
 }
 
+/// Determines the appropriate configuration directory for the application.
+///
+/// Mirrors `get_data_dir`: the standard system-specific config directory is
+/// preferred, falling back to a `.config` subdirectory in the current working directory.
+pub fn get_config_dir() -> PathBuf {
+    if let Some(proj_dirs) = project_directory() {
+        proj_dirs.config_dir().to_path_buf()
+    } else {
+        PathBuf::from(".").join(".config")
+    }
+}
+
 /// Initializes the `tracing` subscriber for file-based logging.
 ///
 /// This function sets up a log file in the application's data directory and configures
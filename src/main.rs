@@ -1,87 +1,363 @@
 // src/main.rs
 
-use color_eyre::eyre::Result;
-use tracing::{debug, error, info};
+use clap::Parser;
+use color_eyre::eyre::{Result, WrapErr};
+use tracing::{debug, error, info, warn};
 use crate::app::{App, AppState, ExportStatus};
-use chrono::Local;
+use crate::cli::OutputFormat;
+use crate::config::CompletionBell;
+use crate::core::export::{self, ExportFormat};
+use crate::core::models::ScanReport;
+use crate::core::scanner::{ScanEvent, ScanSectionKind};
 use crossterm::{
     event::{
         self, Event, KeyCode, KeyEventKind,
     },
+    style::Print,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
     ExecutableCommand,
 };
 use ratatui::prelude::*;
 use std::fs;
-use std::io::stdout;
+use std::io::{IsTerminal, stdout};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
 use std::time::Duration;
 use tokio::sync::mpsc;
-use url::Url;
+use tokio::task::JoinHandle;
 
 mod app;
+mod cli;
+mod clipboard;
+mod config;
 mod core;
+#[cfg(feature = "findings-db")]
+mod findings_db;
+mod headless;
+mod history;
 mod ui;
 mod logging;
 
 /// The main entry point for the application.
 ///
 /// This function performs the following steps:
-/// 1. Initializes the logging system.
+/// 1. Parses the command line and initializes the logging system. Targets given on the
+///    command line are scanned headlessly, skipping the remaining steps.
 /// 2. Sets up the terminal for TUI interaction by entering alternate screen mode and enabling raw mode.
 /// 3. Creates a new `App` instance to hold the application state.
 /// 4. Spawns a channel for asynchronous communication between the scanner task and the main event loop.
 /// 5. Enters the main loop, which continues until the application is signaled to quit.
 ///    - In each iteration, it draws the UI, polls for terminal events, and checks for incoming scan reports.
-/// 6. Cleans up by restoring the terminal to its original state before exiting.
+/// 6. Cleans up by restoring the terminal to its original state before exiting, including
+///    when the loop fails or panics.
 #[tokio::main]
-async fn main() -> Result<()> {
+async fn main() -> Result<ExitCode> {
+    let cli = cli::Cli::parse();
+    color_eyre::install()?;
+
     // Set up logging infrastructure.
     logging::initialize_logging()?;
     info!("Application starting up");
 
+    // Load the user configuration, apply command-line overrides and initialize the application state.
+    let mut config = config::Config::load();
+    if cli.min_severity.is_some() {
+        config.export_min_severity = cli.min_severity.clone();
+    }
+    if let Some(profile) = cli.profile {
+        config.scoring_profile = profile;
+    }
+    for scanner in &cli.skip {
+        match scanner {
+            cli::Scanner::Dns => config.scanners.scan_dns = false,
+            cli::Scanner::Ssl => config.scanners.scan_ssl = false,
+            cli::Scanner::Headers => config.scanners.scan_headers = false,
+            cli::Scanner::Fingerprint => config.scanners.scan_fingerprint = false,
+        }
+    }
+    if cli.dual_cert {
+        config.probe_dual_certificates = true;
+    }
+    if cli.quick_fingerprint {
+        config.quick_fingerprint = true;
+    }
+    if cli.offline {
+        config.offline = true;
+    }
+    if cli.strict {
+        config.strict = true;
+    }
+    core::geoip::init(&config.geoip_database_paths());
+    if let Some(path) = &config.reverse_ip_dataset {
+        core::reverse_ip::init(path);
+    }
+
+    // A trend report only reads the findings database.
+    #[cfg(feature = "findings-db")]
+    if let Some(query) = &cli.query {
+        let conn = findings_db::open().wrap_err_with(|| format!("Failed to open {}", findings_db::path().display()))?;
+        for row in findings_db::run_query(&conn, query, chrono::Local::now()).wrap_err("Failed to run the query")? {
+            println!("{}", row);
+        }
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    // With targets on the command line, scan them without the TUI.
+    if cli.dry_run {
+        return headless::dry_run(headless::expand_targets(&cli.targets), &config, cli.compact);
+    }
+    if cli.until_clean {
+        let policy = headless::RetryPolicy {
+            interval: Duration::from_secs(cli.interval),
+            max_attempts: cli.max_attempts,
+            timeout: cli.timeout.map(Duration::from_secs),
+        };
+        // Every attempt rescans the same targets, so stdin is read to the end first.
+        let targets: Vec<String> = headless::expand_targets(&cli.targets).collect();
+        return headless::run_until_clean(&targets, &config, &policy).await;
+    }
+    if !cli.targets.is_empty() {
+        let color = !cli.no_color && stdout().is_terminal();
+        // Reports read from a pipe go on to one, where one JSON document per line is expected.
+        let format = match cli.format {
+            OutputFormat::Json if cli.targets.iter().any(|target| target == headless::STDIN_TARGET) => OutputFormat::Jsonl,
+            format => format,
+        };
+        let targets = headless::expand_targets(&cli.targets);
+        return headless::run(targets, &config, format, color, cli.compact, cli.summary.as_deref(), cli.export_dir.as_deref()).await;
+    }
+
+    let mut app = App::new(config);
+
+    // In replay mode, open the saved report before touching the terminal so errors stay readable.
+    if let Some(path) = &cli.load {
+        load_report(&mut app, path)?;
+    }
+
     // Prepare the terminal for the TUI.
     stdout().execute(EnterAlternateScreen)?;
     enable_raw_mode()?;
+    install_terminal_restore_hook();
+    let result = run_tui(&mut app).await;
+
+    // Restore the terminal whether the loop ended normally or with an error.
+    info!("Application shutting down gracefully.");
+    restore_terminal();
+    result.map(|()| ExitCode::SUCCESS)
+}
+
+/// Runs the TUI until the user quits.
+async fn run_tui(app: &mut App) -> Result<()> {
     let mut terminal = Terminal::new(CrosstermBackend::new(stdout()))?;
     terminal.clear()?;
 
-    // Initialize the application state.
-    let mut app = App::new();
-    // Create a channel to receive the scan report from the background task.
-    let (tx, mut rx) = mpsc::channel(1);
+    // Create a channel to receive scan progress and the final report from the background task.
+    let (tx, mut rx) = mpsc::unbounded_channel();
 
     // Main application loop.
     while !app.should_quit {
         // Draw the user interface.
         terminal.draw(|frame| {
-            ui::render(&mut app, frame);
+            ui::render(app, frame);
         })?;
 
         // Poll for terminal events with a short timeout.
         if event::poll(Duration::from_millis(100))? {
-            handle_events(&mut app, &tx).await?;
+            handle_events(app, &tx).await?;
         }
 
-        // Check for a completed scan report from the scanner task without blocking.
-        if let Ok(report) = rx.try_recv() {
-            info!(target = %app.input, "Scan finished. Report received.");
-            app.scan_report = Some(report);
-            app.state = AppState::Finished;
-            app.update_summary();
-            app.update_findings();
+        // Check for scan progress from the scanner task without blocking: sections are shown
+        // as they arrive, and the complete report finishes the scan.
+        while let Ok(event) = rx.try_recv() {
+            match event {
+                ScanEvent::Section(section) => app.apply_scan_section(section),
+                ScanEvent::Complete(report) => handle_scan_complete(app, report, &tx),
+            }
         }
 
         // Allow the app to perform any work needed on each tick.
         app.on_tick();
     }
 
-    // Gracefully shut down the application.
-    info!("Application shutting down gracefully.");
-    stdout().execute(LeaveAlternateScreen)?;
-    disable_raw_mode()?;
+    stop_scan(app).await;
     Ok(())
 }
 
+/// How long quitting waits for an aborted scan task to wind down.
+const SCAN_SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Stops the scan still running when the user quits, if any.
+///
+/// Waiting for the scan to finish could take as long as its slowest timeout, so the task is
+/// aborted instead: its futures are dropped at their next await point, which closes their
+/// sockets, and the report it was building is discarded. The aborted task is then awaited
+/// briefly so the connections are closed before the runtime shuts down, and so it never sends
+/// into the channel after its receiver is gone.
+///
+/// Aborting cannot interrupt the SSL/TLS handshakes, which run in a blocking thread that
+/// outlives the task: the runtime shutdown waits for it, which its connect and I/O timeouts
+/// keep to seconds.
+async fn stop_scan(app: &mut App) {
+    let Some(task) = app.scan_task.take() else { return };
+    if task.is_finished() {
+        return;
+    }
+    info!("Quitting during a scan, aborting it.");
+    task.abort();
+    if tokio::time::timeout(SCAN_SHUTDOWN_TIMEOUT, task).await.is_err() {
+        warn!("The aborted scan did not wind down in time; leaving it to the runtime shutdown.");
+    }
+    // The sections only start being tracked when the first one arrives.
+    if app.pending_sections.is_empty() || app.pending_sections.contains(&ScanSectionKind::Ssl) {
+        warn!("The SSL/TLS scan cannot be interrupted; exiting once its handshake ends or times out.");
+    }
+}
+
+/// Leaves raw mode and the alternate screen. Errors are ignored, as this also runs while panicking.
+fn restore_terminal() {
+    let _ = disable_raw_mode();
+    let _ = stdout().execute(LeaveAlternateScreen);
+}
+
+/// Wraps the installed panic hook (color-eyre's) so a panic restores the terminal first.
+/// Otherwise the report would be drawn into the alternate screen, and the shell left in raw mode.
+///
+/// Panics in background tasks (e.g. a scanner) are caught by tokio and leave the TUI running,
+/// so only panics on the main thread, which runs the event loop, restore the terminal.
+fn install_terminal_restore_hook() {
+    let previous_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |panic_info| {
+        if std::thread::current().name() == Some("main") {
+            restore_terminal();
+        }
+        error!(panic = %panic_info, "Application panicked");
+        previous_hook(panic_info);
+    }));
+}
+
+
+/// Shows a completed scan report and moves on to the next queued target, if any.
+///
+/// # Arguments
+///
+/// * `app` - A mutable reference to the application's state.
+/// * `report` - The complete report, including the cross-scanner findings.
+/// * `tx` - The sender endpoint used to start the next scan of a multi-target run.
+fn handle_scan_complete(app: &mut App, mut report: ScanReport, tx: &mpsc::UnboundedSender<ScanEvent>) {
+    app.pending_sections.clear();
+    report.scoring_profile = app.config.scoring_profile;
+    app.scan_report = Some(report);
+    app.update_summary();
+    app.update_findings();
+    if app.is_batch() {
+        app.record_batch_result();
+    }
+    info!(target = %app.report_target(), "Scan finished. Report received.");
+    let target = core::scanner::target_host(app.report_target());
+    // Log every finding, including the Info ones focus mode hides from the list.
+    app::log_findings(&target, &app.collect_findings());
+    app.record_history(&target);
+    app.run_found_critical |= app.summary.critical_issues > 0;
+
+    // In a multi-target run, move on to the next queued target; otherwise we're done.
+    match app.scan_queue.pop_front() {
+        Some(next) => app.scan_task = Some(start_scan(&next, app.scan_options(), tx)),
+        None => {
+            app.state = AppState::Finished;
+            ring_completion_bell(app);
+        }
+    }
+}
+
+/// Rings the terminal bell if the configuration asks for it, so a finished scan is
+/// noticed from another window. Clears the run's Critical flag for the next run.
+fn ring_completion_bell(app: &mut App) {
+    let ring = match app.config.completion_bell {
+        CompletionBell::Off => false,
+        CompletionBell::Always => true,
+        CompletionBell::OnCritical => app.run_found_critical,
+    };
+    app.run_found_critical = false;
+    if ring && let Err(e) = stdout().execute(Print('\x07')) {
+        error!(error = %e, "Failed to ring the terminal bell.");
+    }
+}
+
+/// Writes the displayed report to the working directory, honoring the configured severity
+/// threshold and attaching the user's note, and shows the outcome in the footer.
+fn export_report(app: &mut App, format: ExportFormat) {
+    let Some(report) = app.report_for_export() else { return; };
+    // Exports from the TUI are mostly read by people, so their JSON stays pretty-printed.
+    match export::write_report(&report, app.report_target(), &format, Path::new("."), false) {
+        Ok(path) => {
+            info!(path = %path.display(), "Report exported successfully");
+            let shown = match format {
+                ExportFormat::Json | ExportFormat::TechnologiesCsv | ExportFormat::Checklist => path.display().to_string(),
+                ExportFormat::Bundle { .. } => format!("{}/", path.display()),
+            };
+            app.last_export_path = Some(full_path(&path));
+            app.export_status = ExportStatus::Success(shown);
+        }
+        Err(e) => {
+            error!(error = %e, "Failed to export the report");
+            app.export_status = ExportStatus::Error(e.to_string());
+        }
+    }
+}
+
+/// Returns the absolute form of a path written relative to the working directory.
+fn full_path(path: &Path) -> PathBuf {
+    fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf())
+}
+
+/// Opens the location of the last export in the system file manager: the containing folder
+/// of a report file, or the bundle directory itself. Without a graphical session there is
+/// nothing to open it with, so the full path is shown instead.
+fn open_export_location(app: &mut App) {
+    let Some(path) = app.last_export_path.clone() else { return; };
+    let location = if path.is_dir() { path.clone() } else { path.parent().map_or(path.clone(), Path::to_path_buf) };
+
+    let has_display = !cfg!(target_os = "linux")
+        || std::env::var_os("DISPLAY").is_some()
+        || std::env::var_os("WAYLAND_DISPLAY").is_some();
+    if !has_display {
+        debug!(path = %location.display(), "No graphical session, showing the export path instead.");
+        app.export_status = ExportStatus::Success(path.display().to_string());
+        return;
+    }
+    match open::that_detached(&location) {
+        Ok(()) => info!(path = %location.display(), "Opened the export location."),
+        Err(e) => {
+            warn!(path = %location.display(), error = %e, "Could not open the export location.");
+            app.export_status = ExportStatus::Success(path.display().to_string());
+        }
+    }
+}
+
+/// Loads a previously exported report and shows it as a finished scan.
+///
+/// The disclaimer is skipped since no scan is performed.
+///
+/// # Arguments
+///
+/// * `app` - A mutable reference to the application's state.
+/// * `path` - The path of the JSON report to load.
+fn load_report(app: &mut App, path: &Path) -> Result<()> {
+    let content = fs::read_to_string(path)
+        .wrap_err_with(|| format!("Failed to read report {}", path.display()))?;
+    let report: core::models::ScanReport = serde_json::from_str(&content)
+        .wrap_err_with(|| format!("Failed to parse report {}", path.display()))?;
+    info!(path = %path.display(), "Loaded saved report.");
+
+    // Use the file name as the target so exports of the loaded report get a sensible name.
+    app.input = path.file_stem().map(|s| s.to_string_lossy().into_owned()).unwrap_or_default();
+    app.notes = report.notes.clone();
+    app.scan_report = Some(report);
+    app.update_summary();
+    app.update_findings();
+    app.state = AppState::Finished;
+    Ok(())
+}
 
 /// Handles terminal events, such as key presses, and updates the application state accordingly.
 ///
@@ -91,21 +367,34 @@ async fn main() -> Result<()> {
 ///
 /// * `app` - A mutable reference to the `App` struct, representing the application's state.
 /// * `tx` - A sender endpoint of a channel, used to initiate the scan task.
-async fn handle_events(app: &mut App, tx: &mpsc::Sender<core::models::ScanReport>) -> Result<()> {
+async fn handle_events(app: &mut App, tx: &mpsc::UnboundedSender<ScanEvent>) -> Result<()> {
     if let Event::Key(key) = event::read()? {
         // Process event only on key press, not release.
         if key.kind == KeyEventKind::Press {
             debug!("Key event received: {:?}", key.code);
             match app.state {
                 AppState::Disclaimer => {
-                    if key.code == KeyCode::Enter { app.state = AppState::Idle; }
+                    if key.code == KeyCode::Enter { app.accept_disclaimer(); }
+                }
+                // The tutorial takes the keys until it is dismissed, with Enter or right away with Esc.
+                AppState::Idle if app.show_tutorial => {
+                    if matches!(key.code, KeyCode::Enter | KeyCode::Esc) { app.dismiss_tutorial(); }
                 }
                 AppState::Idle => handle_idle_input(app, key.code, tx).await,
-                AppState::Finished => handle_finished_keyboard_input(app, key.code),
-                AppState::Scanning => {
+                AppState::Finished => handle_finished_keyboard_input(app, key.code, tx),
+                AppState::Scanning => match key.code {
                     // Allow quitting even while a scan is in progress.
-                    if key.code == KeyCode::Char('q') { app.quit(); }
-                }
+                    KeyCode::Char('q') => app.quit(),
+                    // The log panel follows the scan live, so it can be opened while waiting.
+                    KeyCode::Char('l') | KeyCode::Char('L') => {
+                        app.show_logs = !app.show_logs;
+                        if app.show_logs { app.refresh_logs(); }
+                    }
+                    KeyCode::PageUp if app.show_logs => app.scroll_logs_up(),
+                    KeyCode::PageDown if app.show_logs => app.scroll_logs_down(),
+                    KeyCode::End if app.show_logs => app.follow_logs(),
+                    _ => {}
+                },
             }
         }
     }
@@ -122,7 +411,7 @@ async fn handle_events(app: &mut App, tx: &mpsc::Sender<core::models::ScanReport
 /// * `app` - A mutable reference to the application's state.
 /// * `key_code` - The `KeyCode` corresponding to the pressed key.
 /// * `tx` - The sender endpoint of the channel to communicate with the scanner task.
-async fn handle_idle_input(app: &mut App, key_code: KeyCode, tx: &mpsc::Sender<core::models::ScanReport>) {
+async fn handle_idle_input(app: &mut App, key_code: KeyCode, tx: &mpsc::UnboundedSender<ScanEvent>) {
     // Reset any lingering export status messages.
     if !matches!(app.export_status, ExportStatus::Idle) {
         app.export_status = ExportStatus::Idle;
@@ -130,40 +419,71 @@ async fn handle_idle_input(app: &mut App, key_code: KeyCode, tx: &mpsc::Sender<c
 
     match key_code {
         KeyCode::Char('q') => app.quit(),
+        KeyCode::F(1) => app.show_disclaimer(),
+        // Toggle insecure inspection for the next scans.
+        KeyCode::F(2) => app.accept_invalid_certs = !app.accept_invalid_certs,
+        // Toggle scanning the host the target redirects to.
+        KeyCode::F(3) => app.follow_canonical_redirect = !app.follow_canonical_redirect,
         KeyCode::Char(c) => app.input.push(c),
         KeyCode::Backspace => { app.input.pop(); },
+        // Cycle through previously scanned targets.
+        KeyCode::Up => app.recall_previous_target(),
+        KeyCode::Down => app.recall_next_target(),
         KeyCode::Enter => {
+            // Several targets separated by commas or spaces are scanned one after another.
+            let targets: Vec<String> = app.input
+                .split(|c: char| c == ',' || c.is_whitespace())
+                .filter(|t| !t.is_empty())
+                .map(String::from)
+                .collect();
+
             // Do nothing if the input is empty.
-            if app.input.is_empty() { return; }
+            let Some(first) = targets.first().cloned() else { return; };
+
+            if targets.len() > 1 {
+                info!(count = targets.len(), "Queueing multi-target scan");
+                app.start_batch(targets);
+                app.scan_queue.pop_front();
+            }
 
             // Change state to indicate scanning has started.
             app.state = AppState::Scanning;
-            let tx_clone = tx.clone();
-            let raw_input = app.input.clone();
-            
-            // Prepend "https://" to the input if no scheme is present.
-            let input_with_scheme = if !raw_input.starts_with("http://") && !raw_input.starts_with("https://") {
-                format!("https://{}", raw_input)
-            } else { raw_input };
-
-            // Attempt to parse the input as a URL to extract the host. Fallback to the raw input.
-            let target_domain = Url::parse(&input_with_scheme)
-                .ok().and_then(|url| url.host_str().map(String::from))
-                .unwrap_or_else(|| app.input.clone());
-            
-            info!(target = %target_domain, "Initiating new scan");
-
-            // Spawn a new asynchronous task to run the scan without blocking the UI.
-            tokio::spawn(async move {
-                let report = core::scanner::run_full_scan(&target_domain).await;
-                // Send the completed report back to the main event loop.
-                let _ = tx_clone.send(report).await;
-            });
+            app.scan_task = Some(start_scan(&first, app.scan_options(), tx));
         }
         _ => {}
     }
 }
 
+/// Normalizes a raw target and spawns a background task that scans it.
+///
+/// Each scanner's results are sent through `tx` as soon as they are ready, followed by
+/// the completed `ScanReport`.
+///
+/// Returns the task's handle, kept in `App::scan_task` so quitting can stop it.
+///
+/// # Arguments
+///
+/// * `raw_input` - The target as typed by the user (a domain or a URL).
+/// * `options` - The opt-in checks to run, from the configuration.
+/// * `tx` - The sender endpoint of the channel to communicate with the main loop.
+fn start_scan(raw_input: &str, options: core::scanner::ScanOptions, tx: &mpsc::UnboundedSender<ScanEvent>) -> JoinHandle<()> {
+    let tx_clone = tx.clone();
+
+    let target_domain = core::scanner::target_host(raw_input);
+
+    info!(target = %target_domain, "Initiating new scan");
+
+    // Spawn a new asynchronous task to run the scan without blocking the UI.
+    tokio::spawn(async move {
+        // Sends fail once the main loop has quit and dropped the receiver, which is fine.
+        let report = core::scanner::run_full_scan_with_progress(&target_domain, options, |section| {
+            let _ = tx_clone.send(ScanEvent::Section(section));
+        }).await;
+        // Send the completed report back to the main event loop.
+        let _ = tx_clone.send(ScanEvent::Complete(report));
+    })
+}
+
 /// Manages keyboard input when the application is in the `AppState::Finished` state.
 ///
 /// This function handles navigating findings, exporting the report, starting a new scan,
@@ -173,12 +493,62 @@ async fn handle_idle_input(app: &mut App, key_code: KeyCode, tx: &mpsc::Sender<c
 ///
 /// * `app` - A mutable reference to the application's state.
 /// * `key_code` - The `KeyCode` corresponding to the pressed key.
-fn handle_finished_keyboard_input(app: &mut App, key_code: KeyCode) {
+/// * `tx` - The sender endpoint used when the scan is re-run.
+fn handle_finished_keyboard_input(app: &mut App, key_code: KeyCode, tx: &mpsc::UnboundedSender<ScanEvent>) {
+    // Opening the export's folder is only offered while its confirmation is shown.
+    let export_shown = matches!(app.export_status, ExportStatus::Success(_));
     // Reset any lingering export status messages on new input.
     if !matches!(app.export_status, ExportStatus::Idle) {
         app.export_status = ExportStatus::Idle;
     }
 
+    // While the notes editor is open, every key edits the note.
+    if let Some(draft) = app.notes_input.as_mut() {
+        match key_code {
+            KeyCode::Char(c) => draft.push(c),
+            KeyCode::Backspace => { draft.pop(); },
+            KeyCode::Enter => app.save_notes(),
+            KeyCode::Esc => app.notes_input = None,
+            _ => {}
+        }
+        return;
+    }
+
+    // While the check explanations popup is open, it captures the keyboard.
+    if app.show_check_details {
+        match key_code {
+            KeyCode::Char('w') | KeyCode::Char('W') | KeyCode::Esc => app.show_check_details = false,
+            KeyCode::Char('q') | KeyCode::Char('Q') => app.quit(),
+            _ => {}
+        }
+        return;
+    }
+
+    // While the score breakdown popup is open, it captures the keyboard.
+    if app.show_score_breakdown {
+        match key_code {
+            KeyCode::Char('s') | KeyCode::Char('S') | KeyCode::Esc => app.show_score_breakdown = false,
+            KeyCode::Char('q') | KeyCode::Char('Q') => app.quit(),
+            _ => {}
+        }
+        return;
+    }
+
+    // While the raw response popup is open, it captures the keyboard.
+    if app.show_raw_response {
+        match key_code {
+            KeyCode::Char('r') | KeyCode::Char('R') | KeyCode::Esc => app.show_raw_response = false,
+            KeyCode::Char('b') | KeyCode::Char('B') => app.raw_response_show_body = !app.raw_response_show_body,
+            KeyCode::Up => app.raw_response_scroll = app.raw_response_scroll.saturating_sub(1),
+            KeyCode::Down => app.raw_response_scroll = app.raw_response_scroll.saturating_add(1),
+            KeyCode::PageUp => app.raw_response_scroll = app.raw_response_scroll.saturating_sub(10),
+            KeyCode::PageDown => app.raw_response_scroll = app.raw_response_scroll.saturating_add(10),
+            KeyCode::Char('q') | KeyCode::Char('Q') => app.quit(),
+            _ => {}
+        }
+        return;
+    }
+
     // If the log panel is visible, specific keys control log scrolling.
     if app.show_logs {
         match key_code {
@@ -193,6 +563,19 @@ fn handle_finished_keyboard_input(app: &mut App, key_code: KeyCode) {
                 app.log_horizontal_scroll_state = app.log_horizontal_scroll_state.position(app.log_horizontal_scroll);
                 return; // Consume the event to prevent other actions.
             },
+            // Handle vertical scrolling; End goes back to following the newest lines.
+            KeyCode::PageUp => {
+                app.scroll_logs_up();
+                return;
+            },
+            KeyCode::PageDown => {
+                app.scroll_logs_down();
+                return;
+            },
+            KeyCode::End => {
+                app.follow_logs();
+                return;
+            },
             _ => {}
         }
     }
@@ -200,36 +583,96 @@ fn handle_finished_keyboard_input(app: &mut App, key_code: KeyCode) {
     match key_code {
         KeyCode::Char('q') | KeyCode::Char('Q') => app.quit(),
         KeyCode::Char('n') | KeyCode::Char('N') => app.reset(),
-        KeyCode::Char('e') | KeyCode::Char('E') => {
-            // Export the scan report to a JSON file.
-            if let Some(report) = &app.scan_report {
-                match serde_json::to_string_pretty(report) {
-                    Ok(json_data) => {
-                        let timestamp = Local::now().format("%Y%m%d_%H%M%S");
-                        let target_domain = app.input.split_once("://").unwrap_or(("", &app.input)).1;
-                        let filename = format!("{}-{}.json", target_domain.replace('/', "_"), timestamp);
-                        
-                        match fs::write(&filename, json_data) {
-                            Ok(_) => { 
-                                info!(filename = %filename, "Report exported successfully"); 
-                                app.export_status = ExportStatus::Success(filename); 
-                            },
-                            Err(e) => { 
-                                error!(error = %e, "Failed to write report to file"); 
-                                app.export_status = ExportStatus::Error(e.to_string()); 
-                            },
-                        }
-                    }
-                    Err(e) => { 
-                        error!(error = %e, "Failed to serialize report to JSON"); 
-                        app.export_status = ExportStatus::Error(e.to_string()); 
-                    },
-                }
-            }
+        // Toggle insecure inspection and scan the displayed target again with the new setting.
+        KeyCode::F(2) => {
+            let target = app.report_target().to_string();
+            app.reset();
+            app.accept_invalid_certs = !app.accept_invalid_certs;
+            info!(target = %target, accept_invalid_certs = app.accept_invalid_certs, "Re-running scan");
+            app.input = target;
+            app.state = AppState::Scanning;
+            app.scan_task = Some(start_scan(&app.input, app.scan_options(), tx));
         },
+        // Toggle following the canonical redirect and scan the displayed target again.
+        KeyCode::F(3) => {
+            let target = app.report_target().to_string();
+            app.reset();
+            app.follow_canonical_redirect = !app.follow_canonical_redirect;
+            info!(target = %target, follow_canonical_redirect = app.follow_canonical_redirect, "Re-running scan");
+            app.input = target;
+            app.state = AppState::Scanning;
+            app.scan_task = Some(start_scan(&app.input, app.scan_options(), tx));
+        },
+        // Export the scan report to a JSON file, honoring the configured severity threshold.
+        KeyCode::Char('e') | KeyCode::Char('E') => export_report(app, ExportFormat::Json),
+        // Export the report together with a copy of the log, for sharing a problematic scan.
+        KeyCode::Char('b') | KeyCode::Char('B') => {
+            let log_file = logging::get_data_dir().join(logging::LOG_FILE.as_str());
+            export_report(app, ExportFormat::Bundle { log_file });
+        },
+        // Export only the detected technology stack, for asset inventories.
+        KeyCode::Char('t') | KeyCode::Char('T') => export_report(app, ExportFormat::TechnologiesCsv),
+        // Export the Critical and Warning findings as a Markdown task list to hand off.
+        KeyCode::Char('k') | KeyCode::Char('K') => export_report(app, ExportFormat::Checklist),
+        // Open the folder holding the export that was just written.
+        KeyCode::Char('o') | KeyCode::Char('O') if export_shown => open_export_location(app),
+        // Switch between the targets of a multi-target run.
+        KeyCode::Char(']') => app.select_next_batch_entry(),
+        KeyCode::Char('[') => app.select_previous_batch_entry(),
         // Navigation controls for the findings list.
         KeyCode::Down => app.select_next_finding(),
         KeyCode::Up => app.select_previous_finding(),
+        KeyCode::F(1) => app.show_disclaimer(),
+        // Show or hide Info findings.
+        KeyCode::Char('f') | KeyCode::Char('F') => app.toggle_focus_mode(),
+        // Switch between the side-by-side and the stacked layout.
+        KeyCode::Char('v') | KeyCode::Char('V') => app.compact_mode = !app.compact_mode,
+        // Attach a note to the report before exporting it.
+        KeyCode::Char('a') | KeyCode::Char('A') => app.open_notes_editor(),
+        // Copy the selected finding's code, e.g. for a ticket.
+        KeyCode::Char('c') | KeyCode::Char('C') => {
+            let selected = app.analysis_list_state.selected().and_then(|i| app.all_findings.get(i));
+            if let Some(code) = selected.map(|finding| finding.code.clone()) {
+                app.export_status = match clipboard::copy(&code) {
+                    Ok(()) => ExportStatus::Copied(code),
+                    Err(e) => {
+                        debug!(error = %e, "Could not copy to the clipboard");
+                        ExportStatus::Error(format!("Clipboard unavailable ({}), finding code: {}", e, code))
+                    }
+                };
+            }
+        },
+        // Copy a config snippet that fixes the selected finding, or its remediation when there is none.
+        KeyCode::Char('h') | KeyCode::Char('H') => {
+            if let Some((label, text)) = app.selected_remediation_hint() {
+                app.export_status = match clipboard::copy(&text) {
+                    Ok(()) => ExportStatus::Copied(label),
+                    Err(e) => {
+                        debug!(error = %e, "Could not copy to the clipboard");
+                        ExportStatus::Error(format!("Clipboard unavailable ({}), {} is shown in the details", e, label))
+                    }
+                };
+            }
+        },
+        // Add the selected finding to the current target's ignore list.
+        KeyCode::Char('i') | KeyCode::Char('I') => {
+            if let Err(e) = app.suppress_selected_finding() {
+                error!(error = %e, "Failed to save the ignore list");
+                app.export_status = ExportStatus::Error(e.to_string());
+            }
+        },
+        // Jump between the DNS/SSL/HTTP/Technology groups of the findings list.
+        KeyCode::Tab => app.select_next_category(),
+        KeyCode::BackTab => app.select_previous_category(),
+        // Show why each summary check passed or failed.
+        KeyCode::Char('w') | KeyCode::Char('W') => app.show_check_details = true,
+        // Show which findings cost how many points.
+        KeyCode::Char('s') | KeyCode::Char('S') => app.show_score_breakdown = true,
+        // Dump the raw response of the main HTTP request.
+        KeyCode::Char('r') | KeyCode::Char('R') => {
+            app.show_raw_response = true;
+            app.raw_response_scroll = 0;
+        },
         // Toggle the visibility of the log panel.
         KeyCode::Char('l') | KeyCode::Char('L') => {
             app.show_logs = !app.show_logs;
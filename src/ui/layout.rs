@@ -16,6 +16,7 @@ pub struct AppLayout {
     pub summary: Rect,
     pub footer: Rect,
     pub log_panel: Rect,
+    pub batch_panel: Rect,
 }
 
 /// Creates the complete application layout dynamically based on the current state.
@@ -28,15 +29,21 @@ pub struct AppLayout {
 ///
 /// The middle content area is split horizontally. The proportions of this split
 /// are determined by the `show_logs` flag, allowing the layout to adapt
-/// to show or hide the log panel.
+/// to show or hide the log panel. During a multi-target run, the top of the
+/// summary column is given to the batch panel.
+///
+/// In compact mode the middle area is stacked instead: report, then summary (and batch
+/// panel), then logs, each using the full width.
 ///
 /// # Arguments
 /// * `frame_size` - The `Rect` representing the total size of the terminal frame.
 /// * `show_logs` - A boolean that determines whether to allocate space for the log panel.
+/// * `show_batch` - A boolean that determines whether to allocate space for the batch panel.
+/// * `compact` - A boolean that selects the stacked single-column layout.
 ///
 /// # Returns
 /// An `AppLayout` struct containing the calculated `Rect` for each widget area.
-pub fn create_layout(frame_size: Rect, show_logs: bool) -> AppLayout {
+pub fn create_layout(frame_size: Rect, show_logs: bool, show_batch: bool, compact: bool) -> AppLayout {
     // Define the main vertical layout: input, content, footer.
     let main_chunks = Layout::default()
         .direction(Direction::Vertical)
@@ -49,23 +56,39 @@ pub fn create_layout(frame_size: Rect, show_logs: bool) -> AppLayout {
 
     // Determine the horizontal layout constraints for the middle content area
     // based on whether the log panel should be visible.
-    let content_constraints = if show_logs {
+    let content_constraints = match (compact, show_logs) {
+        // Stacked with logs: Report, Summary (fixed height), Logs (30%)
+        (true, true) => vec![Constraint::Min(8), Constraint::Length(16), Constraint::Percentage(30)],
+        // Stacked without logs: Report, Summary (fixed height)
+        (true, false) => vec![Constraint::Min(8), Constraint::Length(16)],
         // With logs visible: Report (45%), Summary (20%), Logs (35%)
-        vec![Constraint::Percentage(45), Constraint::Percentage(20), Constraint::Percentage(35)]
-    } else {
+        (false, true) => vec![Constraint::Percentage(45), Constraint::Percentage(20), Constraint::Percentage(35)],
         // Without logs visible: Report (70%), Summary (30%)
-        vec![Constraint::Percentage(70), Constraint::Percentage(30)]
+        (false, false) => vec![Constraint::Percentage(70), Constraint::Percentage(30)],
     };
 
     let content_chunks = Layout::default()
-        .direction(Direction::Horizontal)
+        .direction(if compact { Direction::Vertical } else { Direction::Horizontal })
         .constraints(content_constraints)
         .split(main_chunks[1]);
 
+    // Split the summary area to make room for the batch panel when needed:
+    // above the summary in a column, beside it when stacked.
+    let (batch_panel, summary) = if show_batch {
+        let column = Layout::default()
+            .direction(if compact { Direction::Horizontal } else { Direction::Vertical })
+            .constraints([Constraint::Percentage(35), Constraint::Min(0)])
+            .split(content_chunks[1]);
+        (column[0], column[1])
+    } else {
+        (Rect::default(), content_chunks[1])
+    };
+
     AppLayout {
         input: main_chunks[0],
         report: content_chunks[0],
-        summary: content_chunks[1],
+        summary,
+        batch_panel,
         // If logs are visible, assign the third chunk to the log panel;
         // otherwise, assign a default (empty) Rect.
         log_panel: if show_logs { content_chunks[2] } else { Rect::default() },
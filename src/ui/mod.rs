@@ -1,7 +1,14 @@
 // src/ui/mod.rs
 
 use crate::app::{App, AppState};
-use ratatui::prelude::*;
+use ratatui::{prelude::*, widgets::Paragraph};
+
+/// The smallest terminal width the full layout can be drawn in.
+pub const MIN_WIDTH: u16 = 60;
+/// The smallest terminal height the full layout can be drawn in.
+pub const MIN_HEIGHT: u16 = 20;
+/// Below this width the panes are stacked (compact mode) even if it was not turned on.
+pub const COMPACT_WIDTH: u16 = 100;
 
 // Declare the modules responsible for UI rendering.
 // `layout` is made public as it defines the core layout structure.
@@ -22,8 +29,17 @@ mod widgets;
 /// * `app` - A mutable reference to the application's state.
 /// * `frame` - A mutable reference to the `Frame` on which to draw.
 pub fn render(app: &mut App, frame: &mut Frame) {
-    // 1. Calculate the dynamic layout based on whether the log panel is visible.
-    let app_layout = layout::create_layout(frame.area(), app.show_logs);
+    // 0. Below the minimum size the layout would overflow, so show a hint instead.
+    let area = frame.area();
+    if area.width < MIN_WIDTH || area.height < MIN_HEIGHT {
+        render_too_small(frame, area);
+        return;
+    }
+
+    // 1. Calculate the dynamic layout based on whether the log and batch panels are visible,
+    //    stacking the panes on narrow terminals or when compact mode is on.
+    let compact = app.compact_mode || area.width < COMPACT_WIDTH;
+    let app_layout = layout::create_layout(area, app.show_logs, app.is_batch(), compact);
 
     // 2. Render the primary UI widgets in their designated areas.
     widgets::input::render_input(frame, app, app_layout.input);
@@ -31,13 +47,57 @@ pub fn render(app: &mut App, frame: &mut Frame) {
     widgets::summary::render_summary(frame, app, app_layout.summary);
     widgets::footer::render_footer(frame, app, app_layout.footer);
 
-    // 3. Conditionally render the log panel if it's enabled.
+    // 3. Conditionally render the log panel and the batch panel if they're enabled.
+    if app.is_batch() {
+        widgets::batch_view::render_batch_view(frame, app, app_layout.batch_panel);
+    }
     if app.show_logs {
         widgets::log_view::render_log_view(frame, app, app_layout.log_panel);
     }
 
-    // 4. If the app is in the `Disclaimer` state, render the popup as an overlay.
+    // 4. Render the check explanations popup over the results when requested.
+    if app.show_check_details && matches!(app.state, AppState::Finished) {
+        widgets::check_details_popup::render_check_details_popup(frame, app, frame.area());
+    }
+
+    if app.show_score_breakdown && matches!(app.state, AppState::Finished) {
+        widgets::score_breakdown_popup::render_score_breakdown_popup(frame, app, frame.area());
+    }
+
+    if app.show_raw_response && matches!(app.state, AppState::Finished) {
+        widgets::raw_response_popup::render_raw_response_popup(frame, app, frame.area());
+    }
+
+    // The notes editor is drawn above the results it annotates.
+    if app.notes_input.is_some() && matches!(app.state, AppState::Finished) {
+        widgets::notes_popup::render_notes_popup(frame, app, frame.area());
+    }
+
+    // The tutorial follows the first acknowledgment of the disclaimer, over the input screen.
+    if app.show_tutorial && matches!(app.state, AppState::Idle) {
+        widgets::tutorial_popup::render_tutorial_popup(frame, frame.area());
+    }
+
+    // 5. If the app is in the `Disclaimer` state, render the popup as an overlay.
     if matches!(app.state, AppState::Disclaimer) {
         widgets::disclaimer_popup::render_disclaimer_popup(frame, frame.area());
     }
-}
\ No newline at end of file
+}
+
+/// Renders a short notice asking the user to enlarge the terminal.
+///
+/// # Arguments
+/// * `frame` - A mutable reference to the `Frame` on which to draw.
+/// * `area` - The full terminal area.
+fn render_too_small(frame: &mut Frame, area: Rect) {
+    let text = Text::from(vec![
+        Line::from("Terminal too small".bold().yellow()),
+        Line::from(format!("need at least {}×{}, have {}×{}", MIN_WIDTH, MIN_HEIGHT, area.width, area.height)),
+        Line::from("Enlarge the window to continue."),
+    ]);
+    // Vertically center the notice when there is room for it.
+    let top = area.height.saturating_sub(text.height() as u16) / 2;
+    let notice_area = Rect { y: area.y + top, height: area.height - top, ..area };
+    let notice = Paragraph::new(text).alignment(Alignment::Center);
+    frame.render_widget(notice, notice_area);
+}
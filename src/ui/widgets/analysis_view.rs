@@ -21,13 +21,23 @@ use ratatui::{
 /// * `app` - A mutable reference to the application's state.
 /// * `area` - The `Rect` in which to render this widget.
 pub fn render_analysis_view(frame: &mut Frame, app: &mut App, area: Rect) {
-    // The main container for the analysis view, with a title and border.
+    // The main container for the analysis view, with a title and border. Once the scan is done,
+    // the title carries the headline counts, so they stay in view however the list is scrolled.
+    // The navigation keys are listed in the footer.
+    let title = if matches!(app.state, AppState::Finished) {
+        format!(
+            "Analysis Report — {} critical, {} warning, {} info",
+            app.summary.critical_issues, app.summary.warning_issues, app.summary.info_issues
+        )
+    } else {
+        "Analysis Report".to_string()
+    };
     let main_block = Block::default()
         .borders(Borders::ALL)
-        .title("Analysis Report (Navigate with ↑ ↓)");
+        .title(title);
 
-    // Render a placeholder or spinner if the scan is not yet finished.
-    if !matches!(app.state, AppState::Finished) {
+    // Render a placeholder or spinner until the first results are available.
+    if !matches!(app.state, AppState::Finished) && app.scan_report.is_none() {
         let content = match app.state {
             // Display a simple prompt when waiting for input.
             AppState::Idle => Paragraph::new("Scan results will appear here...")
@@ -49,6 +59,24 @@ pub fn render_analysis_view(frame: &mut Frame, app: &mut App, area: Rect) {
         return;
     }
     
+    // A clean report gets a visible pass mark, so "no issues" is not mistaken for "no results".
+    // While the scan runs, the partial results are shown with the sections still awaited.
+    let main_block = if matches!(app.state, AppState::Scanning) {
+        let waiting_for: Vec<String> = app.pending_sections.iter().map(|kind| kind.to_string()).collect();
+        let progress = format!(" {} Scanning... waiting for {} ", SPINNER_CHARS[app.spinner_frame], waiting_for.join(", "));
+        main_block.title(Line::from(progress).fg(Color::Cyan).right_aligned())
+    } else if app.is_clean() {
+        main_block.title(Line::from(" ✓ No issues requiring action ").fg(Color::Green).right_aligned())
+    } else {
+        main_block
+    };
+    // Focus mode hides Info findings; say how many, so nothing disappears silently.
+    let main_block = if app.hidden_info_findings > 0 {
+        main_block.title_bottom(Line::from(format!(" ({} info hidden, [F] to show) ", app.hidden_info_findings)).fg(Color::DarkGray).right_aligned())
+    } else {
+        main_block
+    };
+
     // If the scan is finished, render the main block and prepare to draw the results inside.
     let inner_area = main_block.inner(area);
     frame.render_widget(main_block, area);
@@ -72,7 +100,8 @@ pub fn render_analysis_view(frame: &mut Frame, app: &mut App, area: Rect) {
             category: knowledge_base::FindingCategory::Http,
             severity: crate::core::models::Severity::Info,
             description: "",
-            remediation: ""
+            remediation: "",
+            references: &[],
         };
         let detail = knowledge_base::get_finding_detail(&f.code).unwrap_or(&default_detail);
         
@@ -81,10 +110,11 @@ pub fn render_analysis_view(frame: &mut Frame, app: &mut App, area: Rect) {
             knowledge_base::FindingCategory::Dns => "[DNS] ",
             knowledge_base::FindingCategory::Ssl => "[SSL/TLS] ",
             knowledge_base::FindingCategory::Http => "[HTTP] ",
+            knowledge_base::FindingCategory::Technology => "[TECH] ",
         };
 
-        // Style the title based on the finding's severity.
-        let title_style = match detail.severity {
+        // Style the title based on the finding's (possibly overridden) severity.
+        let title_style = match f.severity {
             crate::core::models::Severity::Critical => Style::default().fg(Color::Red),
             crate::core::models::Severity::Warning => Style::default().fg(Color::Yellow),
             crate::core::models::Severity::Info => Style::default().fg(Color::Cyan),
@@ -93,7 +123,7 @@ pub fn render_analysis_view(frame: &mut Frame, app: &mut App, area: Rect) {
         // Assemble the final display line for the list item.
         let line = Line::from(vec![
             Span::styled(category_prefix, Style::default().fg(Color::DarkGray)),
-            Span::styled(detail.title, title_style),
+            Span::styled(f.fill_template(detail.title), title_style),
         ]);
         
         ListItem::new(line)
@@ -107,26 +137,58 @@ pub fn render_analysis_view(frame: &mut Frame, app: &mut App, area: Rect) {
     // Render the stateful list widget in the top pane.
     frame.render_stateful_widget(findings_list, chunks[0], &mut app.analysis_list_state);
     
-    let detail_block = Block::default().borders(Borders::TOP).title("Details");
+    // Name the category of the selected finding, so Tab/Shift-Tab jumps are easy to follow.
+    let detail_title = match app.selected_category() {
+        Some(category) => format!("Details - {}", category),
+        None => "Details".to_string(),
+    };
+    let detail_block = Block::default().borders(Borders::TOP).title(detail_title);
 
     // Check if an item is selected in the list.
     if let Some(selected_index) = app.analysis_list_state.selected() {
         // If so, get the corresponding finding and its details.
-        if let Some(selected_finding) = app.all_findings.get(selected_index) {
-            if let Some(detail) = knowledge_base::get_finding_detail(&selected_finding.code) {
-                // Format the description and remediation advice for display.
-                let text = vec![
-                    Line::from(""),
-                    Line::from("WHAT IT IS:".yellow().bold()),
-                    Line::from(detail.description),
-                    Line::from(""),
-                    Line::from("HOW TO FIX:".yellow().bold()),
-                    Line::from(detail.remediation),
-                ];
-                let p = Paragraph::new(text).wrap(Wrap { trim: true }).block(detail_block);
-                // Render the details in the bottom pane.
-                frame.render_widget(p, chunks[1]);
+        if let Some(selected_finding) = app.all_findings.get(selected_index)
+            && let Some(detail) = knowledge_base::get_finding_detail(&selected_finding.code)
+        {
+            // Format the description and remediation advice for display.
+            let mut text = vec![
+                Line::from(""),
+                Line::from("WHAT IT IS:".yellow().bold()),
+                Line::from(selected_finding.fill_template(detail.description)),
+                Line::from(""),
+                Line::from("HOW TO FIX:".yellow().bold()),
+                Line::from(selected_finding.fill_template(detail.remediation)),
+            ];
+            // Show the ready-made fix for the server, when the knowledge base has one.
+            if let Some((server, snippet)) = app.selected_config_snippet() {
+                text.push(Line::from(""));
+                text.push(Line::from(format!("APPLY ({}, press H to copy):", server).yellow().bold()));
+                text.extend(snippet.lines().map(|line| Line::from(line.to_string()).fg(Color::Green)));
+            }
+            // Make configured severity overrides visible, so the list coloring is never a mystery.
+            // Only the config counts: a scanner may emit a finding at a severity other than the default.
+            if let Some(original) = &selected_finding.escalated_from {
+                text.insert(1, Line::from(format!(
+                    "Escalated by strict mode: {:?} (originally {:?})",
+                    selected_finding.severity, original
+                )).fg(Color::Magenta));
+            } else if app.config.severity_overrides.contains_key(&selected_finding.code) {
+                text.insert(1, Line::from(format!(
+                    "Severity overridden by config: {:?} (default: {:?})",
+                    selected_finding.severity, detail.severity
+                )).fg(Color::Magenta));
+            }
+            // Append the reference links, if the knowledge base provides any.
+            if !detail.references.is_empty() {
+                text.push(Line::from(""));
+                text.push(Line::from("LEARN MORE:".yellow().bold()));
+                for reference in detail.references {
+                    text.push(Line::from(Span::styled(format!("- {}", reference), Style::default().fg(Color::Blue))));
+                }
             }
+            let p = Paragraph::new(text).wrap(Wrap { trim: true }).block(detail_block);
+            // Render the details in the bottom pane.
+            frame.render_widget(p, chunks[1]);
         }
     } else {
         // If no item is selected, render a placeholder in the details pane.
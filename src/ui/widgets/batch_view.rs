@@ -0,0 +1,47 @@
+// src/ui/widgets/batch_view.rs
+
+use crate::app::{App, AppState, SPINNER_CHARS};
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, List, ListItem},
+    text::Line,
+};
+
+/// Renders the list of targets in a multi-target run.
+///
+/// Each row shows the target and, once scanned, its score. The target currently
+/// being scanned gets the spinner, and the row whose report is loaded in the
+/// analysis view is highlighted.
+///
+/// # Arguments
+///
+/// * `frame` - The mutable frame to render onto.
+/// * `app` - A mutable reference to the application's state.
+/// * `area` - The `Rect` in which to render this widget.
+pub fn render_batch_view(frame: &mut Frame, app: &mut App, area: Rect) {
+    let done = app.batch.iter().filter(|entry| entry.report.is_some()).count();
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!("Targets ({}/{})", done, app.batch.len()));
+
+    let scanning = matches!(app.state, AppState::Scanning);
+    let items: Vec<ListItem> = app.batch.iter().enumerate().map(|(i, entry)| {
+        let status = match entry.score {
+            Some(score) => {
+                let style = if score >= 80 { Style::default().fg(Color::Green) }
+                    else if score >= 50 { Style::default().fg(Color::Yellow) }
+                    else { Style::default().fg(Color::Red) };
+                Span::styled(format!("{:>3}/100", score), style)
+            }
+            // The first target without a report is the one in flight.
+            None if scanning && i == done => Span::styled(format!("{:>7}", SPINNER_CHARS[app.spinner_frame]), Style::default().fg(Color::Cyan)),
+            None => Span::styled(format!("{:>7}", "queued"), Style::default().fg(Color::DarkGray)),
+        };
+        ListItem::new(Line::from(vec![status, Span::raw(" "), Span::raw(entry.target.as_str())]))
+    }).collect();
+
+    let list = List::new(items)
+        .block(block)
+        .highlight_style(Style::new().bg(Color::DarkGray).add_modifier(Modifier::BOLD));
+    frame.render_stateful_widget(list, area, &mut app.batch_list_state);
+}
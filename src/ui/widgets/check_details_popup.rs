@@ -0,0 +1,142 @@
+// src/ui/widgets/check_details_popup.rs
+
+use crate::app::{App, CheckDetail};
+use crate::core::models::RequestDetails;
+use crate::ui::widgets::disclaimer_popup::centered_rect;
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    text::Line,
+};
+
+/// Renders a popup explaining the verdict of each summary security check.
+///
+/// For every category (DNS, SSL/TLS, HTTP headers) it lists the sub-checks that
+/// were evaluated, so users can see exactly why a category shows ✓ or ✗. Problems the
+/// scan itself ran into are listed under "Scan Diagnostics", followed by the requests the
+/// HTTP-based scanners sent and the hosts the scan contacted.
+///
+/// # Arguments
+/// * `frame` - A mutable reference to the `Frame` used for rendering the TUI.
+/// * `app` - A reference to the application's state, containing the summary.
+/// * `area` - The `Rect` representing the total area available for rendering.
+pub fn render_check_details_popup(frame: &mut Frame, app: &App, area: Rect) {
+    let categories = [
+        ("DNS Configuration", app.summary.dns_check_passed, app.summary.dns_check_skipped, &app.summary.dns_check_details),
+        ("SSL/TLS Certificate", app.summary.ssl_check_passed, app.summary.ssl_check_skipped, &app.summary.ssl_check_details),
+        ("HTTP Security Headers", app.summary.headers_check_passed, app.summary.headers_check_skipped, &app.summary.headers_check_details),
+    ];
+
+    let mut lines = Vec::new();
+    for (name, passed, skipped, details) in categories {
+        if skipped {
+            lines.push(Line::from(vec![Span::raw("– ").fg(Color::DarkGray), Span::raw(name).bold()]));
+            lines.push(Line::from("   Skipped: this scanner is disabled in the configuration.").fg(Color::DarkGray));
+            lines.push(Line::from(""));
+            continue;
+        }
+        let (icon, style) = status_icon(passed);
+        lines.push(Line::from(vec![Span::styled(format!("{} ", icon), style), Span::raw(name).bold()]));
+        for detail in details.iter() {
+            lines.push(render_detail(detail));
+        }
+        // Break the SPF record down, so its policy can be read term by term.
+        if name == "DNS Configuration" {
+            lines.extend(render_spf_mechanisms(app));
+            if let Some(report) = &app.scan_report && !report.dns_results.resolver.is_empty() {
+                lines.push(Line::from(format!("     Resolved via {}", report.dns_results.resolver)).fg(Color::DarkGray));
+            }
+        }
+        lines.push(Line::from(""));
+    }
+    // Problems of the scan itself, which may have left some checks with incomplete data.
+    if let Some(report) = &app.scan_report && !report.warnings.is_empty() {
+        lines.push(Line::from(vec![Span::styled("! ", Style::default().fg(Color::Yellow)), Span::raw("Scan Diagnostics").bold()]));
+        for warning in &report.warnings {
+            lines.push(Line::from(format!("   {}", warning)).fg(Color::Yellow));
+        }
+        lines.push(Line::from(""));
+    }
+    // What the HTTP-based scanners actually requested, to reproduce a disputed finding.
+    if let Some(report) = &app.scan_report {
+        let requests: Vec<(&str, &RequestDetails)> = [
+            ("Headers", report.headers_results.request.as_ref()),
+            ("Technologies", report.fingerprint_results.request.as_ref()),
+        ].into_iter().filter_map(|(scanner, request)| Some((scanner, request?))).collect();
+        if !requests.is_empty() {
+            lines.push(Line::from(vec![Span::styled("» ", Style::default().fg(Color::Cyan)), Span::raw("Requests").bold()]));
+            for (scanner, request) in requests {
+                let mut line = format!("   {}: {} {}", scanner, request.method, request.url);
+                if let Some(final_url) = &request.final_url {
+                    line.push_str(&format!(" → {}", final_url));
+                }
+                line.push_str(&format!(" (User-Agent: {})", request.user_agent));
+                lines.push(Line::from(line).fg(Color::DarkGray));
+            }
+            lines.push(Line::from(""));
+        }
+    }
+    // The audit trail of the scan's connections.
+    if let Some(report) = &app.scan_report && !report.contacted_hosts.is_empty() {
+        lines.push(Line::from(vec![Span::styled("» ", Style::default().fg(Color::Cyan)), Span::raw("Contacted Hosts").bold()]));
+        lines.push(Line::from(format!("   {}", report.contacted_hosts.join(", "))).fg(Color::DarkGray));
+        if !report.blocked_hosts.is_empty() {
+            lines.push(Line::from(format!("   Refused (offline mode): {}", report.blocked_hosts.join(", "))).fg(Color::Yellow));
+        }
+        lines.push(Line::from(""));
+    }
+    lines.push("Press ".bold() + "W".bold().yellow() + " or ".bold() + "Esc".bold().yellow() + " to close".bold());
+
+    let block = Block::default()
+        .title("Why these checks?")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan));
+
+    let popup_area = centered_rect(70, 70, area);
+    let popup = Paragraph::new(lines)
+        .block(block)
+        .wrap(Wrap { trim: true });
+
+    frame.render_widget(Clear, popup_area);
+    frame.render_widget(popup, popup_area);
+}
+
+/// Renders a single indented sub-check line.
+fn render_detail(detail: &CheckDetail) -> Line<'_> {
+    let (icon, style) = status_icon(detail.passed);
+    Line::from(vec![
+        Span::raw("   "),
+        Span::styled(format!("{} ", icon), style),
+        Span::raw(detail.description.as_str()),
+    ])
+}
+
+/// Renders the mechanisms and modifiers of the SPF record, one per line, below the DNS checks.
+/// Returns nothing when no SPF record was found.
+fn render_spf_mechanisms(app: &App) -> Vec<Line<'_>> {
+    let Some(Ok(Some(spf))) = app.scan_report.as_ref().map(|report| &report.dns_results.spf) else {
+        return Vec::new();
+    };
+    let mut lines = vec![Line::from("     SPF mechanisms:").fg(Color::DarkGray)];
+    for mechanism in &spf.parsed.mechanisms {
+        let term = match &mechanism.value {
+            Some(value) if value.starts_with('/') => format!("{}{}", mechanism.kind, value),
+            Some(value) => format!("{}:{}", mechanism.kind, value),
+            None => mechanism.kind.clone(),
+        };
+        lines.push(Line::from(vec![
+            Span::raw("       - "),
+            Span::styled(term, Style::default().fg(Color::Cyan)),
+            Span::raw(format!(" ({})", mechanism.qualifier_meaning())).fg(Color::DarkGray),
+        ]));
+    }
+    for modifier in &spf.parsed.modifiers {
+        lines.push(Line::from(vec![Span::raw("       - "), Span::styled(modifier.as_str(), Style::default().fg(Color::Cyan))]));
+    }
+    lines
+}
+
+/// Returns the icon and color used for a pass/fail status.
+fn status_icon(passed: bool) -> (&'static str, Style) {
+    if passed { ("✓", Style::default().fg(Color::Green)) } else { ("✗", Style::default().fg(Color::Red)) }
+}
@@ -29,7 +29,7 @@ pub fn render_disclaimer_popup(frame: &mut Frame, area: Rect) {
         Line::from("2. You will use this software responsibly and in accordance with all applicable laws."),
         Line::from("3. The author of this software assumes NO liability and is NOT responsible for any misuse or damage caused by this program."),
         Line::from(""),
-        Line::from("Press ".bold() + "Enter".bold().yellow() + " to Acknowledge and Continue".bold()),
+        "Press ".bold() + "Enter".bold().yellow() + " to Acknowledge and Continue".bold(),
     ]);
 
     let block = Block::default()
@@ -64,7 +64,7 @@ pub fn render_disclaimer_popup(frame: &mut Frame, area: Rect) {
 ///
 /// # Returns
 /// A new `Rect` that is centered within `r`.
-fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
+pub fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
     let popup_layout = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
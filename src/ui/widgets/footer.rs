@@ -21,7 +21,7 @@ use ratatui::{
 /// * `area` - The `Rect` in which to render the footer.
 pub fn render_footer(frame: &mut Frame, app: &App, area: Rect) {
     // Determine the content of the footer based on the application's state.
-    let spans = match app.state {
+    let mut spans = match app.state {
         // In the disclaimer view, show how to proceed.
         AppState::Disclaimer => Line::from(vec![
             Span::raw("Press "),
@@ -29,13 +29,28 @@ pub fn render_footer(frame: &mut Frame, app: &App, area: Rect) {
             Span::raw(" to Acknowledge and Continue"),
         ]),
         
+        // Over the tutorial, show how to close it.
+        AppState::Idle if app.show_tutorial => Line::from(vec![
+            Span::raw("Press "),
+            Span::styled("Enter", Style::new().bold().fg(Color::Yellow)),
+            Span::raw(" or "),
+            Span::styled("Esc", Style::new().bold().fg(Color::Yellow)),
+            Span::raw(" to start"),
+        ]),
+
         // When idle, show the primary actions.
         AppState::Idle => Line::from(vec![
             Span::raw("Press "),
             Span::styled("Enter", Style::new().bold().fg(Color::Yellow)),
             Span::raw(" to scan, "),
             Span::styled("Q", Style::new().bold().fg(Color::Yellow)),
-            Span::raw(" to quit."),
+            Span::raw(" to quit, "),
+            Span::styled("F1", Style::new().bold().fg(Color::Yellow)),
+            Span::raw(" for the disclaimer, "),
+            Span::styled("F2", Style::new().bold().fg(Color::Yellow)),
+            Span::raw(" to accept invalid certificates, "),
+            Span::styled("F3", Style::new().bold().fg(Color::Yellow)),
+            Span::raw(" to follow redirects."),
         ]),
 
         // When the scan is finished, the controls are more complex.
@@ -45,20 +60,30 @@ pub fn render_footer(frame: &mut Frame, app: &App, area: Rect) {
                 ExportStatus::Idle => {
                     // Display different navigation hints depending on whether the log view is active.
                     let nav_controls = if app.show_logs {
-                        "Scroll Logs: [←/→]"
+                        "Scroll Logs: [←/→] [PgUp/PgDn] [End]"
                     } else {
-                        "Navigate List: [↑/↓]"
+                        "Navigate List: [↑/↓] [Tab]"
                     };
-                    let main_controls = "[N]ew Scan | [E]xport | [L]ogs | [Q]uit";
-                    Line::from(vec![
+                    let main_controls = "[N]ew Scan | [E]xport | [B]undle | [T]ech | Chec[K]list | [C]opy Code | [H]int | [I]gnore | [A]nnotate | [W]hy | [S]core | [R]aw | [F]ocus | [L]ogs | [V]iew | [Q]uit";
+                    let mut spans = vec![
                         Span::styled(nav_controls, Style::new().fg(Color::Cyan)),
                         Span::raw(" | "),
-                        Span::raw(main_controls),
-                    ])
+                    ];
+                    if app.is_batch() {
+                        spans.push(Span::styled("Target: [[/]]", Style::new().fg(Color::Cyan)));
+                        spans.push(Span::raw(" | "));
+                    }
+                    spans.push(Span::raw(main_controls));
+                    Line::from(spans)
                 },
                 // Show a success message after a successful export.
-                ExportStatus::Success(filename) => Line::from(
-                    Span::styled(format!("✓ Exported to {}", filename), Style::new().fg(Color::Green))
+                ExportStatus::Success(filename) => Line::from(vec![
+                    Span::styled(format!("✓ Exported to {}", filename), Style::new().fg(Color::Green)),
+                    Span::raw(" | [O]pen folder"),
+                ]),
+                // Confirm what was copied to the clipboard.
+                ExportStatus::Copied(text) => Line::from(
+                    Span::styled(format!("✓ Copied {} to the clipboard", text), Style::new().fg(Color::Green))
                 ),
                 // Show an error message if the export failed.
                 ExportStatus::Error(e) => Line::from(
@@ -68,9 +93,23 @@ pub fn render_footer(frame: &mut Frame, app: &App, area: Rect) {
         }
         
         // During a scan, provide a way to quit.
-        AppState::Scanning => Line::from("Scanning... Press Q to quit."),
+        AppState::Scanning if app.is_batch() => {
+            let done = app.batch.iter().filter(|entry| entry.report.is_some()).count();
+            Line::from(format!("Scanning target {}/{}... [L]ogs | Press Q to quit.", done + 1, app.batch.len()))
+        }
+        AppState::Scanning => Line::from("Scanning... [L]ogs | Press Q to quit."),
     };
 
+    // The scanned host may not be the one typed in, so say so.
+    if app.follow_canonical_redirect && !matches!(app.state, AppState::Disclaimer) {
+        spans.spans.insert(0, Span::styled("[Following redirects, F3] ", Style::new().bold().fg(Color::Cyan)));
+    }
+
+    // Make it obvious when certificate validation is off, whatever else the footer shows.
+    if app.accept_invalid_certs && !matches!(app.state, AppState::Disclaimer) {
+        spans.spans.insert(0, Span::styled("[INSECURE: invalid certs accepted, F2] ", Style::new().bold().fg(Color::Red)));
+    }
+
     // Create and render the Paragraph widget.
     let footer = Paragraph::new(spans).alignment(Alignment::Center);
     frame.render_widget(footer, area);
@@ -22,7 +22,7 @@ use crate::app::{App, AppState};
 /// * `area` - The `Rect` where the input widget should be rendered.
 pub fn render_input(frame: &mut Frame, app: &App, area: Rect) {
     // Create the block with a title and borders.
-    let input_block = Block::default().borders(Borders::ALL).title("Target Domain");
+    let input_block = Block::default().borders(Borders::ALL).title("Target Domain (separate several with commas)");
 
     // Create the paragraph widget with the current input text.
     let input_paragraph = Paragraph::new(app.input.as_str())
@@ -11,7 +11,8 @@ use ratatui::{
 ///
 /// This widget displays the most recent lines from the application's log file.
 /// It includes a horizontal scrollbar to allow viewing of long log lines that
-/// might otherwise be truncated. This version applies custom styling to the
+/// might otherwise be truncated, and a vertical one for paging through older lines.
+/// While `log_follow` is set, the view sticks to the newest lines at the bottom. This version applies custom styling to the
 /// timestamp part of each log line to improve readability.
 ///
 /// # Arguments
@@ -22,7 +23,11 @@ use ratatui::{
 pub fn render_log_view(frame: &mut Frame, app: &mut App, area: Rect) {
     // Create the main block for the log panel with a title and borders.
     let block = Block::default()
-        .title("Logs (scroll with ← →)")
+        .title(if app.log_follow {
+            "Logs (scroll with ← → PgUp PgDn) - following"
+        } else {
+            "Logs (scroll with ← → PgUp PgDn, End to follow)"
+        })
         .borders(Borders::ALL);
     
     // Get the inner area of the block to render the content within the borders.
@@ -38,6 +43,18 @@ pub fn render_log_view(frame: &mut Frame, app: &mut App, area: Rect) {
     // Update the scrollbar's state with the total content length.
     app.log_horizontal_scroll_state = app.log_horizontal_scroll_state.content_length(max_width);
 
+    // The last row is taken by the horizontal scrollbar. Follow mode pins the view to the
+    // bottom; scrolling down to the bottom by hand turns follow mode back on.
+    let visible_lines = inner_area.height.saturating_sub(1) as usize;
+    let max_vertical_scroll = app.log_content.len().saturating_sub(visible_lines);
+    if app.log_follow || app.log_vertical_scroll >= max_vertical_scroll {
+        app.log_vertical_scroll = max_vertical_scroll;
+        app.log_follow = true;
+    }
+    app.log_vertical_scroll_state = app.log_vertical_scroll_state
+        .content_length(max_vertical_scroll)
+        .position(app.log_vertical_scroll);
+
     // --- INIZIO CORREZIONE ---
     // Process each log line to apply custom styling.
     let log_lines: Vec<Line> = app.log_content.iter().map(|line_str| {
@@ -68,7 +85,7 @@ pub fn render_log_view(frame: &mut Frame, app: &mut App, area: Rect) {
     
     // Create the Paragraph widget from our collection of styled lines.
     let log_paragraph = Paragraph::new(log_lines)
-        .scroll((0, app.log_horizontal_scroll as u16));
+        .scroll((app.log_vertical_scroll as u16, app.log_horizontal_scroll as u16));
     // --- FINE CORREZIONE ---
         
     frame.render_widget(log_paragraph, inner_area);
@@ -91,4 +108,14 @@ pub fn render_log_view(frame: &mut Frame, app: &mut App, area: Rect) {
         scrollbar_area,
         &mut app.log_horizontal_scroll_state,
     );
+
+    // The vertical scrollbar sits on the right border, leaving the content width untouched.
+    let vertical_scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
+        .begin_symbol(None)
+        .end_symbol(None);
+    frame.render_stateful_widget(
+        vertical_scrollbar,
+        area.inner(Margin { vertical: 1, horizontal: 0 }),
+        &mut app.log_vertical_scroll_state,
+    );
 }
\ No newline at end of file
@@ -10,5 +10,11 @@ pub mod analysis_view; // Our new widget for the analysis report.
 pub mod footer;         // The widget for the dynamic footer bar.
 pub mod input;          // The widget for the user input field.
 pub mod disclaimer_popup; // The widget for the legal disclaimer popup.
+pub mod tutorial_popup; // The first-run tutorial shown after the disclaimer.
 pub mod summary;        // The widget that displays the scan summary.
-pub mod log_view; // The widget for logs
\ No newline at end of file
+pub mod log_view; // The widget for logs
+pub mod check_details_popup; // The popup explaining the summary's security checks.
+pub mod notes_popup; // The popup for editing the report's notes.
+pub mod raw_response_popup; // The popup dumping the raw HTTP response.
+pub mod score_breakdown_popup; // The popup listing what each finding costs the score.
+pub mod batch_view; // The list of targets in a multi-target run.
\ No newline at end of file
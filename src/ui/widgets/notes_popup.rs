@@ -0,0 +1,50 @@
+// src/ui/widgets/notes_popup.rs
+
+use crate::app::App;
+use crate::ui::widgets::disclaimer_popup::centered_rect;
+use ratatui::{
+    layout::Position,
+    prelude::*,
+    text::Line,
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+};
+
+/// Renders the overlay used to write a free-text note for the current report.
+///
+/// The note is stored in exported reports, so archived scans can say why they were taken
+/// (e.g. "pre-migration baseline").
+///
+/// # Arguments
+/// * `frame` - A mutable reference to the `Frame` used for rendering the TUI.
+/// * `app` - A reference to the application's state, containing the note being edited.
+/// * `area` - The `Rect` representing the total area available for rendering.
+pub fn render_notes_popup(frame: &mut Frame, app: &App, area: Rect) {
+    let Some(draft) = &app.notes_input else { return; };
+
+    let popup_area = centered_rect(60, 25, area);
+    let block = Block::default()
+        .title("Scan Notes")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Yellow));
+    let inner = block.inner(popup_area);
+
+    let text = Text::from(vec![
+        Line::from(draft.as_str()).fg(Color::Yellow),
+        Line::from(""),
+        Line::from(vec![
+            "Enter".bold().yellow(),
+            " to save, ".into(),
+            "Esc".bold().yellow(),
+            " to cancel. Saved with the exported report.".into(),
+        ]),
+    ]);
+
+    frame.render_widget(Clear, popup_area);
+    frame.render_widget(Paragraph::new(text).block(block).wrap(Wrap { trim: false }), popup_area);
+
+    // Place the cursor at the end of the note, like the target input box does.
+    frame.set_cursor_position(Position::new(
+        (inner.x + draft.len() as u16).min(inner.right().saturating_sub(1)),
+        inner.y,
+    ));
+}
@@ -0,0 +1,80 @@
+// src/ui/widgets/raw_response_popup.rs
+
+use crate::app::App;
+use crate::ui::widgets::disclaimer_popup::centered_rect;
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    text::Line,
+};
+
+/// Renders a popup dumping the raw response of the main HTTP request.
+///
+/// It lists every header the server sent, not just the analyzed ones, so a finding that
+/// looks wrong can be checked against the actual response. The body is hidden until the
+/// user expands it, and even then only its first bytes are kept.
+///
+/// # Arguments
+/// * `frame` - A mutable reference to the `Frame` used for rendering the TUI.
+/// * `app` - A reference to the application's state, containing the scan report.
+/// * `area` - The `Rect` representing the total area available for rendering.
+pub fn render_raw_response_popup(frame: &mut Frame, app: &App, area: Rect) {
+    let mut lines = Vec::new();
+    if let Some(report) = &app.scan_report {
+        let headers = &report.headers_results;
+        if let Some(error) = &headers.error {
+            lines.push(Line::from(error.as_str()).fg(Color::Red));
+        } else if headers.raw_headers.is_empty() {
+            lines.push(Line::from("No raw response was recorded for this report.").fg(Color::DarkGray));
+        }
+        for (name, value) in &headers.raw_headers {
+            lines.push(Line::from(vec![
+                Span::styled(format!("{}: ", name), Style::default().fg(Color::Cyan)),
+                Span::raw(value.as_str()),
+            ]));
+        }
+
+        if let Some(body) = &headers.raw_body {
+            lines.push(Line::from(""));
+            let size = if headers.raw_body_size_unknown {
+                format!("more than {} bytes", headers.raw_body_size)
+            } else {
+                format!("{} bytes", headers.raw_body_size)
+            };
+            if app.raw_response_show_body {
+                lines.push(Line::from(format!("Body (first {} of {}):", body.len().min(headers.raw_body_size), size)).bold());
+                lines.extend(body.lines().map(Line::from));
+            } else {
+                lines.push(Line::from(format!("Body: {}, hidden.", size)).fg(Color::DarkGray));
+            }
+        }
+    }
+
+    let body_hint = if app.raw_response_show_body { " to hide the body, " } else { " to show the body, " };
+    let block = Block::default()
+        .title("Raw HTTP Response")
+        .title_bottom(
+            Line::from(vec![
+                " Press ".bold(),
+                "B".bold().yellow(),
+                body_hint.bold(),
+                "↑/↓".bold().yellow(),
+                " to scroll, ".bold(),
+                "R".bold().yellow(),
+                " or ".bold(),
+                "Esc".bold().yellow(),
+                " to close ".bold(),
+            ])
+        )
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan));
+
+    let popup_area = centered_rect(80, 80, area);
+    let popup = Paragraph::new(lines)
+        .block(block)
+        .wrap(Wrap { trim: false })
+        .scroll((app.raw_response_scroll, 0));
+
+    frame.render_widget(Clear, popup_area);
+    frame.render_widget(popup, popup_area);
+}
@@ -0,0 +1,79 @@
+// src/ui/widgets/score_breakdown_popup.rs
+
+use crate::app::App;
+use crate::core::models::{ScoringProfile, Severity, score_from_penalty};
+use crate::ui::widgets::disclaimer_popup::centered_rect;
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    text::Line,
+};
+
+/// Renders a popup showing how the score was computed.
+///
+/// Starting from 100, it lists every finding that costs points and how many, most
+/// expensive first, then the total and the final score. The weights are those of the
+/// report's scoring profile, applied after the configured severity overrides.
+///
+/// # Arguments
+/// * `frame` - A mutable reference to the `Frame` used for rendering the TUI.
+/// * `app` - A reference to the application's state, containing the findings.
+/// * `area` - The `Rect` representing the total area available for rendering.
+pub fn render_score_breakdown_popup(frame: &mut Frame, app: &App, area: Rect) {
+    let deductions = app.score_breakdown();
+    let profile = app.scan_report.as_ref().map(|report| report.scoring_profile).unwrap_or_default();
+
+    let mut lines = vec![Line::from("Starting score: 100").bold()];
+    if profile != ScoringProfile::General {
+        lines.push(Line::from(format!("Weighted by the {} profile.", profile)).fg(Color::DarkGray));
+    }
+    lines.push(Line::from(""));
+    if deductions.is_empty() {
+        lines.push(Line::from("No finding costs any points.").fg(Color::Green));
+    }
+    for deduction in &deductions {
+        let color = match deduction.severity {
+            Severity::Critical => Color::Red,
+            Severity::Warning => Color::Yellow,
+            Severity::Info => Color::Cyan,
+        };
+        lines.push(Line::from(vec![
+            Span::styled(format!("{:>7}  ", format!("-{}", points(deduction.penalty))), Style::default().fg(color)),
+            Span::raw(format!("{}: ", deduction.code)).bold(),
+            Span::raw(deduction.title.as_str()),
+        ]));
+    }
+
+    let total: u32 = deductions.iter().map(|deduction| deduction.penalty).sum();
+    lines.push(Line::from(""));
+    let mut total_line = format!("Total deducted: -{}", points(total));
+    if !total.is_multiple_of(100) {
+        // The score is a whole number, so a fractional total costs the next full point.
+        total_line.push_str(&format!(" (rounded up to -{})", total.div_ceil(100)));
+    }
+    lines.push(Line::from(total_line));
+    lines.push(Line::from(format!("Final score: {}/100", score_from_penalty(total))).bold());
+    lines.push(Line::from(""));
+    lines.push("Press ".bold() + "S".bold().yellow() + " or ".bold() + "Esc".bold().yellow() + " to close".bold());
+
+    let block = Block::default()
+        .title("Score Breakdown")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan));
+
+    let popup_area = centered_rect(70, 70, area);
+    let popup = Paragraph::new(lines)
+        .block(block)
+        .wrap(Wrap { trim: false });
+
+    frame.render_widget(Clear, popup_area);
+    frame.render_widget(popup, popup_area);
+}
+
+/// Formats a penalty given in hundredths of a point, e.g. 1500 as "15" and 750 as "7.5".
+fn points(hundredths: u32) -> String {
+    if hundredths.is_multiple_of(100) {
+        return (hundredths / 100).to_string();
+    }
+    format!("{}.{:02}", hundredths / 100, hundredths % 100).trim_end_matches('0').to_string()
+}
@@ -1,6 +1,8 @@
 // src/ui/widgets/summary.rs
  
 use crate::app::{App, AppState};
+use crate::core::models::{ScoringProfile, Severity};
+use crate::core::scanner::{ScanSectionKind, fingerprint_scanner};
 use ratatui::{
     prelude::*,
     widgets::{Block, Borders, Gauge, Paragraph},
@@ -18,39 +20,46 @@ use ratatui::{
 /// * `app` - A reference to the application's state, containing all scan data.
 /// * `area` - The `Rect` defining the drawable area for this widget.
 pub fn render_summary(frame: &mut Frame, app: &App, area: Rect) {
-    let summary_container = Block::default().borders(Borders::ALL).title("Summary");
+    // Name the host actually scanned when the target's redirect was followed.
+    let title = match app.scan_report.as_ref().and_then(|report| report.followed_redirect.as_ref()) {
+        Some(redirect) => format!("Summary: {} (redirected from {})", redirect.scanned, redirect.requested),
+        None => "Summary".to_string(),
+    };
+    let summary_container = Block::default().borders(Borders::ALL).title(title);
     frame.render_widget(summary_container, area);
  
     // Define the layout for the internal chunks of the widget.
+    // Spacers are `Max` so they collapse first when vertical space is tight,
+    // keeping the sections themselves intact for as long as possible.
     let summary_chunks = Layout::default()
         .direction(Direction::Vertical)
         .margin(1)
         .constraints([
-            Constraint::Length(3), // Score & Rating section
+            Constraint::Length(4), // Score & Rating section
             Constraint::Length(1), // Gauge chart
-            Constraint::Length(2), // Spacer
+            Constraint::Max(2),    // Spacer
             Constraint::Length(4), // Security Checks section
-            Constraint::Length(2), // Spacer
-            Constraint::Length(3), // Issues Found section
-            Constraint::Length(2), // Spacer
+            Constraint::Max(2),    // Spacer
+            Constraint::Length(5), // Issues Found section
+            Constraint::Max(2),    // Spacer
             Constraint::Min(0),    // Technologies section
         ])
         .split(area);
  
-    // Do not render summary content until the scan is complete.
-    if !matches!(app.state, AppState::Finished) {
+    // Do not render summary content until the first results are in.
+    if !matches!(app.state, AppState::Finished) && app.scan_report.is_none() {
         return;
     }
  
     // --- Score & Rating Section ---
-    let (rating_text, rating_style) = match app.summary.score {
-        90..=100 => ("Excellent", Style::default().fg(Color::Green)),
-        75..=89 => ("Good", Style::default().fg(Color::Cyan)),
-        50..=74 => ("Needs Improvement", Style::default().fg(Color::Yellow)),
-        _ => ("Poor", Style::default().fg(Color::Red)),
+    let (rating_text, rating_color) = score_rating(app.summary.score);
+    let score_line = Line::from(format!("{}/100 ({})", app.summary.score, rating_text)).fg(rating_color);
+    // Name the baseline when the score is not the one-size-fits-all one.
+    let score_title = match app.scan_report.as_ref().map(|report| report.scoring_profile) {
+        Some(profile) if profile != ScoringProfile::General => format!("Overall Score ({} profile)", profile),
+        _ => "Overall Score".to_string(),
     };
-    let score_line = Line::from(format!("{}/100 ({})", app.summary.score, rating_text)).style(rating_style);
-    let score_text = Text::from(vec![Line::from("Overall Score".bold()), score_line]);
+    let score_text = Text::from(vec![Line::from(score_title.bold()), score_line, email_score_line(app), hosting_line(app)]);
     frame.render_widget(Paragraph::new(score_text).alignment(Alignment::Center), summary_chunks[0]);
  
     // --- Gauge Chart (Animated) ---
@@ -66,27 +75,64 @@ pub fn render_summary(frame: &mut Frame, app: &App, area: Rect) {
     frame.render_widget(score_gauge, summary_chunks[1]);
  
     // --- Security Checks Section ---
+    let mut checks_title = vec![Span::raw("SECURITY CHECKS").bold()];
+    // The checks may rest on incomplete data; the details are in the [W]hy popup.
+    let diagnostics = app.scan_report.as_ref().map_or(0, |report| report.warnings.len());
+    if diagnostics > 0 {
+        let noun = if diagnostics == 1 { "diagnostic" } else { "diagnostics" };
+        checks_title.push(Span::raw(format!(" ({} scan {}, see [W]hy)", diagnostics, noun)).fg(Color::Yellow));
+    }
     let checks_block = Block::default()
-        .title("SECURITY CHECKS".bold());
+        .title(Line::from(checks_title));
     let mut checks_lines = Vec::new();
+    // A WAF challenge says nothing about the site's headers, so it is neither a pass nor a failure.
+    let headers_blocked = app.summary.blocked_by_waf.as_deref();
+    let summary = &app.summary;
     let checks_to_render = [
-        ("DNS Configuration", app.summary.dns_check_passed),
-        ("SSL/TLS Certificate", app.summary.ssl_check_passed),
-        ("HTTP Security Headers", app.summary.headers_check_passed),
+        ("DNS Configuration", summary.dns_check_passed, &summary.dns_worst_severity, summary.dns_check_skipped, None, app.has_section(ScanSectionKind::Dns)),
+        ("SSL/TLS Certificate", summary.ssl_check_passed, &summary.ssl_worst_severity, summary.ssl_check_skipped, None, app.has_section(ScanSectionKind::Ssl)),
+        // The headers verdict includes the redirect chain.
+        ("HTTP Security Headers", summary.headers_check_passed, &summary.headers_worst_severity, summary.headers_check_skipped, headers_blocked,
+            app.has_section(ScanSectionKind::Headers) && app.has_section(ScanSectionKind::Redirect)),
     ];
-    for (name, passed) in checks_to_render {
-        let (icon, style) = if passed { ("✓", Style::default().fg(Color::Green)) } else { ("✗", Style::default().fg(Color::Red)) };
-        checks_lines.push(Line::from(vec![Span::styled(format!("{} ", icon), style), Span::raw(name)]));
+    for (name, passed, worst, skipped, blocked_by, ready) in checks_to_render {
+        let (icon, style) = match (ready, skipped, blocked_by) {
+            (false, _, _) => ("…", Style::default().fg(Color::DarkGray)),
+            (true, true, _) => ("–", Style::default().fg(Color::DarkGray)),
+            (true, false, Some(_)) => ("!", Style::default().fg(Color::Yellow)),
+            (true, false, None) => verdict_icon(passed, worst.as_ref()),
+        };
+        let mut spans = vec![Span::styled(format!("{} ", icon), style), Span::raw(name)];
+        if ready && skipped {
+            spans.push(Span::raw(" (skipped)").fg(Color::DarkGray));
+        } else if let Some(waf) = blocked_by.filter(|_| ready) {
+            spans.push(Span::raw(format!(" (blocked by {} WAF)", waf)).fg(Color::Yellow).bold());
+        }
+        checks_lines.push(Line::from(spans));
     }
     frame.render_widget(Paragraph::new(checks_lines).block(checks_block), summary_chunks[3]);
  
     // --- Issue Details Section ---
     let issues_block = Block::default()
         .title("ISSUES FOUND".bold());
-    let details_text = Text::from(vec![
+    let mut details_lines = vec![
         Line::from(vec![Span::raw("Critical: "), Span::styled(app.summary.critical_issues.to_string(), Style::default().fg(Color::Red))]),
         Line::from(vec![Span::raw("Warnings: "), Span::styled(app.summary.warning_issues.to_string(), Style::default().fg(Color::Yellow))]),
-    ]);
+    ];
+    // Ignored findings are not counted above, but should not vanish without a trace.
+    if app.summary.suppressed_issues > 0 {
+        details_lines.push(Line::from(format!("{} findings suppressed", app.summary.suppressed_issues)).fg(Color::DarkGray));
+    }
+    let attack_surface = &app.summary.attack_surface;
+    details_lines.push(match attack_surface.total() {
+        // Still scanning: the components have not all arrived yet.
+        _ if !app.has_section(ScanSectionKind::Fingerprint) || !app.has_section(ScanSectionKind::Ssl) => {
+            Line::from("Attack surface: …").fg(Color::DarkGray)
+        }
+        Some(total) => Line::from(format!("Attack surface: {} ({})", total, attack_surface.describe())),
+        None => Line::from("Attack surface: unknown").fg(Color::DarkGray),
+    });
+    let details_text = Text::from(details_lines);
     frame.render_widget(Paragraph::new(details_text).block(issues_block), summary_chunks[5]);
  
     // --- Technologies Section ---
@@ -96,16 +142,32 @@ pub fn render_summary(frame: &mut Frame, app: &App, area: Rect) {
     // Check if the scan report is available and contains fingerprinting results.
     if let Some(report) = &app.scan_report {
         match &report.fingerprint_results.technologies {
+            Ok(_) if report.fingerprint_results.disabled => {
+                tech_lines.push(Line::from("Skipped.").fg(Color::DarkGray));
+            },
+            // Still scanning: the fingerprint has not arrived yet.
+            Ok(_) if !app.has_section(ScanSectionKind::Fingerprint) => {
+                tech_lines.push(Line::from("Detecting...").fg(Color::DarkGray));
+            },
             // Case 1: Scan was successful and technologies were identified.
             Ok(techs) => {
                 if techs.is_empty() {
                     tech_lines.push(Line::from("Not identified."));
                 } else {
                     for tech in techs {
-                        tech_lines.push(Line::from(vec![
+                        let mut spans = vec![
                             Span::raw("- "),
                             Span::styled(tech.name.clone(), Style::default().fg(Color::Cyan)),
-                        ]));
+                        ];
+                        // Show the detected version, highlighting end-of-life ones.
+                        if let Some(version) = &tech.version {
+                            if fingerprint_scanner::is_eol_version(tech) {
+                                spans.push(Span::styled(format!(" {} (EOL)", version), Style::default().fg(Color::Red)));
+                            } else {
+                                spans.push(Span::raw(format!(" {}", version)));
+                            }
+                        }
+                        tech_lines.push(Line::from(spans));
                     }
                 }
             },
@@ -116,7 +178,82 @@ pub fn render_summary(frame: &mut Frame, app: &App, area: Rect) {
                 ));
             }
         }
+        // The quick mode never looked inside the page, so frameworks and libraries may be missing.
+        if report.fingerprint_results.headers_only {
+            tech_lines.push(Line::from("Quick mode: fingerprinted from the headers only.").fg(Color::DarkGray));
+        }
+        // Whatever was detected comes from the WAF's page, not from the site.
+        if let Some(waf) = &report.fingerprint_results.blocked_by_waf {
+            tech_lines.push(Line::from(format!("Blocked by {} WAF: only its challenge page was seen.", waf)).fg(Color::Yellow));
+        }
+        // Append the protocol negotiated via ALPN during the TLS handshake.
+        if let Ok(Some(ssl)) = &report.ssl_results.scan
+            && !ssl.alpn.is_empty()
+        {
+            tech_lines.push(Line::from(vec![
+                Span::raw("- ALPN: "),
+                Span::styled(ssl.alpn.join(", "), Style::default().fg(Color::Cyan)),
+            ]));
+        }
+        // And the protocols the response advertises for later connections (e.g. HTTP/3).
+        if !report.headers_results.advertised_protocols.is_empty() {
+            tech_lines.push(Line::from(vec![
+                Span::raw("- Advertised: "),
+                Span::styled(report.headers_results.advertised_protocols.join(", "), Style::default().fg(Color::Cyan)),
+            ]));
+        }
     }
     let tech_paragraph = Paragraph::new(tech_lines).block(tech_block);
     frame.render_widget(tech_paragraph, summary_chunks[7]);
-}
\ No newline at end of file
+}
+
+/// Shows the email security sub-score, colored like the overall score, or why it is unknown.
+fn email_score_line(app: &App) -> Line<'static> {
+    let Some(score) = app.summary.email_score else {
+        return Line::from("Email security: unknown").fg(Color::DarkGray);
+    };
+    Line::from(format!("Email security: {}/100 (SPF, DMARC, DKIM)", score)).fg(score_rating(score).1)
+}
+
+/// Rates a score out of 100 and gives the color it is shown in.
+fn score_rating(score: u8) -> (&'static str, Color) {
+    match score {
+        90..=100 => ("Excellent", Color::Green),
+        75..=89 => ("Good", Color::Cyan),
+        50..=74 => ("Needs Improvement", Color::Yellow),
+        _ => ("Poor", Color::Red),
+    }
+}
+
+/// Describes where the scanned host is served from, e.g. "93.184.216.34 · AS15133 Edgecast · US",
+/// followed by e.g. "· 120 domains" when the reverse-IP dataset lists the address. Data the GeoIP
+/// databases do not provide is shown as "unknown".
+fn hosting_line(app: &App) -> Line<'static> {
+    let Some(hosting) = app.scan_report.as_ref().and_then(|report| report.dns_results.hosting.as_ref()) else {
+        return Line::from("Host: unresolved").fg(Color::DarkGray);
+    };
+    let asn = match (hosting.asn, &hosting.as_org) {
+        (Some(asn), Some(org)) => format!("AS{} {}", asn, org),
+        (Some(asn), None) => format!("AS{}", asn),
+        (None, _) => "ASN unknown".to_string(),
+    };
+    let country = hosting.country.as_deref().unwrap_or("country unknown");
+    let mut line = format!("{} · {} · {}", hosting.ip, asn, country);
+    if let Some(count) = hosting.shared_domains {
+        line.push_str(&format!(" · {} domains", count));
+    }
+    Line::from(line).fg(Color::DarkGray)
+}
+
+/// The icon of a category's verdict, colored like its worst finding in the findings list: red
+/// for Critical, yellow for Warning, cyan for Info only, and green when it has no finding.
+fn verdict_icon(passed: bool, worst: Option<&Severity>) -> (&'static str, Style) {
+    match worst {
+        Some(Severity::Critical) => ("✗", Style::default().fg(Color::Red)),
+        Some(Severity::Warning) => ("✗", Style::default().fg(Color::Yellow)),
+        Some(Severity::Info) => (if passed { "✓" } else { "✗" }, Style::default().fg(Color::Cyan)),
+        None if passed => ("✓", Style::default().fg(Color::Green)),
+        // A failed sub-check without any finding, e.g. a scan that could not run at all.
+        None => ("✗", Style::default().fg(Color::Red)),
+    }
+}
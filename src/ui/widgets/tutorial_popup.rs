@@ -0,0 +1,48 @@
+// src/ui/widgets/tutorial_popup.rs
+
+use crate::ui::widgets::disclaimer_popup::centered_rect;
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    text::Line,
+};
+
+/// Renders the first-run tutorial popup on top of the input screen.
+///
+/// It explains the input box, the keys worth knowing on the first scan, and how the score is
+/// computed, in one screen. It is shown once, after the disclaimer is first acknowledged.
+///
+/// # Arguments
+/// * `frame` - A mutable reference to the `Frame` used for rendering the TUI.
+/// * `area` - The `Rect` representing the total area available for rendering.
+pub fn render_tutorial_popup(frame: &mut Frame, area: Rect) {
+    let key = |key: &'static str| Span::styled(key, Style::default().bold().fg(Color::Yellow));
+    let tutorial_text = Text::from(vec![
+        Line::from("WELCOME TO VANGUARD RS".bold().cyan()).alignment(Alignment::Center),
+        Line::from(""),
+        Line::from("Scanning".bold()),
+        Line::from("Type a domain or URL in the input box (e.g. example.com) and press Enter. Separate several targets with commas or spaces to scan them one after another; ↑/↓ recall earlier targets."),
+        Line::from(""),
+        Line::from("Keys".bold()),
+        Line::from(vec![key("F2"), Span::raw(" accept invalid certificates, "), key("F3"), Span::raw(" follow redirects, "), key("F1"), Span::raw(" disclaimer, "), key("q"), Span::raw(" quit.")]),
+        Line::from(vec![Span::raw("After a scan: "), key("↑/↓"), Span::raw(" select an issue, "), key("w"), Span::raw(" why checks passed, "), key("s"), Span::raw(" score breakdown, "), key("e"), Span::raw(" export, "), key("l"), Span::raw(" logs, "), key("n"), Span::raw(" new scan.")]),
+        Line::from(""),
+        Line::from("Score".bold()),
+        Line::from("Every scan starts at 100: each Critical finding costs 15 points and each Warning 5, while Info findings are free. The DNS, SSL/TLS and header checks in the summary are colored by their worst finding."),
+        Line::from(""),
+        Line::from(vec![Span::raw("Press "), key("Enter"), Span::raw(" to start, or "), key("Esc"), Span::raw(" to skip. This is only shown once.")]).alignment(Alignment::Center),
+    ]);
+
+    let block = Block::default()
+        .title("Getting Started")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan));
+
+    let popup_area = centered_rect(70, 70, area);
+    let popup = Paragraph::new(tutorial_text)
+        .block(block)
+        .wrap(Wrap { trim: true });
+
+    frame.render_widget(Clear, popup_area);
+    frame.render_widget(popup, popup_area);
+}